@@ -0,0 +1,72 @@
+use rubot::{Bot, Depth};
+
+#[path = "chess.rs"]
+mod chess;
+
+use chess::Chess;
+use shakmaty::Setup;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Counts all allocations, as the bot itself only allocates on the heap.
+struct Counter;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counter = Counter;
+
+const CALLS: usize = 100;
+
+/// Prints the average amount of allocations of repeatedly calling `select` using the same bot,
+/// which is the common case when playing a whole game.
+fn count_allocations(name: &str, fen: &str) {
+    let chess = Chess::from_fen(fen);
+    let mut bot = Bot::new(chess.0.turn());
+    // The first call may allocate buffers which are reused by later calls.
+    bot.select(&chess, Depth(1));
+
+    let start = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..CALLS {
+        bot.select(&chess, Depth(1));
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - start;
+    println!("{:060} {:10}", name, allocations / CALLS);
+}
+
+fn main() {
+    println!("{:065} allocations", "game");
+    count_allocations(
+        "Gustav Neumann vs Carl Mayet, Berlin, 1866",
+        "5rkr/pp2Rp2/1b1p1Pb1/3P2Q1/2n3P1/2p5/P4P2/4R1K1 w - - 1 0",
+    );
+    count_allocations(
+        "Daniel Harrwitz vs Bernhard Horwitz, London, 1846",
+        "3q1r1k/2p4p/1p1pBrp1/p2Pp3/2PnP3/5PP1/PP1Q2K1/5R1R w - - 1 0",
+    );
+    count_allocations(
+        "Paul Morphy vs NN, New Orleans (blind, simul), 1858 ",
+        "r1b3kr/3pR1p1/ppq4p/5P2/4Q3/B7/P5PP/5RK1 w - - 1 0",
+    );
+    count_allocations(
+        "Jules De Riviere vs Paul Journoud, Paris, 1860",
+        "r1bk3r/pppq1ppp/5n2/4N1N1/2Bp4/Bn6/P4PPP/4R1K1 w - - 1 0",
+    );
+}
@@ -1,6 +1,9 @@
 #![allow(unused)]
 
+use rubot::Bounded;
 use shakmaty::{fen::Fen, Color, FromSetup, Move, MoveList, Outcome, Position, Role, Setup};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 #[derive(Debug, Clone, Default)]
 pub struct Chess(pub shakmaty::Chess);
@@ -23,9 +26,9 @@ impl rubot::Game for Chess {
                 Outcome::Draw => 0,
                 Outcome::Decisive { winner } => {
                     if winner == player {
-                        std::i32::MAX
+                        i32::MAX
                     } else {
-                        std::i32::MIN
+                        i32::MIN
                     }
                 }
             }
@@ -52,14 +55,29 @@ impl rubot::Game for Chess {
         }
     }
 
+    fn key(&self, _: Self::Player) -> Option<u64> {
+        let mut hasher = DefaultHasher::new();
+        self.0.board().hash(&mut hasher);
+        self.0.turn().hash(&mut hasher);
+        self.0.castling_rights().hash(&mut hasher);
+        self.0.ep_square().hash(&mut hasher);
+        Some(hasher.finish())
+    }
+
+    fn hash_action(&self, action: &Self::Action, _: Self::Player) -> Option<u64> {
+        // Moves between the same two squares are treated as the same action.
+        let from = action.from().map_or(64, u64::from);
+        Some(from * 64 + u64::from(action.to()))
+    }
+
     #[inline]
     fn is_upper_bound(&self, fitness: Self::Fitness, _: Self::Player) -> bool {
-        fitness == std::i32::MAX
+        fitness.is_upper_bound()
     }
 
     #[inline]
     fn is_lower_bound(&self, fitness: Self::Fitness, _: Self::Player) -> bool {
-        fitness == std::i32::MIN
+        fitness.is_lower_bound()
     }
 }
 
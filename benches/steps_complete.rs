@@ -6,12 +6,37 @@ mod chess;
 use chess::Chess;
 use shakmaty::Setup;
 
+/// The margin used for futility pruning, which is the value of a knight or bishop.
+const FUTILITY_MARGIN: i32 = 30;
+
 fn count_steps(name: &str, fen: &str) {
     let chess = Chess::from_fen(fen);
     let mut bot = Bot::new(chess.0.turn());
     let mut logger = Logger::new(ToCompletion);
-    bot.select(&chess, &mut logger);
-    println!("{:060} {:10}", name, logger.steps());
+    let action = bot.select(&chess, &mut logger);
+    let mut history = Bot::with_history_heuristic(chess.0.turn());
+    let mut history_logger = Logger::new(ToCompletion);
+    history.select(&chess, &mut history_logger);
+    let mut cached = Bot::with_transposition_table(chess.0.turn(), 1 << 16);
+    let mut cached_logger = Logger::new(ToCompletion);
+    cached.select(&chess, &mut cached_logger);
+    let mut futility = Bot::with_futility_margin(chess.0.turn(), FUTILITY_MARGIN);
+    let mut futility_logger = Logger::new(ToCompletion);
+    // Futility pruning must not miss the mate.
+    assert_eq!(
+        futility.select(&chess, &mut futility_logger),
+        action,
+        "{}",
+        name
+    );
+    println!(
+        "{:060} {:10} {:10} {:14} {:10}",
+        name,
+        logger.steps(),
+        history_logger.steps(),
+        cached_logger.steps(),
+        futility_logger.steps()
+    );
 }
 
 /// http://wtharvey.com/m8n2.txt
@@ -78,7 +103,7 @@ fn mate_in_four() {
 }
 
 fn main() {
-    println!("{:065} steps", "game");
+    println!("{:065} steps    history  transpositions   futility", "game");
     mate_in_two();
     mate_in_three();
     mate_in_four();
@@ -1,6 +1,6 @@
 //! These benchmarks use moments from games by https://lichess.org/@/rubot_simple/all
 
-use rubot::{Bot, Depth, Logger};
+use rubot::{bench, Bot, Depth};
 
 #[path = "chess.rs"]
 mod chess;
@@ -11,10 +11,14 @@ use shakmaty::Setup;
 fn count_steps(name: &str, fen: &str, depth: u32) {
     let chess = Chess::from_fen(fen);
     let mut bot = Bot::new(chess.0.turn());
-    let mut logger = Logger::new(Depth(depth));
-    bot.select(&chess, &mut logger);
-    println!("{:060} {:10}", name, logger.steps());
-    assert!(!logger.completed());
+    let measurement = bench::measure(&mut bot, &chess, Depth(depth));
+    println!(
+        "{:060} {:10} {:6.2}",
+        name,
+        measurement.steps,
+        measurement.branching_factor().unwrap_or(0.0)
+    );
+    assert!(!measurement.completed);
 }
 
 fn depth_three() {
@@ -114,7 +118,7 @@ fn depth_six() {
 }
 
 fn main() {
-    println!("{:065} steps", "game");
+    println!("{:065} steps branching", "game");
     depth_three();
     depth_four();
     depth_five();
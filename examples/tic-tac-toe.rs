@@ -281,8 +281,9 @@ fn main() {
                 }
             }
             Piece::O => {
-                let action = opponent.select(&game, Duration::from_secs(1)).unwrap();
-                game.make_move(action.0, action.1).unwrap();
+                opponent
+                    .select_and_apply(&mut game, Duration::from_secs(1))
+                    .unwrap();
             }
         }
     }
@@ -317,7 +318,7 @@ impl rubot::Game for Game {
         if !self.is_finished() {
             for x in 0..3 {
                 for y in 0..3 {
-                    if let None = self.tiles()[x][y] {
+                    if self.tiles()[x][y].is_none() {
                         actions.push(Action(x, y));
                     }
                 }
@@ -351,6 +352,10 @@ impl rubot::Game for Game {
         }
     }
 
+    fn max_actions(&self) -> Option<usize> {
+        Some(9)
+    }
+
     fn is_upper_bound(&self, fitness: Self::Fitness, _: Self::Player) -> bool {
         fitness == Fitness::Win
     }
@@ -375,4 +380,12 @@ mod tests {
         assert_eq!(opponent.select(&game, &mut logger).unwrap(), Action(1, 1));
         assert!(logger.duration() < Duration::from_secs(1));
     }
+
+    #[test]
+    fn solve_empty() {
+        let game = Game::new();
+
+        let mut bot = Bot::new(Piece::X);
+        assert_eq!(bot.solve(&game, 10), Some(Fitness::Even));
+    }
 }
@@ -19,5 +19,20 @@ fuzz_target!(|data: &[u8]| {
             );
             panic!();
         }
+
+        let fitness = Bot::new(true)
+            .detailed_select(&node, ToCompletion)
+            .map(|act| act.fitness);
+        let expected = Brute::new(true).best_fitness(&node, std::u32::MAX);
+        if fitness != expected {
+            println!(
+                "Error with node: {:?}. Expected fitness: {:?}, Actual: {:?}, Principal variation: {:?}",
+                node,
+                expected,
+                fitness,
+                Brute::new(true).principal_variation(&node, std::u32::MAX)
+            );
+            panic!();
+        }
     }
 });
@@ -0,0 +1,53 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+extern crate rubot;
+
+use rubot::{brute::Brute, tree::Node, Bot, ToCompletion};
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() >= 4 {
+        let node = Node::from_bytes(data);
+        // Every search variant which does not change the result, together with its name.
+        let variants: [(&str, fn(&Node) -> Option<usize>); 7] = [
+            ("select", |node| Bot::new(true).select(node, ToCompletion)),
+            ("detailed_select", |node| {
+                Bot::new(true)
+                    .detailed_select(node, ToCompletion)
+                    .map(|act| act.path[0])
+            }),
+            ("select_with_range", |node| {
+                Bot::new(true)
+                    .select_with_range(node, ToCompletion)
+                    .map(|(act, _, _)| act)
+            }),
+            ("select_no_root_cutoff", |node| {
+                Bot::new(true).select_no_root_cutoff(node, ToCompletion)
+            }),
+            ("with_root_action_limit", |node| {
+                Bot::with_root_action_limit(true, usize::MAX).select(node, ToCompletion)
+            }),
+            ("with_futility_margin", |node| {
+                Bot::with_futility_margin(true, 0).select(node, ToCompletion)
+            }),
+            ("with_principal_variation_search", |node| {
+                Bot::with_principal_variation_search(true, 1).select(node, ToCompletion)
+            }),
+        ];
+
+        for &(name, variant) in variants.iter() {
+            let selected = variant(&node);
+            let is_best = Brute::new(true).check_if_best(&node, selected.as_ref(), std::u32::MAX);
+            if !is_best {
+                println!(
+                    "Error in {} with node: {:?}. Expected: {:?}, Actual: {:?}",
+                    name,
+                    node,
+                    Brute::new(true).select(&node, std::u32::MAX),
+                    selected
+                );
+                panic!();
+            }
+        }
+    }
+});
@@ -0,0 +1,160 @@
+use crate::Game;
+
+use super::{Branch, CancelledError, MiniMax};
+
+use alloc::vec::Vec;
+
+/// The states analysed during a single depth of [`Bot::analyze`][an].
+///
+/// [an]: struct.Bot.html#method.analyze
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T::Action: serde::Serialize, T::Fitness: serde::Serialize",
+        deserialize = "T::Action: serde::Deserialize<'de>, T::Fitness: serde::Deserialize<'de>"
+    ))
+)]
+pub struct AnalysisTree<T: Game> {
+    /// The depth of the analysed iteration, which is `0` if only the actions of the
+    /// initial state and the best answer to each of them were analysed.
+    pub depth: u32,
+    /// The analysed root actions, in the order they were analysed.
+    pub children: Vec<AnalysisNode<T>>,
+}
+
+/// An analysed action and all analysed actions of the state reached by it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T::Action: serde::Serialize, T::Fitness: serde::Serialize",
+        deserialize = "T::Action: serde::Deserialize<'de>, T::Fitness: serde::Deserialize<'de>"
+    ))
+)]
+pub struct AnalysisNode<T: Game> {
+    /// The analysed action.
+    pub action: T::Action,
+    /// The fitness of this action, which is only exact if `bound` is [`Bound::Exact`][ex].
+    ///
+    /// [ex]: enum.Bound.html#variant.Exact
+    pub fitness: T::Fitness,
+    /// Whether some actions were pruned while analysing this action.
+    pub bound: Bound,
+    /// The analysed actions of the state reached by this action, in the order they were analysed.
+    ///
+    /// This is empty for actions at the lowest depth, actions pruned using a futility margin
+    /// and actions whose result was taken from the transposition table.
+    pub children: Vec<AnalysisNode<T>>,
+}
+
+/// How the fitness of an [`AnalysisNode`][an] relates to its actual fitness.
+///
+/// [an]: struct.AnalysisNode.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Bound {
+    /// The fitness is exact.
+    Exact,
+    /// The actual fitness is at most the given fitness, as the action was pruned
+    /// after finding an action of the opponent which is worse than an alternative.
+    Upper,
+    /// The actual fitness is at least the given fitness, as the action was pruned
+    /// after finding an action of the bot which is better than an alternative.
+    Lower,
+}
+
+impl Bound {
+    fn of<T: Game>(branch: Branch<T>) -> Self {
+        match branch {
+            Branch::Equal(_) => Bound::Exact,
+            Branch::Worse(_) => Bound::Upper,
+            Branch::Better(_) => Bound::Lower,
+        }
+    }
+}
+
+/// Records the states analysed by `Ctxt`, see `Bot::analyze`.
+pub(super) struct Analysis<T: Game> {
+    /// The actions which are currently analysed, each one reached by the previous one.
+    stack: Vec<AnalysisNode<T>>,
+    /// The tree of the depth which is currently analysed.
+    current: Option<AnalysisTree<T>>,
+    /// The tree of the last completed depth.
+    completed: Option<AnalysisTree<T>>,
+}
+
+impl<T: Game> Analysis<T> {
+    pub(super) fn new() -> Self {
+        Analysis {
+            stack: Vec::new(),
+            current: None,
+            completed: None,
+        }
+    }
+
+    /// Starts recording the given depth.
+    pub(super) fn start_depth(&mut self, depth: u32) {
+        self.current = Some(AnalysisTree {
+            depth,
+            children: Vec::new(),
+        });
+    }
+
+    /// Marks the current depth as completed.
+    pub(super) fn complete_depth(&mut self) {
+        self.completed = self.current.take();
+    }
+
+    /// Returns the tree of the last completed depth, or the tree of the current depth if
+    /// it ended early as the best action was found.
+    pub(super) fn finish(self, found: bool) -> Option<AnalysisTree<T>> {
+        if found {
+            self.current.or(self.completed)
+        } else {
+            self.completed
+        }
+    }
+
+    /// Starts analysing `action`, which has the given immediate `fitness`.
+    pub(super) fn push(&mut self, action: &T::Action, fitness: T::Fitness) {
+        self.stack.push(AnalysisNode {
+            action: action.clone(),
+            fitness,
+            bound: Bound::Exact,
+            children: Vec::new(),
+        });
+    }
+
+    /// Finishes analysing the last pushed action, discarding it if the search was cancelled.
+    pub(super) fn pop(&mut self, result: &Result<MiniMax<T>, CancelledError>) {
+        let mut node = self.stack.pop().expect("unexpected empty stack");
+        match result {
+            Err(CancelledError) => return,
+            Ok(MiniMax::DeadEnd) => {}
+            Ok(MiniMax::Terminated(_, branch)) | Ok(MiniMax::Open(_, branch)) => {
+                node.fitness = branch.fitness();
+                node.bound = Bound::of(*branch);
+            }
+        }
+        self.add(node);
+    }
+
+    /// Records `action` without analysing any of its children.
+    pub(super) fn leaf(&mut self, action: &T::Action, branch: Branch<T>) {
+        self.add(AnalysisNode {
+            action: action.clone(),
+            fitness: branch.fitness(),
+            bound: Bound::of(branch),
+            children: Vec::new(),
+        });
+    }
+
+    fn add(&mut self, node: AnalysisNode<T>) {
+        if let Some(parent) = self.stack.last_mut() {
+            parent.children.push(node);
+        } else if let Some(current) = self.current.as_mut() {
+            current.children.push(node);
+        }
+    }
+}
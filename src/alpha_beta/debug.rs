@@ -1,8 +1,8 @@
 use crate::Game;
 
-use std::fmt::{self, Debug};
+use core::fmt::{self, Debug};
 
-use super::{Action, Branch, Ctxt, MiniMax, State};
+use super::{Action, AnalysisNode, AnalysisTree, Branch, Ctxt, Explanation, MiniMax, State};
 
 impl<T: Game> Debug for Action<T>
 where
@@ -17,6 +17,21 @@ where
     }
 }
 
+impl<T: Game> Debug for Explanation<T>
+where
+    T::Action: Debug,
+    T::Fitness: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Explanation")
+            .field("action", &self.action)
+            .field("fitness", &self.fitness)
+            .field("line", &self.line)
+            .field("verdict", &self.verdict)
+            .finish()
+    }
+}
+
 impl<'a, T: Game> Debug for Ctxt<'a, T>
 where
     T: Debug,
@@ -50,7 +65,7 @@ where
     }
 }
 
-impl<T: Game> Debug for State<T>
+impl<T: Game> Debug for State<'_, T>
 where
     T::Action: Debug,
     T::Fitness: Debug,
@@ -80,3 +95,31 @@ where
         }
     }
 }
+
+impl<T: Game> Debug for AnalysisTree<T>
+where
+    T::Action: Debug,
+    T::Fitness: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AnalysisTree")
+            .field("depth", &self.depth)
+            .field("children", &self.children)
+            .finish()
+    }
+}
+
+impl<T: Game> Debug for AnalysisNode<T>
+where
+    T::Action: Debug,
+    T::Fitness: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AnalysisNode")
+            .field("action", &self.action)
+            .field("fitness", &self.fitness)
+            .field("bound", &self.bound)
+            .field("children", &self.children)
+            .finish()
+    }
+}
@@ -1,16 +1,24 @@
 //! A deterministic game bot using alpha beta pruning.
-use crate::{Game, IntoRunCondition, RunCondition};
+use crate::{rng::XorShiftRng, Depth, Game, IntoRunCondition, RunCondition, Undo};
 
-use tapir::Tap;
-
-use std::cmp::{self, Reverse};
-use std::mem;
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::cmp::{self, Ordering, Reverse};
+use core::fmt;
+use core::mem;
+use core::ops::{Add, Sub};
 
+mod analysis;
 mod debug;
+mod transposition;
+
+use analysis::Analysis;
+pub use analysis::{AnalysisNode, AnalysisTree, Bound};
+use transposition::TranspositionTable;
 
 /// A game bot which analyses its moves using [alpha beta pruning][ab_wiki] with [iterative deepening][id]. In case [`select`][sel] terminates
 /// before `condition` returned true, the result is always the best possible move. While this bot caches some data
-/// during computation, it does not require a lot of memory and will not store anything between different [`select`][sel] calls.
+/// during computation, it does not require a lot of memory and will not store anything between different [`select`][sel] calls,
+/// unless a persistent [transposition table][tt] is used, for example by using [`Bot::with_cache`][cache].
 ///
 /// This bot requires [`Game`][game] to be implemented for your game.
 ///
@@ -51,145 +59,2936 @@ mod debug;
 /// [ab_wiki]:https://en.wikipedia.org/wiki/Alpha%E2%80%93beta_pruning
 /// [sel]: struct.Bot.html#method.select
 /// [game]: ../trait.Game.html
+/// [tt]: struct.Bot.html#method.with_transposition_table
+/// [cache]: struct.Bot.html#method.with_cache
 pub struct Bot<T: Game> {
     player: T::Player,
+    /// The deepest depth which is analysed, regardless of the `RunCondition`.
+    max_depth: Option<u32>,
+    /// The maximum amount of actions considered at the root.
+    root_action_limit: Option<usize>,
+    repetition_penalty: Option<Offset<T>>,
+    /// The fitness of states already seen on the current search path.
+    repetition_draw: Option<T::Fitness>,
+    /// The offset subtracted from the fitness of drawn states.
+    contempt: Option<Offset<T>>,
+    futility_margin: Option<Offset<T>>,
+    /// The smallest difference between two fitness values, see `Bot::with_principal_variation_search`.
+    /// If the step is `None`, `Game::fitness_step` is used instead.
+    null_window: Option<NullWindow<T, Option<T::Fitness>>>,
+    partial_order: RetryOrder,
+    trust_action_order: bool,
+    order_key: bool,
+    history_heuristic: bool,
+    /// Reverts actions which were executed in place, see `Bot::with_undo`.
+    undo: Option<UndoFn<T>>,
+    /// The amount of irreversible actions after which a state is a leaf.
+    irreversible_limit: Option<u32>,
+    transpositions: Option<TranspositionTable<T>>,
+    /// Whether the transposition table is kept between searches.
+    persistent_transpositions: bool,
+    /// The key of the state analysed by the last call to `Bot::ponder`.
+    pondered: Option<u64>,
+    on_terminal: Option<Box<OnTerminal<T>>>,
+    /// Empty paths reused by each search, see `Ctxt::path_cache`.
+    path_cache: Vec<Vec<T::Action>>,
+    /// An empty buffer reused for the actions of the initial state.
+    root_buffer: Vec<Action<T>>,
+}
+
+/// A fitness offset and the function used to apply it.
+type Offset<T> = (
+    <T as Game>::Fitness,
+    fn(<T as Game>::Fitness, <T as Game>::Fitness) -> <T as Game>::Fitness,
+);
+
+/// The amount of entries used by the history heuristic, see `Bot::with_history_heuristic`.
+const HISTORY_SIZE: usize = 1 << 12;
+
+/// The maximum amount of actions analysed past the lowest depth, see `Game::is_quiet`.
+const QUIESCENCE_LIMIT: u32 = 16;
+
+/// The smallest difference between two fitness values and the functions used to add and subtract it.
+type NullWindow<T, S = <T as Game>::Fitness> = (
+    S,
+    fn(<T as Game>::Fitness, <T as Game>::Fitness) -> <T as Game>::Fitness,
+    fn(<T as Game>::Fitness, <T as Game>::Fitness) -> <T as Game>::Fitness,
+);
+
+/// The function used to revert actions, see `Bot::with_undo`.
+type UndoFn<T> = fn(&mut T, &<T as Game>::Action, <T as Game>::Player);
+
+/// The callback set using `Bot::on_terminal_found`.
+type OnTerminal<T> = dyn FnMut(TerminalKind, &Action<T>) + Send;
+
+/// The comparator given to `Bot::select_by`.
+type TieBreak<'a, T> = dyn Fn(&<T as Game>::Action, &<T as Game>::Action) -> Ordering + 'a;
+
+/// Checks `Game::validate_action` in debug builds, which is done before each call to `Game::execute`.
+#[inline]
+fn validate<T: Game>(state: &T, action: &T::Action, player: T::Player) {
+    debug_assert!(
+        state.validate_action(action, player),
+        "tried to execute an action which is invalid according to `Game::validate_action`"
+    );
 }
 
 impl<T: Game> Bot<T> {
     /// Creates a new `Bot` for the given `player`.
     pub fn new(player: T::Player) -> Self {
-        Self { player }
+        Self {
+            player,
+            max_depth: None,
+            root_action_limit: None,
+            repetition_penalty: None,
+            repetition_draw: None,
+            contempt: None,
+            futility_margin: None,
+            null_window: None,
+            partial_order: RetryOrder::Ascending,
+            trust_action_order: false,
+            order_key: false,
+            history_heuristic: false,
+            undo: None,
+            irreversible_limit: None,
+            transpositions: None,
+            persistent_transpositions: false,
+            pondered: None,
+            on_terminal: None,
+            path_cache: Vec::new(),
+            root_buffer: Vec::new(),
+        }
     }
 
-    /// Returns a chosen action based on the given game state.
+    /// Creates a new `Bot` for the given `player` which only considers the `limit` actions
+    /// with the highest initial fitness at the root.
     ///
-    /// Returns  `None` if no `Action` is possible or the bot is currently not the active player.
+    /// Unlike the root, all following states are still analysed completely, so the fitness of
+    /// each considered action is as accurate as with `Bot::new`. This is useful to restrict which
+    /// actions the bot may actually choose, without weakening its evaluation of them.
     ///
-    /// This method runs until either the best possible action was found
-    /// or one of `RunCondition::depth` and `RunCondition::step` returned `false`.
-    pub fn select<U: IntoRunCondition>(&mut self, state: &T, condition: U) -> Option<T::Action> {
-        self.inner_select(state, condition)
-            .map(|mut act| act.path.pop().unwrap())
+    /// Actions with an equal initial fitness are considered in the order returned by [`Game::actions`][act].
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 1).with_children(&[
+    ///         Node::new(true, 5),
+    ///     ]),
+    ///     Node::new(false, 9).with_children(&[
+    ///         Node::new(true, -5),
+    ///         Node::new(true, 7),
+    ///     ]),
+    /// ]);
+    ///
+    /// assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(0));
+    /// // Only `[1]` is considered, as it has the highest initial fitness.
+    /// let mut bot = Bot::with_root_action_limit(true, 1);
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+    /// ```
+    ///
+    /// [act]: ../trait.Game.html#tymethod.actions
+    pub fn with_root_action_limit(player: T::Player, limit: usize) -> Self {
+        let mut bot = Self::new(player);
+        bot.set_root_action_limit(Some(limit));
+        bot
     }
 
-    /// Similar to `select`, except that this function also returns the principal variation and the
-    /// final evaluation of the given action.
+    /// Only considers the `limit` actions with the highest initial fitness at the root,
+    /// see [`with_root_action_limit`][wral]. `None` considers all actions, which is the default.
     ///
-    /// The actions are sorted in order they are executed, so
-    /// `action.path[0]` is always equal to the result of `select`.
+    /// [wral]: struct.Bot.html#method.with_root_action_limit
+    pub fn set_root_action_limit(&mut self, limit: Option<usize>) {
+        self.root_action_limit = limit;
+    }
+
+    /// Creates a new `Bot` for the given `player` which uses [futility pruning][fut] with the given `margin`.
+    ///
+    /// Right before the last analysed depth, actions of the bot are skipped if their fitness
+    /// plus `margin` is still worse than the best already found alternative. This assumes that the fitness
+    /// can not change by more than `margin` with a single action, which speeds up the search
+    /// but may cause the bot to miss actions which only turn out well later on.
+    /// A larger `margin` prunes less, while being less likely to miss good actions.
+    ///
+    /// Note that `margin` is added to the fitness of these actions, so it must not overflow.
+    /// This is never done for [upper bounds][ub], as these can not be improved upon anyways.
+    ///
+    /// [fut]: https://www.chessprogramming.org/Futility_Pruning
+    /// [ub]: ../trait.Game.html#method.is_upper_bound
+    pub fn with_futility_margin(player: T::Player, margin: T::Fitness) -> Self
+    where
+        T::Fitness: Add<Output = T::Fitness>,
+    {
+        let mut bot = Self::new(player);
+        bot.set_futility_margin(Some(margin));
+        bot
+    }
+
+    /// Uses futility pruning with the given `margin`, see [`with_futility_margin`][wfm].
+    /// `None` disables futility pruning, which is the default.
+    ///
+    /// [wfm]: struct.Bot.html#method.with_futility_margin
+    pub fn set_futility_margin(&mut self, margin: Option<T::Fitness>)
+    where
+        T::Fitness: Add<Output = T::Fitness>,
+    {
+        fn add<F: Add<Output = F>>(fitness: F, margin: F) -> F {
+            fitness + margin
+        }
+
+        self.futility_margin = margin.map(|margin| (margin, add as fn(_, _) -> _));
+    }
+
+    /// Creates a new `Bot` for the given `player` which uses [principal variation search][pvs],
+    /// where `step` is the smallest possible difference between two fitness values, for example `1` for integers.
+    ///
+    /// After the first action of a state, which is expected to be the best one, all other actions are first
+    /// analysed using a null window, which only checks whether they are better than the first action.
+    /// This is cheaper than a complete analysis, but such an action has to be analysed again if it is actually better.
+    /// This is faster if the best action is usually analysed first, which is the case if the fitness
+    /// after a single action is a good estimate of its actual fitness.
+    ///
+    /// The result is the same as without principal variation search, as long as no two fitness values
+    /// differ by less than `step`. Note that `step` is added to and subtracted from fitness values
+    /// which are not an [upper][ub] or a [lower bound][lb] respectively, so this must not overflow.
     ///
     /// ```rust
     /// use rubot::{Bot, ToCompletion, tree::Node};
     ///
     /// # #[rustfmt::skip]
     /// let tree = Node::root().with_children(&[
-    ///     Node::new(true, 4),
-    ///     Node::new(true, 0).with_children(&[
-    ///         Node::new(true, 5), // This is the best possible result.
+    ///     Node::new(false, 6).with_children(&[
     ///         Node::new(true, 3),
-    ///     ])
+    ///         Node::new(true, 6),
+    ///     ]),
+    ///     Node::new(false, 4).with_children(&[
+    ///         Node::new(true, 4),
+    ///         Node::new(true, 5),
+    ///     ]),
+    ///     Node::new(false, 2).with_children(&[
+    ///         Node::new(true, 1),
+    ///     ]),
     /// ]);
     ///
-    /// assert_eq!(&Bot::new(true)
-    ///     .detailed_select(&tree, ToCompletion)
-    ///     .unwrap()
-    ///     .path, &[1, 0]);
+    /// let mut bot = Bot::with_principal_variation_search(true, 1);
+    /// let action = bot.detailed_select(&tree, ToCompletion).unwrap();
+    /// assert_eq!((action.fitness, &action.path[..]), (4, &[1, 0][..]));
+    ///
+    /// // The result is the same as without principal variation search.
+    /// let expected = Bot::new(true).detailed_select(&tree, ToCompletion).unwrap();
+    /// assert_eq!((expected.fitness, expected.path), (action.fitness, action.path));
     /// ```
-    pub fn detailed_select<U: IntoRunCondition>(
-        &mut self,
-        state: &T,
-        condition: U,
-    ) -> Option<Action<T>> {
-        self.inner_select(state, condition)
-            .map(|act| act.tap(|act| act.path.reverse()))
+    ///
+    /// [pvs]: https://www.chessprogramming.org/Principal_Variation_Search
+    /// [ub]: ../trait.Game.html#method.is_upper_bound
+    /// [lb]: ../trait.Game.html#method.is_lower_bound
+    pub fn with_principal_variation_search(player: T::Player, step: T::Fitness) -> Self
+    where
+        T::Fitness: Add<Output = T::Fitness> + Sub<Output = T::Fitness>,
+    {
+        let mut bot = Self::new(player);
+        bot.set_principal_variation_search(Some(step));
+        bot
     }
 
-    fn inner_select<U: IntoRunCondition>(&mut self, state: &T, condition: U) -> Option<Action<T>> {
-        let mut condition = condition.into_run_condition();
+    /// Uses principal variation search with the given `step`, see [`with_principal_variation_search`][wpvs].
+    /// `None` disables principal variation search, which is the default.
+    ///
+    /// This replaces the setting of [`set_fitness_step`][sfs].
+    ///
+    /// [wpvs]: struct.Bot.html#method.with_principal_variation_search
+    /// [sfs]: struct.Bot.html#method.set_fitness_step
+    pub fn set_principal_variation_search(&mut self, step: Option<T::Fitness>)
+    where
+        T::Fitness: Add<Output = T::Fitness> + Sub<Output = T::Fitness>,
+    {
+        self.null_window = step.map(|step| Self::null_window_fns(Some(step)));
+    }
 
-        let (active, actions) = state.actions(self.player);
-        if !active {
-            return None;
-        }
+    /// Creates a new `Bot` for the given `player` which uses [principal variation search][pvs] with
+    /// the step returned by [`Game::fitness_step`][fs] of the analysed state.
+    ///
+    /// This is the same as [`with_principal_variation_search`][wpvs], except that the step does not have to be known
+    /// when creating the bot. If the game does not have a `fitness_step`, principal variation search is not used.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, Logger, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 3).with_children(&[
+    ///         Node::new(true, 8),
+    ///         Node::new(true, 0),
+    ///     ]),
+    ///     Node::new(false, 2).with_children(&[
+    ///         Node::new(true, 2),
+    ///         Node::new(true, 5),
+    ///     ]),
+    /// ]);
+    ///
+    /// // `Node` uses a step of `1`, so this searches exactly like `with_principal_variation_search(true, 1)`.
+    /// let mut logger = Logger::new(ToCompletion);
+    /// assert_eq!(Bot::with_fitness_step(true).select(&tree, &mut logger), Some(1));
+    /// let steps = logger.steps();
+    /// let mut bot = Bot::with_principal_variation_search(true, 1);
+    /// assert_eq!(bot.select(&tree, &mut logger), Some(1));
+    /// assert_eq!(logger.steps(), steps);
+    /// ```
+    ///
+    /// [pvs]: https://www.chessprogramming.org/Principal_Variation_Search
+    /// [fs]: ../trait.Game.html#method.fitness_step
+    /// [wpvs]: struct.Bot.html#method.with_principal_variation_search
+    pub fn with_fitness_step(player: T::Player) -> Self
+    where
+        T::Fitness: Add<Output = T::Fitness> + Sub<Output = T::Fitness>,
+    {
+        let mut bot = Self::new(player);
+        bot.set_fitness_step(true);
+        bot
+    }
 
-        let actions: Vec<_> = actions
-            .into_iter()
-            .map(|action| Action {
-                fitness: state.look_ahead(&action, self.player),
-                path: vec![action],
-            })
-            .collect();
+    /// Uses principal variation search with the step returned by [`Game::fitness_step`][fs]
+    /// if `enabled` is `true`, see [`with_fitness_step`][wfs].
+    ///
+    /// This replaces the setting of [`set_principal_variation_search`][spvs].
+    ///
+    /// [fs]: ../trait.Game.html#method.fitness_step
+    /// [wfs]: struct.Bot.html#method.with_fitness_step
+    /// [spvs]: struct.Bot.html#method.set_principal_variation_search
+    pub fn set_fitness_step(&mut self, enabled: bool)
+    where
+        T::Fitness: Add<Output = T::Fitness> + Sub<Output = T::Fitness>,
+    {
+        self.null_window = if enabled {
+            Some(Self::null_window_fns(None))
+        } else {
+            None
+        };
+    }
 
-        if actions.is_empty() {
-            return None;
+    fn null_window_fns(step: Option<T::Fitness>) -> NullWindow<T, Option<T::Fitness>>
+    where
+        T::Fitness: Add<Output = T::Fitness> + Sub<Output = T::Fitness>,
+    {
+        fn add<F: Add<Output = F>>(fitness: F, step: F) -> F {
+            fitness + step
         }
 
-        let mut ctxt = Ctxt::new(state, self.player, actions);
+        fn sub<F: Sub<Output = F>>(fitness: F, step: F) -> F {
+            fitness - step
+        }
 
-        for depth in 0.. {
-            if !condition.depth(depth) {
-                return Some(ctxt.cancel());
-            }
+        (step, add as fn(_, _) -> _, sub as fn(_, _) -> _)
+    }
 
-            // Return early in case there is only one relevant action left.
-            // This is the case if we either only have one possible actions,
-            // or if all other possible actions are worse than the lower bound.
-            if let Some(exhausted) = ctxt.exhausted() {
-                return Some(exhausted);
-            }
+    /// Creates a new `Bot` for the given `player` which retries partially terminated actions in the given `order`.
+    ///
+    /// An action is partially terminated once all of its paths are known except for a cutoff
+    /// at the highest level. Each depth, the partially terminated actions which may still be better
+    /// than the currently best action are retried, by default starting with the least promising one.
+    /// Depending on the game, retrying the most promising actions first may result in more cutoffs.
+    ///
+    /// This does not change the selected action of a complete search.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    /// use rubot::alpha_beta::RetryOrder;
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 2).with_children(&[
+    ///         Node::new(true, 2),
+    ///         Node::new(true, 7),
+    ///     ]),
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 1),
+    ///         Node::new(true, 6),
+    ///     ]),
+    ///     Node::new(false, 4).with_children(&[
+    ///         Node::new(true, 0),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut ascending = Bot::with_partial_order(true, RetryOrder::Ascending);
+    /// let mut descending = Bot::with_partial_order(true, RetryOrder::Descending);
+    /// assert_eq!(ascending.select(&tree, ToCompletion), Some(0));
+    /// assert_eq!(descending.select(&tree, ToCompletion), Some(0));
+    /// ```
+    pub fn with_partial_order(player: T::Player, order: RetryOrder) -> Self {
+        let mut bot = Self::new(player);
+        bot.set_partial_order(order);
+        bot
+    }
 
-            let mut unfinished = mem::take(&mut ctxt.unfinished);
-            // Try unfinished actions with a high expected fitness first,
-            // as they are expected to give us a better alpha value.
-            unfinished.sort_by_key(|act| Reverse(act.fitness));
+    /// Retries partially terminated actions in the given `order`, see [`with_partial_order`][wpo].
+    /// The default is [`RetryOrder::Ascending`][asc].
+    ///
+    /// [wpo]: struct.Bot.html#method.with_partial_order
+    /// [asc]: enum.RetryOrder.html#variant.Ascending
+    pub fn set_partial_order(&mut self, order: RetryOrder) {
+        self.partial_order = order;
+    }
 
-            if let Some(best) = ctxt.best.take() {
-                // If computation is cancelled here, we don't know anything new,
-                // so we can just return the previous best action.
-                if let Some(ret) = ctxt.try_action(best, depth, &mut condition, |_, act| act) {
-                    return Some(ret);
-                }
-            }
+    /// Creates a new `Bot` for the given `player` which uses the order of [`Game::actions`][act]
+    /// instead of sorting actions by their fitness.
+    ///
+    /// By default, the actions of each state are sorted so the action with the best immediate fitness
+    /// is analysed first, which hopefully causes early cutoffs. Some games already
+    /// generate actions in a good order, for example by returning captures first in chess.
+    /// In this case sorting can be both unnecessary and worse than the original order.
+    /// The order is then also not changed depending on which actions previously caused a cutoff.
+    ///
+    /// This does not change the selected action of a complete search.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// // The actions of this tree are already sorted by their actual fitness.
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 1).with_children(&[
+    ///         Node::new(true, 5),
+    ///     ]),
+    ///     Node::new(false, 8).with_children(&[
+    ///         Node::new(true, -2),
+    ///         Node::new(true, 8),
+    ///     ]),
+    ///     Node::new(false, 9).with_children(&[
+    ///         Node::new(true, -5),
+    ///         Node::new(true, 7),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut bot = Bot::trust_action_order(true);
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(0));
+    /// assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(0));
+    /// ```
+    ///
+    /// [act]: ../trait.Game.html#tymethod.actions
+    pub fn trust_action_order(player: T::Player) -> Self {
+        let mut bot = Self::new(player);
+        bot.set_trust_action_order(true);
+        bot
+    }
 
-            for action in unfinished.into_iter() {
-                // In case computation is cancelled here, we may not yet have computed the best action of
-                // the previous depth, to guard against this, we add the cancelled action back to `state.unfinished`
-                // in case it is still empty.
-                let on_cancel = |ctxt: &mut Ctxt<T>, act| {
-                    if ctxt.unfinished.is_empty() {
-                        ctxt.unfinished.push(act);
-                    }
-                    ctxt.cancel()
-                };
+    /// Uses the order of [`Game::actions`][act] instead of sorting actions by their fitness
+    /// if `trust` is `true`, see [`trust_action_order`][tao].
+    ///
+    /// [act]: ../trait.Game.html#tymethod.actions
+    /// [tao]: struct.Bot.html#method.trust_action_order
+    pub fn set_trust_action_order(&mut self, trust: bool) {
+        self.trust_action_order = trust;
+    }
 
-                if let Some(ret) = ctxt.try_action(action, depth, &mut condition, on_cancel) {
-                    return Some(ret);
-                }
-            }
+    /// Creates a new `Bot` for the given `player` which sorts actions using [`Game::order_key`][ok].
+    ///
+    /// By default, the bot clones the state and executes each possible action before sorting them by their fitness.
+    /// With this setting, actions are instead sorted using their order key and only executed once they are analysed,
+    /// so actions which are cut off are never executed. This is useful for games with a large amount of actions
+    /// which can cheaply estimate which actions are promising.
+    ///
+    /// This does not change the selected action of a complete search.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 0).with_children(&[
+    ///         Node::new(true, 3),
+    ///         Node::new(true, 1),
+    ///         Node::new(true, 4),
+    ///     ]),
+    ///     Node::new(false, 0).with_children(&[
+    ///         Node::new(true, 2),
+    ///         Node::new(true, 6),
+    ///     ]),
+    /// ]);
+    ///
+    /// // The order key of `Node` is the fitness of the child, so actions are sorted without executing them.
+    /// let mut bot = Bot::with_order_key(true);
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+    /// assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(1));
+    /// ```
+    ///
+    /// [ok]: ../trait.Game.html#method.order_key
+    pub fn with_order_key(player: T::Player) -> Self {
+        let mut bot = Self::new(player);
+        bot.set_order_key(true);
+        bot
+    }
 
-            // We only test partially terminated action which may still be better than the best
-            // fitness at the current depth.
-            //
-            // As the current best fitness does not come from a terminated path,
-            // we still have to keep the other partially terminated actions around,
-            // in case the best fitness of a later depth is lower.
-            for action in ctxt.relevant_partials() {
-                // In case computation is cancelled here, we already tested at least some actions which were better than
-                // the cancelled partial action at the previous depth, so we can use `ctxt.cancel()` without any special
-                // considerations.
-                if let Some(ret) =
-                    ctxt.try_action(action, depth, &mut condition, |ctxt, _| ctxt.cancel())
-                {
-                    return Some(ret);
-                }
-            }
+    /// Sorts actions using [`Game::order_key`][ok] if `enabled` is `true`, see [`with_order_key`][wok].
+    ///
+    /// [ok]: ../trait.Game.html#method.order_key
+    /// [wok]: struct.Bot.html#method.with_order_key
+    pub fn set_order_key(&mut self, enabled: bool) {
+        self.order_key = enabled;
+    }
+
+    /// Creates a new `Bot` for the given `player` which uses the [history heuristic][hh] to sort actions.
+    ///
+    /// Whenever an action causes a cutoff, its [`hash_action`][ha] is rewarded, with cutoffs closer to the
+    /// initial state being rewarded more. Actions with the same fitness are then sorted by their reward,
+    /// so actions which often caused cutoffs anywhere in the tree are analysed first.
+    /// This is useful if a lot of actions have the same fitness, for example quiet moves in chess.
+    ///
+    /// Actions for which `hash_action` returns `None` are never rewarded.
+    /// This does not change the selected action of a complete search.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// // All actions have the same initial fitness.
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 0).with_children(&[
+    ///         Node::new(true, 0).with_children(&[Node::new(false, -3)]),
+    ///         Node::new(true, 0).with_children(&[Node::new(false, 2)]),
+    ///     ]),
+    ///     Node::new(false, 0).with_children(&[
+    ///         Node::new(true, 0).with_children(&[Node::new(false, 1)]),
+    ///         Node::new(true, 0).with_children(&[Node::new(false, 5)]),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut bot = Bot::with_history_heuristic(true);
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+    /// assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(1));
+    /// ```
+    ///
+    /// [hh]: https://www.chessprogramming.org/History_Heuristic
+    /// [ha]: ../trait.Game.html#method.hash_action
+    pub fn with_history_heuristic(player: T::Player) -> Self {
+        let mut bot = Self::new(player);
+        bot.set_history_heuristic(true);
+        bot
+    }
+
+    /// Uses the history heuristic to sort actions if `enabled` is `true`, see [`with_history_heuristic`][whh].
+    ///
+    /// [whh]: struct.Bot.html#method.with_history_heuristic
+    pub fn set_history_heuristic(&mut self, enabled: bool) {
+        self.history_heuristic = enabled;
+    }
+
+    /// Creates a new `Bot` for the given `player` which executes actions in place
+    /// and reverts them using [`Undo::undo`][undo] afterwards.
+    ///
+    /// By default, the bot clones the state for each analysed action. For games with a large state,
+    /// this is often the most expensive part of the search. With this setting, the state is only
+    /// cloned once for each action of the initial state.
+    ///
+    /// This does not change the selected action.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, Game, ToCompletion, Undo};
+    ///
+    /// // `Pile` is the game of the example of `Bot::with_transposition_table`.
+    /// # /// A pile of stones, both players take between 1 and 3 stones
+    /// # /// until the player who takes the last stone wins.
+    /// # #[derive(Clone)]
+    /// # struct Pile(u32, bool);
+    /// #
+    /// # impl Game for Pile {
+    /// #     type Player = bool;
+    /// #     type Action = u32;
+    /// #     type Fitness = bool;
+    /// #     type Actions = std::ops::RangeInclusive<u32>;
+    /// #
+    /// #     fn actions(&self, player: bool) -> (bool, Self::Actions) {
+    /// #         (player == self.1, 1..=self.0.min(3))
+    /// #     }
+    /// #
+    /// #     fn execute(&mut self, action: &u32, player: bool) -> bool {
+    /// #         self.0 -= action;
+    /// #         self.1 = !self.1;
+    /// #         self.0 == 0 && player != self.1
+    /// #     }
+    /// #
+    /// #     fn is_upper_bound(&self, fitness: bool, _: bool) -> bool {
+    /// #         fitness
+    /// #     }
+    /// #
+    /// #     fn key(&self, _: bool) -> Option<u64> {
+    /// #         Some(u64::from(self.0) * 2 + u64::from(self.1))
+    /// #     }
+    /// # }
+    ///
+    /// impl Undo for Pile {
+    ///     fn undo(&mut self, &action: &u32, _: bool) {
+    ///         self.0 += action;
+    ///         self.1 = !self.1;
+    ///     }
+    /// }
+    ///
+    /// let mut bot = Bot::with_undo(true);
+    /// assert_eq!(bot.select(&Pile(7, true), ToCompletion), Some(3));
+    /// ```
+    ///
+    /// [undo]: ../trait.Undo.html#tymethod.undo
+    pub fn with_undo(player: T::Player) -> Self
+    where
+        T: Undo,
+    {
+        let mut bot = Self::new(player);
+        bot.set_undo(true);
+        bot
+    }
+
+    /// Executes actions in place and reverts them using [`Undo::undo`][undo] if `enabled` is `true`,
+    /// see [`with_undo`][wu].
+    ///
+    /// [undo]: ../trait.Undo.html#tymethod.undo
+    /// [wu]: struct.Bot.html#method.with_undo
+    pub fn set_undo(&mut self, enabled: bool)
+    where
+        T: Undo,
+    {
+        self.undo = if enabled { Some(T::undo) } else { None };
+    }
+
+    /// Creates a new `Bot` for the given `player` which stops analysing a line once it contains
+    /// `limit` [irreversible actions][irr].
+    ///
+    /// The state reached by the last of these actions is treated as if it had no further actions,
+    /// so its fitness is used as is. This naturally bounds the search in games which are
+    /// always progressing, for example chess endgames where the material only decreases.
+    /// Unlike a depth limit, reversible actions are not counted.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, Game, ToCompletion};
+    ///
+    /// // `Pile` is the game of the example of `Bot::with_transposition_table`.
+    /// # /// A pile of stones, both players take between 1 and 3 stones
+    /// # /// until the player who takes the last stone wins.
+    /// # #[derive(Clone)]
+    /// # struct Pile(u32, bool);
+    /// impl Game for Pile {
+    /// #     type Player = bool;
+    /// #     type Action = u32;
+    /// #     type Fitness = bool;
+    /// #     type Actions = std::ops::RangeInclusive<u32>;
+    /// #
+    /// #     fn actions(&self, player: bool) -> (bool, Self::Actions) {
+    /// #         (player == self.1, 1..=self.0.min(3))
+    /// #     }
+    /// #
+    /// #     fn execute(&mut self, action: &u32, player: bool) -> bool {
+    /// #         self.0 -= action;
+    /// #         self.1 = !self.1;
+    /// #         self.0 == 0 && player != self.1
+    /// #     }
+    /// #
+    /// #     fn is_upper_bound(&self, fitness: bool, _: bool) -> bool {
+    /// #         fitness
+    /// #     }
+    /// #
+    /// #     fn key(&self, _: bool) -> Option<u64> {
+    /// #         Some(u64::from(self.0) * 2 + u64::from(self.1))
+    /// #     }
+    ///     // Taking 3 stones is irreversible.
+    ///     fn is_irreversible(&self, &action: &u32) -> bool {
+    ///         action == 3
+    ///     }
+    /// }
+    ///
+    /// // Taking all 3 stones wins the game.
+    /// assert_eq!(Bot::new(true).select(&Pile(3, true), ToCompletion), Some(3));
+    /// assert_eq!(Bot::new(true).solve(&Pile(5, true), 10), Some(true));
+    /// // Lines are not analysed after someone took 3 stones, so the bot does not see its win.
+    /// assert_eq!(Bot::with_irreversible_limit(true, 1).solve(&Pile(5, true), 10), Some(false));
+    /// ```
+    ///
+    /// [irr]: ../trait.Game.html#method.is_irreversible
+    pub fn with_irreversible_limit(player: T::Player, limit: u32) -> Self {
+        let mut bot = Self::new(player);
+        bot.set_irreversible_limit(Some(limit));
+        bot
+    }
+
+    /// Stops analysing a line once it contains `limit` irreversible actions, see [`with_irreversible_limit`][wil].
+    /// `None` removes this limit, which is the default.
+    ///
+    /// [wil]: struct.Bot.html#method.with_irreversible_limit
+    pub fn set_irreversible_limit(&mut self, limit: Option<u32>) {
+        self.irreversible_limit = limit;
+    }
+
+    /// Creates a new `Bot` for the given `player` which caches the results of analysed states
+    /// in a [transposition table][tt] with `size` entries.
+    ///
+    /// Many games reach the same state using different sequences of actions. Using [`Game::key`][key],
+    /// the bot recognizes these states and reuses the previous result if the state is analysed
+    /// up to the same depth and the result does not depend on the bounds used during its computation.
+    /// Results of states which were analysed until the end of the game are also reused by deeper searches.
+    /// The table has a fixed size, so once two results are stored at the same position, the older one is replaced.
+    ///
+    /// As the result then depends on the actions used to reach a state, the table is not used
+    /// while a [repetition penalty][rep] or an [irreversible limit][irr] is set.
+    /// The table is cleared at the start of each search, unless it is [persistent][per].
+    ///
+    /// This does not change the selected action of a complete search.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, Game, Logger, ToCompletion};
+    ///
+    /// /// A pile of stones, both players take between 1 and 3 stones
+    /// /// until the player who takes the last stone wins.
+    /// #[derive(Clone)]
+    /// struct Pile(u32, bool);
+    ///
+    /// impl Game for Pile {
+    ///     type Player = bool;
+    ///     type Action = u32;
+    ///     type Fitness = bool;
+    ///     type Actions = std::ops::RangeInclusive<u32>;
+    ///
+    ///     fn actions(&self, player: bool) -> (bool, Self::Actions) {
+    ///         (player == self.1, 1..=self.0.min(3))
+    ///     }
+    ///
+    ///     fn execute(&mut self, action: &u32, player: bool) -> bool {
+    ///         self.0 -= action;
+    ///         self.1 = !self.1;
+    ///         self.0 == 0 && player != self.1
+    ///     }
+    ///
+    ///     fn is_upper_bound(&self, fitness: bool, _: bool) -> bool {
+    ///         fitness
+    ///     }
+    ///
+    ///     fn key(&self, _: bool) -> Option<u64> {
+    ///         Some(u64::from(self.0) * 2 + u64::from(self.1))
+    ///     }
+    /// }
+    ///
+    /// let pile = Pile(21, true);
+    /// let mut logger = Logger::new(ToCompletion);
+    /// assert_eq!(Bot::new(true).select(&pile, &mut logger), Some(1));
+    /// let steps = logger.steps();
+    ///
+    /// let mut bot = Bot::with_transposition_table(true, 1024);
+    /// assert_eq!(bot.select(&pile, &mut logger), Some(1));
+    /// assert!(logger.steps() < steps);
+    /// ```
+    ///
+    /// [tt]: https://en.wikipedia.org/wiki/Transposition_table
+    /// [key]: ../trait.Game.html#method.key
+    /// [rep]: struct.Bot.html#method.set_repetition_penalty
+    /// [irr]: struct.Bot.html#method.with_irreversible_limit
+    /// [per]: struct.Bot.html#method.set_persistent_transpositions
+    pub fn with_transposition_table(player: T::Player, size: usize) -> Self {
+        let mut bot = Self::new(player);
+        bot.set_transposition_table(Some(size));
+        bot
+    }
+
+    /// Replaces the transposition table of this bot with an empty one with `size` entries,
+    /// see [`with_transposition_table`][wtt]. `None` removes the table, which is the default.
+    ///
+    /// [wtt]: struct.Bot.html#method.with_transposition_table
+    pub fn set_transposition_table(&mut self, size: Option<usize>) {
+        self.transpositions = size.map(TranspositionTable::new);
+    }
+
+    /// Creates a new `Bot` for the given `player` which keeps the results of previous searches,
+    /// which is useful when selecting actions for consecutive states of the same game.
+    ///
+    /// This uses a [persistent][per] [transposition table][tt] with `65536` entries.
+    /// As stored results are only used for states with the same [`Game::key`][key], results
+    /// of unrelated states are never used, so there is no need to invalidate the cache.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, Game, Logger, ToCompletion};
+    ///
+    /// // `Pile` is the game of the example of `Bot::with_transposition_table`.
+    /// # /// A pile of stones, both players take between 1 and 3 stones
+    /// # /// until the player who takes the last stone wins.
+    /// # #[derive(Clone)]
+    /// # struct Pile(u32, bool);
+    /// #
+    /// # impl Game for Pile {
+    /// #     type Player = bool;
+    /// #     type Action = u32;
+    /// #     type Fitness = bool;
+    /// #     type Actions = std::ops::RangeInclusive<u32>;
+    /// #
+    /// #     fn actions(&self, player: bool) -> (bool, Self::Actions) {
+    /// #         (player == self.1, 1..=self.0.min(3))
+    /// #     }
+    /// #
+    /// #     fn execute(&mut self, action: &u32, player: bool) -> bool {
+    /// #         self.0 -= action;
+    /// #         self.1 = !self.1;
+    /// #         self.0 == 0 && player != self.1
+    /// #     }
+    /// #
+    /// #     fn is_upper_bound(&self, fitness: bool, _: bool) -> bool {
+    /// #         fitness
+    /// #     }
+    /// #
+    /// #     fn key(&self, _: bool) -> Option<u64> {
+    /// #         Some(u64::from(self.0) * 2 + u64::from(self.1))
+    /// #     }
+    /// # }
+    ///
+    /// let pile = Pile(21, true);
+    /// let mut bot = Bot::with_cache(true);
+    /// let mut logger = Logger::new(ToCompletion);
+    /// assert_eq!(bot.select(&pile, &mut logger), Some(1));
+    /// let steps = logger.steps();
+    ///
+    /// // The second search reuses the results of the first one.
+    /// assert_eq!(bot.select(&pile, &mut logger), Some(1));
+    /// assert!(logger.steps() < steps);
+    /// ```
+    ///
+    /// [per]: struct.Bot.html#method.set_persistent_transpositions
+    /// [tt]: struct.Bot.html#method.with_transposition_table
+    /// [key]: ../trait.Game.html#method.key
+    pub fn with_cache(player: T::Player) -> Self {
+        let mut bot = Self::with_transposition_table(player, 1 << 16);
+        bot.set_persistent_transpositions(true);
+        bot
+    }
+
+    /// Sets the deepest depth analysed by all following searches, regardless of their [`RunCondition`][rc].
+    ///
+    /// This behaves like combining each run condition with [`Depth(depth)`][depth], which is useful for games
+    /// with unbounded sequences of actions while using a time based run condition. `None` removes this limit,
+    /// which is the default.
+    ///
+    /// This limit can be changed between calls to [`select`][sel], for example to adjust the difficulty
+    /// depending on how well the opponent did so far.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 3).with_children(&[
+    ///         Node::new(true, -1),
+    ///     ]),
+    ///     Node::new(false, 1).with_children(&[
+    ///         Node::new(true, 1),
+    ///         Node::new(true, 4),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// bot.set_max_depth(Some(0));
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(0));
+    ///
+    /// bot.set_max_depth(None);
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+    /// ```
+    ///
+    /// [rc]: ../trait.RunCondition.html
+    /// [depth]: ../struct.Depth.html
+    /// [sel]: struct.Bot.html#method.select
+    pub fn set_max_depth(&mut self, depth: Option<u32>) {
+        self.max_depth = depth;
+    }
+
+    /// Changes the playing strength of this bot, which is used by all following calls to [`select`][sel].
+    ///
+    /// At strength `level`, the bot analyses at most `level` depths, so it only looks at the immediate
+    /// outcome of its actions at `0`. `u8::MAX` removes this limit and is the default.
+    /// This is a shorthand for [`set_max_depth`][smd] and replaces any limit set using it.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 6).with_children(&[
+    ///         Node::new(true, 2),
+    ///     ]),
+    ///     Node::new(false, 4).with_children(&[
+    ///         Node::new(true, 5),
+    ///     ]),
+    /// ]);
+    ///
+    /// // The opponent lost the last game, so the bot plays weaker.
+    /// let mut bot = Bot::new(true);
+    /// bot.set_strength(0);
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(0));
+    ///
+    /// bot.set_strength(u8::MAX);
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+    /// ```
+    ///
+    /// [sel]: struct.Bot.html#method.select
+    /// [smd]: struct.Bot.html#method.set_max_depth
+    pub fn set_strength(&mut self, level: u8) {
+        if level == u8::MAX {
+            self.set_max_depth(None);
+        } else {
+            self.set_max_depth(Some(u32::from(level)));
+        }
+    }
+
+    /// Discourages lines which revisit a state already seen on the current search path,
+    /// by subtracting `penalty` from the fitness of all states reached after such a repetition.
+    ///
+    /// States are compared using [`Game::key`][key], so this has no effect for games
+    /// which do not implement it. `None` disables this heuristic, which is the default.
+    ///
+    /// This is a heuristic useful for games which are prone to shuffling back and forth without making any progress.
+    /// Note that this penalty is applied in addition to any draw by repetition rules of the game itself,
+    /// so a large `penalty` may cause the bot to prefer a worse line over a repetition which actually wins.
+    /// As a repeated state is penalized for both players, this can also cause the bot to expect
+    /// repetitions from its opponent.
+    ///
+    /// [key]: ../trait.Game.html#method.key
+    pub fn set_repetition_penalty(&mut self, penalty: Option<T::Fitness>)
+    where
+        T::Fitness: Sub<Output = T::Fitness>,
+    {
+        fn sub<F: Sub<Output = F>>(fitness: F, penalty: F) -> F {
+            fitness - penalty
+        }
+
+        self.repetition_penalty = penalty.map(|penalty| (penalty, sub as fn(_, _) -> _));
+    }
+
+    /// Treats states already seen on the current search path as a draw with the given fitness,
+    /// instead of analysing them again.
+    ///
+    /// This prevents the search from endlessly revisiting the same states in games where actions can be undone,
+    /// for example by moving a unit back and forth. States are compared using [`Game::key`][key],
+    /// so this has no effect for games which do not implement it. The [contempt][con] is also applied
+    /// to repeated states. `None` disables this, which is the default.
+    ///
+    /// [key]: ../trait.Game.html#method.key
+    /// [con]: struct.Bot.html#method.set_contempt
+    pub fn set_repetition_draw(&mut self, draw: Option<T::Fitness>) {
+        self.repetition_draw = draw;
+    }
+
+    /// Subtracts `contempt` from the fitness of all states which are a [draw][draw].
+    ///
+    /// A positive `contempt` makes the bot avoid draws and keep playing for a win, which is useful
+    /// against weaker opponents. A negative `contempt` instead steers the bot towards draws, which is
+    /// useful against stronger opponents. `None` treats draws like any other state, which is the default.
+    ///
+    /// This includes repeated states when using [`set_repetition_draw`][rep]. As the fitness is always
+    /// from the perspective of this bot, the opponent is expected to have the opposite preference.
+    ///
+    /// [draw]: ../trait.Game.html#method.is_draw
+    /// [rep]: struct.Bot.html#method.set_repetition_draw
+    pub fn set_contempt(&mut self, contempt: Option<T::Fitness>)
+    where
+        T::Fitness: Sub<Output = T::Fitness>,
+    {
+        fn sub<F: Sub<Output = F>>(fitness: F, contempt: F) -> F {
+            fitness - contempt
+        }
+
+        self.contempt = contempt.map(|contempt| (contempt, sub as fn(_, _) -> _));
+    }
+
+    /// Keeps the [transposition table][tt] between searches if `persistent` is `true`.
+    ///
+    /// This is useful if following searches analyse similar states, for example the state
+    /// after the opponent replied to the previously selected action. As the stored results
+    /// do not depend on the analysed state, this does not change the selected action of a complete search.
+    /// By default, the table is cleared at the start of each search.
+    ///
+    /// This has no effect if this bot does not use a transposition table.
+    ///
+    /// [tt]: struct.Bot.html#method.with_transposition_table
+    pub fn set_persistent_transpositions(&mut self, persistent: bool) {
+        self.persistent_transpositions = persistent;
+    }
+
+    /// Calls `f` as soon as the search proves that the game is decided, which is the case if
+    /// either an action reaches an [upper bound][ub] or all actions lead to a [lower bound][lb].
+    ///
+    /// As the search stops once a win is found, this only happens at most once per call to [`select`][sel].
+    /// The path of the given action is sorted in the order the actions are executed,
+    /// just like the result of [`detailed_select`][det].
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    /// use rubot::alpha_beta::TerminalKind;
+    /// use std::sync::mpsc;
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(true, 4),
+    ///     Node::new(true, 0).with_children(&[
+    ///         Node::new(true, i8::MAX),
+    ///     ]),
+    /// ]);
+    ///
+    /// let (sender, receiver) = mpsc::channel();
+    /// let mut bot = Bot::new(true);
+    /// bot.on_terminal_found(move |kind, action| sender.send((kind, action.path.clone())).unwrap());
+    ///
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+    /// assert_eq!(receiver.try_recv(), Ok((TerminalKind::Win, vec![1, 0])));
+    /// ```
+    ///
+    /// [ub]: ../trait.Game.html#method.is_upper_bound
+    /// [lb]: ../trait.Game.html#method.is_lower_bound
+    /// [sel]: struct.Bot.html#method.select
+    /// [det]: struct.Bot.html#method.detailed_select
+    pub fn on_terminal_found(&mut self, f: impl FnMut(TerminalKind, &Action<T>) + Send + 'static) {
+        self.on_terminal = Some(Box::new(f));
+    }
+
+    /// Returns all actions which are currently possible for this bot.
+    ///
+    /// Returns `None` if the bot is currently not the active player. Note that this ignores
+    /// [`with_root_action_limit`][limit], so all legal actions are returned.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 7),
+    ///     Node::new(false, 5),
+    /// ]);
+    ///
+    /// assert_eq!(Bot::new(true).legal_actions(&tree), Some(vec![0, 1]));
+    /// assert_eq!(Bot::new(false).legal_actions(&tree), None);
+    /// ```
+    ///
+    /// [limit]: struct.Bot.html#method.with_root_action_limit
+    pub fn legal_actions(&self, state: &T) -> Option<Vec<T::Action>> {
+        let (active, actions) = state.actions(self.player);
+        if active {
+            Some(actions.into_iter().collect())
+        } else {
+            None
+        }
+    }
+
+    /// Returns a chosen action based on the given game state.
+    ///
+    /// Returns  `None` if no `Action` is possible or the bot is currently not the active player.
+    /// Use [`try_select`][ts] to find out why no action was returned.
+    ///
+    /// This method runs until either the best possible action was found
+    /// or one of `RunCondition::depth` and `RunCondition::step` returned `false`.
+    ///
+    /// If multiple actions are equally good, the one with the highest [`rarity`][rar] is chosen.
+    /// Out of all actions with the same rarity, the bot prefers the action with the greatest fitness
+    /// returned by [`look_ahead`][la] and then the one which is returned first by [`actions`][act].
+    /// This does not apply to wins and losses, which instead prefer the shortest win and the longest loss.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 5),
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 5),
+    ///     ]),
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 5),
+    ///     ]),
+    /// ]);
+    ///
+    /// // All actions have a fitness of `5`.
+    /// assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(1));
+    /// ```
+    ///
+    /// [ts]: struct.Bot.html#method.try_select
+    /// [rar]: ../trait.Game.html#method.rarity
+    /// [la]: ../trait.Game.html#method.look_ahead
+    /// [act]: ../trait.Game.html#tymethod.actions
+    pub fn select<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Option<T::Action> {
+        self.try_select(state, condition).ok()
+    }
+
+    /// Similar to `select`, except that this function returns the reason why no action was chosen.
+    ///
+    /// ```rust
+    /// use rubot::{alpha_beta::SelectError, Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 7),
+    ///     Node::new(false, 5),
+    /// ]);
+    ///
+    /// assert_eq!(Bot::new(true).try_select(&tree, ToCompletion), Ok(0));
+    /// assert_eq!(Bot::new(false).try_select(&tree, ToCompletion), Err(SelectError::NotActivePlayer));
+    /// assert_eq!(Bot::new(true).try_select(&Node::root(), ToCompletion), Err(SelectError::Terminal));
+    /// let mut bot = Bot::with_root_action_limit(true, 0);
+    /// assert_eq!(bot.try_select(&tree, ToCompletion), Err(SelectError::NoActions));
+    /// ```
+    pub fn try_select<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Result<T::Action, SelectError> {
+        self.search(state, condition, SearchOptions::default())
+            .map(|mut act| act.path.pop().unwrap())
+    }
+
+    /// Similar to `select`, except that this function also returns the principal variation and the
+    /// final evaluation of the given action.
+    ///
+    /// The actions are sorted in order they are executed, so
+    /// `action.path[0]` is always equal to the result of `select`.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(true, 4),
+    ///     Node::new(true, 0).with_children(&[
+    ///         Node::new(true, 5), // This is the best possible result.
+    ///         Node::new(true, 3),
+    ///     ])
+    /// ]);
+    ///
+    /// assert_eq!(&Bot::new(true)
+    ///     .detailed_select(&tree, ToCompletion)
+    ///     .unwrap()
+    ///     .path, &[1, 0]);
+    /// ```
+    pub fn detailed_select<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Option<Action<T>> {
+        self.inner_select(state, condition).map(|mut act| {
+            act.path.reverse();
+            act
+        })
+    }
+
+    /// Similar to `select`, except that this function also returns the final evaluation of the chosen action.
+    ///
+    /// This is cheaper than `detailed_select` if the principal variation is not needed,
+    /// as it is not collected.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(true, 4),
+    ///     Node::new(true, 0).with_children(&[
+    ///         Node::new(true, 5), // This is the best possible result.
+    ///         Node::new(true, 3),
+    ///     ])
+    /// ]);
+    ///
+    /// assert_eq!(Bot::new(true).select_scored(&tree, ToCompletion), Some((1, 5)));
+    /// ```
+    pub fn select_scored<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Option<(T::Action, T::Fitness)> {
+        self.inner_select(state, condition).map(|mut act| {
+            let action = act.path.pop().unwrap();
+            // The remaining path is reused by the next search.
+            act.path.clear();
+            self.path_cache.push(act.path);
+            (action, act.fitness)
+        })
+    }
+
+    /// A convenience method over `select`, which also executes the chosen action on `state`.
+    ///
+    /// This searches for the best action of `state` exactly like `select`, and then calls
+    /// [`Game::execute`][exe] with the chosen action and the player of this bot before returning it.
+    /// The state is not changed if no action was chosen.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let mut tree = Node::root().with_children(&[
+    ///     Node::new(false, 4),
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 5),
+    ///         Node::new(true, 3),
+    ///     ])
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// assert_eq!(bot.select_and_apply(&mut tree, ToCompletion), Some(0));
+    /// assert!(tree.is_leaf());
+    /// // The game is over, so there is no action left.
+    /// assert_eq!(bot.select_and_apply(&mut tree, ToCompletion), None);
+    /// ```
+    ///
+    /// [exe]: ../trait.Game.html#tymethod.execute
+    pub fn select_and_apply<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &mut T,
+        condition: U,
+    ) -> Option<T::Action> {
+        let action = self.select(state, condition)?;
+        validate(state, &action, self.player);
+        state.execute(&action, self.player);
+        Some(action)
+    }
+
+    /// Returns all states analysed during the last completed depth while searching for the best action of `state`.
+    ///
+    /// Each analysed action is stored together with its fitness and whether it was pruned,
+    /// in the order in which the actions were analysed. As every analysed state is kept in memory,
+    /// this is only intended for small games or positions, for example to visualize the search.
+    /// Root actions which were already completely analysed at a previous depth are not analysed again,
+    /// so they are not part of the returned tree.
+    ///
+    /// Returns `None` if no `Action` is possible, the bot is currently not the active player
+    /// or no depth was completed before `condition` stopped the search.
+    ///
+    /// ```rust
+    /// use rubot::{alpha_beta::Bound, Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 4).with_children(&[
+    ///         Node::new(true, 6),
+    ///         Node::new(true, 2),
+    ///     ]),
+    ///     Node::new(false, 3).with_children(&[
+    ///         Node::new(true, 3),
+    ///     ]),
+    /// ]);
+    ///
+    /// let analysis = Bot::new(true).analyze(&tree, ToCompletion).unwrap();
+    /// assert_eq!(analysis.depth, 1);
+    /// let first = &analysis.children[0];
+    /// assert_eq!((first.action, first.fitness, first.bound), (1, 3, Bound::Exact));
+    /// // As the opponent can reach `2`, the remaining actions of `[0]` are pruned.
+    /// let second = &analysis.children[1];
+    /// assert_eq!((second.action, second.fitness, second.bound), (0, 2, Bound::Upper));
+    /// assert_eq!(second.children.len(), 1);
+    /// ```
+    pub fn analyze<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Option<AnalysisTree<T>> {
+        let mut analysis = None;
+        let options = SearchOptions {
+            analysis: Some(&mut analysis),
+            ..SearchOptions::default()
+        };
+        self.search(state, condition, options).ok()?;
+        analysis
+    }
+
+    /// Returns the game-theoretic value of `state`, assuming perfect play by every player.
+    ///
+    /// Unlike [`select`][sel], this returns the exact fitness of the best action instead of
+    /// the action itself. As some games are too large to be analysed completely, the search
+    /// is limited to `max_depth`.
+    ///
+    /// Returns `None` if no `Action` is possible, the bot is currently not the active player
+    /// or the game could not be solved within `max_depth`.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 2).with_children(&[
+    ///         Node::new(true, 2).with_children(&[
+    ///             Node::new(false, 6),
+    ///             Node::new(false, 3),
+    ///         ]),
+    ///     ]),
+    ///     Node::new(false, 4).with_children(&[
+    ///         Node::new(true, 0),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// assert_eq!(bot.solve(&tree, 10), Some(6));
+    /// // The tree can not be solved by only looking at the first action.
+    /// assert_eq!(bot.solve(&tree, 1), None);
+    /// ```
+    ///
+    /// [sel]: struct.Bot.html#method.select
+    pub fn solve(&mut self, state: &T, max_depth: u32) -> Option<T::Fitness> {
+        // Limit the condition itself, so we notice if we stopped early.
+        let max_depth = self
+            .max_depth
+            .map_or(max_depth, |max| cmp::min(max, max_depth));
+        let mut condition = Stopped::new(Depth(max_depth).into_run_condition());
+        // Decisive actions are not necessarily the value of the game.
+        let options = SearchOptions {
+            stop_decisive: false,
+            ..SearchOptions::default()
+        };
+        let action = self.search(state, &mut condition, options).ok()?;
+        if !condition.stopped {
+            Some(action.fitness)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the minimum amount of plies needed to reach an [upper bound][ub],
+    /// regardless of the actions of the opponent.
+    ///
+    /// Returns `None` if there is no such forced win in at most `max_plies` plies.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 0).with_children(&[
+    ///         Node::new(true, 0).with_children(&[
+    ///             Node::new(true, i8::MAX),
+    ///         ]),
+    ///     ]),
+    ///     Node::new(false, 5),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// assert_eq!(bot.forced_win_in(&tree, 2), None);
+    /// assert_eq!(bot.forced_win_in(&tree, 5), Some(3));
+    /// ```
+    ///
+    /// [ub]: ../trait.Game.html#method.is_upper_bound
+    pub fn forced_win_in(&mut self, state: &T, max_plies: u32) -> Option<u32> {
+        // A search up to `Depth(max_plies - 1)` looks at most `max_plies` actions ahead.
+        // As the shortest win is preferred, the path of the selected action is the shortest forced win.
+        let action = self.inner_select(state, Depth(max_plies.checked_sub(1)?))?;
+        if state.is_upper_bound(action.fitness, self.player) {
+            Some(action.path.len() as u32)
+        } else {
+            None
+        }
+    }
+
+    /// Similar to `select`, except that `tie_break` is used to choose between root actions with the same fitness,
+    /// preferring the greater action.
+    ///
+    /// `tie_break` is only used if both actions also have the same [`rarity`][rar], actions which are equal
+    /// according to `tie_break` are compared as described in [`select`][sel]. To still find the preferred action,
+    /// root actions which are preferred over the current best action are analysed again if they may be equally good.
+    /// All other cutoffs are unaffected.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 6).with_children(&[
+    ///         Node::new(true, 3),
+    ///         Node::new(true, 8),
+    ///     ]),
+    ///     Node::new(false, 2).with_children(&[
+    ///         Node::new(true, 3),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// // Both actions have a fitness of `3`.
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(0));
+    /// assert_eq!(bot.select_by(&tree, ToCompletion, |a, b| a.cmp(b)), Some(1));
+    /// ```
+    ///
+    /// [rar]: ../trait.Game.html#method.rarity
+    /// [sel]: struct.Bot.html#method.select
+    pub fn select_by<U: IntoRunCondition<T::Fitness>, F: Fn(&T::Action, &T::Action) -> Ordering>(
+        &mut self,
+        state: &T,
+        condition: U,
+        tie_break: F,
+    ) -> Option<T::Action> {
+        let options = SearchOptions {
+            tie_break: Some(&tie_break),
+            ..SearchOptions::default()
+        };
+        self.search(state, condition, options)
+            .ok()
+            .map(|mut act| act.path.pop().unwrap())
+    }
+
+    /// Similar to `select`, except that one of the root actions with the same fitness as the best action
+    /// is chosen at random.
+    ///
+    /// Each of these actions is equally likely, except for actions with a lower [`rarity`][rar],
+    /// which are never chosen. The result is deterministic, so selecting an action for the same
+    /// state using the same `seed` always returns the same action.
+    ///
+    /// The root actions are shuffled once before the search, which replaces the order of
+    /// [`Game::actions`][act] used to break the remaining ties, see [`select_by`][sb].
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 1),
+    ///     Node::new(false, 4),
+    ///     Node::new(false, 4),
+    ///     Node::new(false, 4),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// let action = bot.select_random(&tree, ToCompletion, 42);
+    /// assert!(action == Some(1) || action == Some(2) || action == Some(3));
+    /// assert_eq!(bot.select_random(&tree, ToCompletion, 42), action);
+    /// ```
+    ///
+    /// [rar]: ../trait.Game.html#method.rarity
+    /// [act]: ../trait.Game.html#tymethod.actions
+    /// [sb]: struct.Bot.html#method.select_by
+    pub fn select_random<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+        seed: u32,
+    ) -> Option<T::Action> {
+        let (_, actions) = state.actions(self.player);
+        let mut actions: Vec<_> = actions.into_iter().collect();
+        // A random permutation, so the first of any subset of actions is equally likely to be each of them.
+        let mut rng = XorShiftRng::new(seed);
+        for i in (1..actions.len()).rev() {
+            actions.swap(i, rng.next_u32() as usize % (i + 1));
+        }
+
+        let options = SearchOptions {
+            root_order: Some(actions),
+            ..SearchOptions::default()
+        };
+        self.search(state, condition, options)
+            .ok()
+            .map(|mut act| act.path.pop().unwrap())
+    }
+
+    /// Returns the chosen action together with the fitness of the best and the worst root action.
+    ///
+    /// The best fitness is the fitness of the chosen action, equal to the `fitness` returned by
+    /// [`detailed_select`][det]. The worst fitness is the most recent evaluation of the worst root action.
+    /// As actions which are clearly worse than the chosen one are often pruned, this evaluation
+    /// is only an upper limit for such actions and may also come from an earlier depth,
+    /// so the worst fitness should be considered approximate.
+    ///
+    /// This is useful to get a quick sense of how forced the current state is.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 6).with_children(&[
+    ///         Node::new(true, -1),
+    ///     ]),
+    ///     Node::new(false, 2).with_children(&[
+    ///         Node::new(true, 4),
+    ///     ]),
+    ///     Node::new(false, 3),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// // `[0]` looks good at first, but may lose a lot.
+    /// assert_eq!(bot.select_with_range(&tree, ToCompletion), Some((1, 4, -1)));
+    /// ```
+    ///
+    /// [det]: struct.Bot.html#method.detailed_select
+    pub fn select_with_range<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Option<(T::Action, T::Fitness, T::Fitness)> {
+        let mut root = Vec::new();
+        let options = SearchOptions {
+            root: Some(&mut root),
+            ..SearchOptions::default()
+        };
+        let mut action = self.search(state, condition, options).ok()?;
+        let worst = root.into_iter().map(|act| act.fitness).min().unwrap();
+        Some((action.path.pop().unwrap(), action.fitness, worst))
+    }
+
+    /// Returns every root action together with its fitness and principal variation,
+    /// sorted from the best to the worst action.
+    ///
+    /// Just like for [`detailed_select`][det], the actions of each path are sorted in the order they are executed.
+    /// To get the exact fitness of each root action, root actions are never cut off,
+    /// similar to [`select_no_root_cutoff`][nrc]. Actions with the same fitness are sorted by their
+    /// [`rarity`][rar] and then by their order in [`Game::actions`][act], so the result is deterministic.
+    ///
+    /// If the search is stopped by `condition` or because a winning action was found, the result is
+    /// only approximate, as some actions were already analysed at the current depth while others were not.
+    ///
+    /// Returns an empty `Vec` if no `Action` is possible or the bot is currently not the active player.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 3).with_children(&[
+    ///         Node::new(true, 3),
+    ///         Node::new(true, -4),
+    ///     ]),
+    ///     Node::new(false, 0).with_children(&[
+    ///         Node::new(true, 1),
+    ///     ]),
+    ///     Node::new(false, 2),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// let ranking = bot.rank_actions(&tree, ToCompletion);
+    /// let ranking: Vec<_> = ranking.iter().map(|act| (act.fitness, &act.path[..])).collect();
+    /// assert_eq!(ranking, &[(2, &[2][..]), (1, &[1, 0]), (-4, &[0, 1])]);
+    /// ```
+    ///
+    /// [det]: struct.Bot.html#method.detailed_select
+    /// [nrc]: struct.Bot.html#method.select_no_root_cutoff
+    /// [rar]: ../trait.Game.html#method.rarity
+    /// [act]: ../trait.Game.html#tymethod.actions
+    pub fn rank_actions<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Vec<Action<T>> {
+        let mut root = Vec::new();
+        let options = SearchOptions {
+            root: Some(&mut root),
+            root_cutoff: false,
+            ..SearchOptions::default()
+        };
+        // In case no action may be chosen, `root` stays empty.
+        let _ = self.search(state, condition, options);
+
+        // `sort_by_cached_key` is stable, so equal actions keep the order of `Game::actions`.
+        root.sort_by_cached_key(|act| {
+            let rarity = state.rarity(act.path.last().unwrap(), self.player);
+            Reverse((act.fitness, rarity))
+        });
+        for act in root.iter_mut() {
+            act.path.reverse();
+        }
+        root
+    }
+
+    /// Returns the `k` best root actions together with their fitness and principal variation,
+    /// sorted from the best to the worst action.
+    ///
+    /// This is equal to the first `k` actions returned by [`rank_actions`][rank].
+    /// As the exact fitness of the second to `k`th best action is needed, root actions are never cut off.
+    /// This method is therefore slower than [`select`][sel], which only has to prove that an action is better
+    /// than all others.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 6),
+    ///     Node::new(false, 2).with_children(&[
+    ///         Node::new(true, 9),
+    ///     ]),
+    ///     Node::new(false, 4),
+    ///     Node::new(false, 1),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// let top = bot.select_top_k(&tree, ToCompletion, 2);
+    /// let fitness: Vec<_> = top.iter().map(|act| act.fitness).collect();
+    /// assert_eq!(fitness, &[9, 6]);
+    /// ```
+    ///
+    /// [rank]: struct.Bot.html#method.rank_actions
+    /// [sel]: struct.Bot.html#method.select
+    pub fn select_top_k<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+        k: usize,
+    ) -> Vec<Action<T>> {
+        let mut ranking = self.rank_actions(state, condition);
+        ranking.truncate(k);
+        ranking
+    }
+
+    /// Similar to `select`, except that this function also returns whether the chosen action
+    /// is proven to be the best possible action.
+    ///
+    /// This is the case if the search either analysed the complete game tree or found a winning action.
+    /// It is `false` if the search was stopped early, either by `condition`, the maximum depth
+    /// of the bot, or because the best action is already [decisive][dec].
+    ///
+    /// ```rust
+    /// use rubot::{Bot, Depth, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 1).with_children(&[
+    ///         Node::new(true, 5),
+    ///     ]),
+    ///     Node::new(false, 9).with_children(&[
+    ///         Node::new(true, -5),
+    ///         Node::new(true, 7),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// // Without searching, `[1]` looks better than `[0]`.
+    /// assert_eq!(bot.select_checked(&tree, Depth(0)), (Some(1), false));
+    /// assert_eq!(bot.select_checked(&tree, ToCompletion), (Some(0), true));
+    /// ```
+    ///
+    /// [dec]: ../trait.Game.html#method.is_decisive
+    pub fn select_checked<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> (Option<T::Action>, bool) {
+        let mut proven = false;
+        let options = SearchOptions {
+            proven: Some(&mut proven),
+            ..SearchOptions::default()
+        };
+        let action = self
+            .search(state, condition, options)
+            .ok()
+            .map(|mut act| act.path.pop().unwrap());
+        (action, proven)
+    }
+
+    /// Similar to `select`, except that this function also returns whether the bot
+    /// expects to win or lose after the chosen action.
+    ///
+    /// The verdict is based on whether the expected fitness of the chosen action is an
+    /// [upper bound][ub] or a [lower bound][lb]. If every action leads to a loss,
+    /// the chosen action is the one which delays the loss the longest.
+    ///
+    /// ```rust
+    /// use rubot::{alpha_beta::Verdict, Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 0).with_children(&[
+    ///         Node::new(true, i8::MIN),
+    ///     ]),
+    ///     Node::new(false, i8::MIN),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// assert_eq!(bot.select_with_verdict(&tree, ToCompletion), Some((0, Verdict::Losing)));
+    /// ```
+    ///
+    /// [ub]: ../trait.Game.html#method.is_upper_bound
+    /// [lb]: ../trait.Game.html#method.is_lower_bound
+    pub fn select_with_verdict<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Option<(T::Action, Verdict)> {
+        let mut action = self.inner_select(state, condition)?;
+        let verdict = self.verdict(state, action.fitness);
+        Some((action.path.pop().unwrap(), verdict))
+    }
+
+    /// Similar to `select`, except that this function returns an [`Explanation`][exp] of the chosen action,
+    /// containing its fitness, the expected answers and the [`Verdict`][verdict] of the bot.
+    ///
+    /// The explanation implements `Display` if both `T::Action` and `T::Fitness` implement `Debug`,
+    /// which is useful to show the reasoning of the bot to players.
+    ///
+    /// ```rust
+    /// use rubot::{alpha_beta::Verdict, Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 2).with_children(&[
+    ///         Node::new(true, 4).with_children(&[
+    ///             Node::new(false, 3),
+    ///         ]),
+    ///         Node::new(true, 6),
+    ///     ]),
+    ///     Node::new(false, 3).with_children(&[
+    ///         Node::new(true, -1),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// let explanation = bot.explain(&tree, ToCompletion).unwrap();
+    /// assert_eq!(explanation.action, 0);
+    /// assert_eq!(explanation.fitness, 3);
+    /// assert_eq!(explanation.line, &[0, 0]);
+    /// assert_eq!(explanation.verdict, Verdict::Unknown);
+    /// assert_eq!(
+    ///     explanation.to_string(),
+    ///     "0 with fitness 3 (the outcome is unknown), expecting 0, 0"
+    /// );
+    /// ```
+    ///
+    /// [exp]: struct.Explanation.html
+    /// [verdict]: enum.Verdict.html
+    pub fn explain<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Option<Explanation<T>> {
+        let mut action = self.inner_select(state, condition)?;
+        let verdict = self.verdict(state, action.fitness);
+        let first = action.path.pop().unwrap();
+        action.path.reverse();
+        Some(Explanation {
+            action: first,
+            fitness: action.fitness,
+            line: action.path,
+            verdict,
+        })
+    }
+
+    /// Returns the expected outcome after an action with the given `fitness`.
+    fn verdict(&self, state: &T, fitness: T::Fitness) -> Verdict {
+        if state.is_upper_bound(fitness, self.player) {
+            Verdict::Winning
+        } else if state.is_lower_bound(fitness, self.player) {
+            Verdict::Losing
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Similar to `select`, except that root actions are never cut off.
+    ///
+    /// Usually, root actions which are already known to be worse than the current best action
+    /// are only analysed until this is proven. This method instead computes the exact fitness of every
+    /// root action at each depth, while still using cutoffs for all deeper states. This is slower than `select`
+    /// but results in an accurate evaluation of each root action, which is useful when analysing a game.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, Logger, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 1).with_children(&[
+    ///         Node::new(true, 5),
+    ///     ]),
+    ///     Node::new(false, 9).with_children(&[
+    ///         Node::new(true, -5),
+    ///         Node::new(true, 7),
+    ///     ]),
+    ///     Node::new(false, 8).with_children(&[
+    ///         Node::new(true, -2),
+    ///         Node::new(true, 8),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// let mut logger = Logger::new(ToCompletion);
+    /// assert_eq!(bot.select(&tree, &mut logger), Some(0));
+    /// let steps = logger.steps();
+    ///
+    /// // Every reply to `[1]` and `[2]` is analysed, even though the first one is already worse than `[0]`.
+    /// assert_eq!(bot.select_no_root_cutoff(&tree, &mut logger), Some(0));
+    /// assert!(logger.steps() > steps);
+    /// ```
+    pub fn select_no_root_cutoff<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Option<T::Action> {
+        let options = SearchOptions {
+            root_cutoff: false,
+            ..SearchOptions::default()
+        };
+        self.search(state, condition, options)
+            .ok()
+            .map(|mut act| act.path.pop().unwrap())
+    }
+
+    /// Similar to `select`, except that only the actions in `allowed` are considered for the initial state.
+    ///
+    /// All deeper states still consider all of their actions. Actions in `allowed` which are not
+    /// returned by [`Game::actions`][act] are ignored, so this returns `None` if none of them are possible.
+    /// This is useful to find the best action of a given kind, for example the best capture in chess.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 2),
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 3),
+    ///     ]),
+    ///     Node::new(false, 4).with_children(&[
+    ///         Node::new(true, 1),
+    ///         Node::new(true, 0),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+    /// assert_eq!(bot.select_subset(&tree, ToCompletion, vec![0, 2]), Some(0));
+    /// assert_eq!(bot.select_subset(&tree, ToCompletion, vec![7]), None);
+    /// ```
+    ///
+    /// [act]: ../trait.Game.html#tymethod.actions
+    pub fn select_subset<U: IntoRunCondition<T::Fitness>, I: IntoIterator<Item = T::Action>>(
+        &mut self,
+        state: &T,
+        condition: U,
+        allowed: I,
+    ) -> Option<T::Action> {
+        let allowed: Vec<_> = allowed.into_iter().collect();
+        let options = SearchOptions {
+            allowed: Some(&allowed),
+            ..SearchOptions::default()
+        };
+        self.search(state, condition, options)
+            .ok()
+            .map(|mut act| act.path.pop().unwrap())
+    }
+
+    /// Analyses the state reached by the opponent playing `predicted` on `state`, which allows the bot
+    /// to think while waiting for the opponent. Returns the action the bot would choose in that state.
+    ///
+    /// The results of this search are stored in the [transposition table][tt] of this bot.
+    /// They are reused by the next search if its state was analysed while pondering, even if the table
+    /// is not [persistent][per]. This is the case if the opponent chose `predicted`, but also if the
+    /// actual action of the opponent leads to a state which was reached by any of the analysed lines,
+    /// for example by taking the same actions in a different order. On such a miss, the action
+    /// of the deepest result stored for the actual state is analysed first.
+    ///
+    /// States are compared using [`Game::key`][key], so this only helps if the bot uses a transposition table
+    /// and the game supports keys. If the state of the next search was not analysed, the results are discarded
+    /// at the start of this search, just like the results of any other previous search,
+    /// so a wrong prediction does not change the behavior of the bot.
+    ///
+    /// `predicted` must be a possible action of the opponent in `state`.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, Game, Logger, ToCompletion};
+    ///
+    /// // `Pile` is the game of the example of `Bot::with_transposition_table`.
+    /// # /// A pile of stones, both players take between 1 and 3 stones
+    /// # /// until the player who takes the last stone wins.
+    /// # #[derive(Clone)]
+    /// # struct Pile(u32, bool);
+    /// #
+    /// # impl Game for Pile {
+    /// #     type Player = bool;
+    /// #     type Action = u32;
+    /// #     type Fitness = bool;
+    /// #     type Actions = std::ops::RangeInclusive<u32>;
+    /// #
+    /// #     fn actions(&self, player: bool) -> (bool, Self::Actions) {
+    /// #         (player == self.1, 1..=self.0.min(3))
+    /// #     }
+    /// #
+    /// #     fn execute(&mut self, action: &u32, player: bool) -> bool {
+    /// #         self.0 -= action;
+    /// #         self.1 = !self.1;
+    /// #         self.0 == 0 && player != self.1
+    /// #     }
+    /// #
+    /// #     fn is_upper_bound(&self, fitness: bool, _: bool) -> bool {
+    /// #         fitness
+    /// #     }
+    /// #
+    /// #     fn key(&self, _: bool) -> Option<u64> {
+    /// #         Some(u64::from(self.0) * 2 + u64::from(self.1))
+    /// #     }
+    /// # }
+    ///
+    /// // While the opponent is thinking, analyse the state after they took a single stone.
+    /// let mut bot = Bot::with_transposition_table(true, 1024);
+    /// let mut pile = Pile(18, false);
+    /// assert_eq!(bot.ponder(&pile, &1, ToCompletion), Some(1));
+    ///
+    /// // The opponent took 3 stones instead, which results in the same state
+    /// // as both players taking a single stone after the predicted action.
+    /// pile.execute(&3, false);
+    /// let mut logger = Logger::new(ToCompletion);
+    /// assert_eq!(bot.select(&pile, &mut logger), Some(3));
+    /// let steps = logger.steps();
+    /// let mut fresh = Bot::with_transposition_table(true, 1024);
+    /// assert_eq!(fresh.select(&pile, &mut logger), Some(3));
+    /// assert!(steps < logger.steps());
+    /// ```
+    ///
+    /// [tt]: struct.Bot.html#method.with_transposition_table
+    /// [per]: struct.Bot.html#method.set_persistent_transpositions
+    /// [key]: ../trait.Game.html#method.key
+    pub fn ponder<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        predicted: &T::Action,
+        condition: U,
+    ) -> Option<T::Action> {
+        let mut state = state.clone();
+        validate(&state, predicted, self.player);
+        state.execute(predicted, self.player);
+        let action = self.select(&state, condition);
+        self.pondered = state.key(self.player);
+        action
+    }
+
+    /// Similar to `select`, except that the state kept between searches is stored in `context`
+    /// instead of this bot.
+    ///
+    /// The [transposition table][tt] of `context` is used instead of the one of this bot and is only cleared
+    /// when `context` was last used by a bot of a different player, as its results are stored from the point of view
+    /// of that player. Reusing `context` for consecutive states of the same game with bots of the same player
+    /// therefore reuses the results of previous searches, while sharing it between both players of a self-play game
+    /// does not. The actions which caused cutoffs and the allocated buffers are kept as well.
+    /// Using a new context for each search behaves just like `select` without a transposition table.
+    ///
+    /// ```rust
+    /// use rubot::{alpha_beta::SearchContext, Bot, Game, Logger, ToCompletion};
+    ///
+    /// // `Pile` is the game of the example of `Bot::with_transposition_table`.
+    /// # /// A pile of stones, both players take between 1 and 3 stones
+    /// # /// until the player who takes the last stone wins.
+    /// # #[derive(Clone)]
+    /// # struct Pile(u32, bool);
+    /// #
+    /// # impl Game for Pile {
+    /// #     type Player = bool;
+    /// #     type Action = u32;
+    /// #     type Fitness = bool;
+    /// #     type Actions = std::ops::RangeInclusive<u32>;
+    /// #
+    /// #     fn actions(&self, player: bool) -> (bool, Self::Actions) {
+    /// #         (player == self.1, 1..=self.0.min(3))
+    /// #     }
+    /// #
+    /// #     fn execute(&mut self, action: &u32, player: bool) -> bool {
+    /// #         self.0 -= action;
+    /// #         self.1 = !self.1;
+    /// #         self.0 == 0 && player != self.1
+    /// #     }
+    /// #
+    /// #     fn is_upper_bound(&self, fitness: bool, _: bool) -> bool {
+    /// #         fitness
+    /// #     }
+    /// #
+    /// #     fn key(&self, _: bool) -> Option<u64> {
+    /// #         Some(u64::from(self.0) * 2 + u64::from(self.1))
+    /// #     }
+    /// # }
+    ///
+    /// let mut bot = Bot::new(true);
+    /// let mut context = SearchContext::with_transposition_table(1024);
+    /// let mut pile = Pile(21, true);
+    /// assert_eq!(bot.select_with(&pile, ToCompletion, &mut context), Some(1));
+    /// pile.execute(&1, true);
+    /// pile.execute(&1, false);
+    ///
+    /// // The following state was already analysed while searching the previous one.
+    /// let mut logger = Logger::new(ToCompletion);
+    /// assert_eq!(bot.select_with(&pile, &mut logger, &mut context), Some(3));
+    /// let steps = logger.steps();
+    /// assert_eq!(Bot::new(true).select(&pile, &mut logger), Some(3));
+    /// assert!(steps < logger.steps());
+    /// ```
+    ///
+    /// [tt]: struct.Bot.html#method.with_transposition_table
+    pub fn select_with<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+        context: &mut SearchContext<T>,
+    ) -> Option<T::Action>
+    where
+        T::Player: PartialEq,
+    {
+        context.claim(self.player);
+        self.swap_context(context);
+        let persistent = mem::replace(&mut self.persistent_transpositions, true);
+        let options = SearchOptions {
+            killers: Some(&mut context.killers),
+            root_actions: Some(&mut context.root_actions),
+            depth: Some(&mut context.depth),
+            ..SearchOptions::default()
+        };
+        let result = self.search(state, condition, options);
+        self.persistent_transpositions = persistent;
+        self.swap_context(context);
+        result.ok().map(|mut act| act.path.pop().unwrap())
+    }
+
+    /// Continues the last search of `context` after adding the actions in `extra` to its root actions.
+    ///
+    /// This is useful for an engine which first only considers a few promising actions using
+    /// [`with_root_action_limit`][limit] and considers more actions if there is still time left.
+    /// The search starts at the depth reached by the last search instead of the lowest depth,
+    /// so the previous root actions reuse the results stored in the [transposition table][tt] of `context`,
+    /// while the actions in `extra` are directly analysed at that depth.
+    ///
+    /// The root action limit of this bot is ignored and actions in `extra` which are not returned
+    /// by [`Game::actions`][act] are ignored as well. Afterwards, this search is the last search of `context`,
+    /// so it can be widened again. `state` must be the state used by the last search of `context`.
+    /// If `context` was not used yet or last used by a bot of a different player, only the actions
+    /// in `extra` are considered.
+    ///
+    /// ```rust
+    /// use rubot::{alpha_beta::SearchContext, Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 1),
+    ///     ]),
+    ///     Node::new(false, 3).with_children(&[
+    ///         Node::new(true, 4),
+    ///     ]),
+    ///     Node::new(false, 0).with_children(&[
+    ///         Node::new(true, 6),
+    ///     ]),
+    /// ]);
+    ///
+    /// // Only consider the action which looks best at first.
+    /// let mut bot = Bot::with_root_action_limit(true, 1);
+    /// let mut context = SearchContext::new();
+    /// assert_eq!(bot.select_with(&tree, ToCompletion, &mut context), Some(0));
+    ///
+    /// // There is still time left, so the second action is considered as well.
+    /// assert_eq!(bot.widen_and_continue(&tree, ToCompletion, &mut context, vec![1]), Some(1));
+    /// assert_eq!(bot.widen_and_continue(&tree, ToCompletion, &mut context, vec![2]), Some(2));
+    /// ```
+    ///
+    /// [limit]: struct.Bot.html#method.with_root_action_limit
+    /// [tt]: struct.Bot.html#method.with_transposition_table
+    /// [act]: ../trait.Game.html#tymethod.actions
+    pub fn widen_and_continue<U: IntoRunCondition<T::Fitness>, I: IntoIterator<Item = T::Action>>(
+        &mut self,
+        state: &T,
+        condition: U,
+        context: &mut SearchContext<T>,
+        extra: I,
+    ) -> Option<T::Action>
+    where
+        T::Player: PartialEq,
+    {
+        context.claim(self.player);
+        let mut allowed = mem::take(&mut context.root_actions);
+        allowed.extend(extra);
+        self.swap_context(context);
+        let persistent = mem::replace(&mut self.persistent_transpositions, true);
+        let limit = self.root_action_limit.take();
+        let options = SearchOptions {
+            allowed: Some(&allowed),
+            start: context.depth,
+            killers: Some(&mut context.killers),
+            root_actions: Some(&mut context.root_actions),
+            depth: Some(&mut context.depth),
+            ..SearchOptions::default()
+        };
+        let result = self.search(state, condition, options);
+        self.root_action_limit = limit;
+        self.persistent_transpositions = persistent;
+        self.swap_context(context);
+        result.ok().map(|mut act| act.path.pop().unwrap())
+    }
+
+    /// Similar to `select`, but also returns [`Diagnostic`s][diag] about suspicious fitness values
+    /// found during the search, which can help to debug a game implementation.
+    ///
+    /// To get the exact fitness of each root action, root actions are never cut off,
+    /// similar to [`select_no_root_cutoff`][nrc].
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    /// use rubot::alpha_beta::Diagnostic;
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 4),
+    ///         Node::new(true, 4),
+    ///     ]),
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 4),
+    ///         Node::new(true, 9)
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// let (action, diagnostics) = bot.select_with_diagnostics(&tree, ToCompletion);
+    /// assert_eq!(action, Some(0));
+    /// // Both actions have a fitness of `4`, so the bot can't distinguish between them.
+    /// assert_eq!(diagnostics, &[Diagnostic::FlatEvaluation]);
+    /// ```
+    ///
+    /// [diag]: enum.Diagnostic.html
+    /// [nrc]: struct.Bot.html#method.select_no_root_cutoff
+    pub fn select_with_diagnostics<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> (Option<T::Action>, Vec<Diagnostic>) {
+        let mut root = Vec::new();
+        let options = SearchOptions {
+            root: Some(&mut root),
+            root_cutoff: false,
+            ..SearchOptions::default()
+        };
+        let action = self
+            .search(state, condition, options)
+            .ok()
+            .map(|mut act| act.path.pop().unwrap());
+
+        let mut diagnostics = Vec::new();
+        let mut fitness = root.iter().map(|act| act.fitness);
+        if root.len() > 1 && fitness.clone().all(|f| f == root[0].fitness) {
+            diagnostics.push(Diagnostic::FlatEvaluation);
+        }
+
+        let upper = fitness
+            .clone()
+            .filter(|&f| state.is_upper_bound(f, self.player))
+            .min();
+        let lower = fitness
+            .clone()
+            .filter(|&f| state.is_lower_bound(f, self.player))
+            .max();
+        if fitness.any(|f| upper.is_some_and(|u| f > u) || lower.is_some_and(|l| f < l)) {
+            diagnostics.push(Diagnostic::FitnessNearBounds);
+        }
+
+        (action, diagnostics)
+    }
+
+    /// Returns the fitness of the first action of `line`, searching `line` before all other actions.
+    ///
+    /// This can be used to quickly check claims like "`x` loses to `y`" by calling `verify_line(state, &[x, y], condition)`.
+    /// While all other replies are still considered, starting with `line` means that a correct claim
+    /// results in a lot of early cutoffs. Once `line` has been searched, the bot continues with
+    /// its usual iterative deepening, using the principal variation of the previous depth instead.
+    ///
+    /// Returns `None` if `line` is empty or the bot is currently not the active player.
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if `line` contains an action which is not possible at that point.
+    /// Except for the first action, this uses [`Game::heuristic_actions`][ha] to get the possible actions.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 1).with_children(&[
+    ///         Node::new(true, 5),
+    ///     ]),
+    ///     Node::new(false, 9).with_children(&[
+    ///         Node::new(true, 7),
+    ///         Node::new(true, -5),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// // `[1]` is worse than it seems, as the opponent can reply with `[1][1]`.
+    /// assert_eq!(bot.verify_line(&tree, &[1, 1], ToCompletion), Some(-5));
+    /// // The fitness of the first action does not depend on the given reply.
+    /// assert_eq!(bot.verify_line(&tree, &[1, 0], ToCompletion), Some(-5));
+    /// ```
+    ///
+    /// [ha]: ../trait.Game.html#method.heuristic_actions
+    pub fn verify_line<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        line: &[T::Action],
+        condition: U,
+    ) -> Option<T::Fitness> {
+        let mut condition = condition.into_run_condition();
+
+        let first = line.first()?;
+        if !state.actions(self.player).0 {
+            return None;
+        }
+
+        let action = Action {
+            fitness: state.look_ahead(first, self.player),
+            path: line.iter().rev().cloned().collect(),
+        };
+        // The depth at which the complete `line` is used.
+        let start = line.len() as u32 - 1;
+        let max_depth = self.max_depth;
+        let start = max_depth.map_or(start, |max| cmp::min(max.saturating_sub(1), start));
+        let mut ctxt = self.context(state, vec![action]);
+        let action = ctxt.deepen(&mut condition, start, max_depth);
+        condition.search_finished(&action.fitness, action.path.len());
+        let fitness = action.fitness;
+        ctxt.discard_path(action.path);
+        let (root_buffer, path_cache) = ctxt.into_buffers();
+        self.root_buffer = root_buffer;
+        self.path_cache = path_cache;
+        Some(fitness)
+    }
+
+    /// Computes the fitness of each action in `actions` in parallel, using a separate thread for each action.
+    ///
+    /// Each action is analysed from a clone of `state` using the same search as [`verify_line`][vl]
+    /// with a run condition of [`Depth(depth)`][depth]. As the result does not depend on the time
+    /// each thread takes, it is deterministic for a given `depth`. This is useful to quickly
+    /// evaluate a few candidate actions, for example when analysing a game.
+    ///
+    /// All settings of this bot are used by each thread, except for the callback of
+    /// [`on_terminal_found`][otf], which is not called.
+    ///
+    /// This method requires the `rayon` feature.
+    ///
+    /// Returns `None` if the bot is currently not the active player.
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if `actions` contains an action which is not possible.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 4).with_children(&[
+    ///         Node::new(true, 0),
+    ///     ]),
+    ///     Node::new(false, 1),
+    ///     Node::new(false, 3).with_children(&[
+    ///         Node::new(true, 2),
+    ///         Node::new(true, 7),
+    ///     ]),
+    /// ]);
+    ///
+    /// let bot = Bot::new(true);
+    /// assert_eq!(bot.evaluate_moves_parallel(&tree, &[0, 2], 10), Some(vec![0, 2]));
+    /// ```
+    ///
+    /// [vl]: struct.Bot.html#method.verify_line
+    /// [depth]: ../struct.Depth.html
+    /// [otf]: struct.Bot.html#method.on_terminal_found
+    #[cfg(feature = "rayon")]
+    pub fn evaluate_moves_parallel(
+        &self,
+        state: &T,
+        actions: &[T::Action],
+        depth: u32,
+    ) -> Option<Vec<T::Fitness>>
+    where
+        T: Send + Sync,
+        T::Player: Send + Sync,
+        T::Action: Send + Sync,
+        T::Fitness: Send + Sync,
+    {
+        use rayon::prelude::*;
+
+        // The callback is not `Sync`, so each thread uses its own bot with the same settings.
+        let bots: Vec<_> = actions.iter().map(|action| (action, self.fork())).collect();
+        bots.into_par_iter()
+            .map(|(action, mut bot)| {
+                bot.verify_line(state, core::slice::from_ref(action), Depth(depth))
+            })
+            .collect()
+    }
+
+    /// Returns an action chosen by searching the actions of `state` in parallel using `threads` threads.
+    ///
+    /// The actions of `state` are split between the threads, each of which searches its
+    /// actions using a separate alpha-beta search. The best action found by any thread is returned.
+    /// Once the search is complete, the returned action has the same fitness as the one returned by [`select`][sel],
+    /// and is the same action unless multiple actions are equally good.
+    ///
+    /// Each thread uses a clone of `condition`, so conditions which count the amount of steps, like [`Steps`][steps],
+    /// are applied to each thread separately. If the search is stopped early, the returned action
+    /// may have been analysed to a lower depth than with `select`, as each thread deepens separately.
+    ///
+    /// All settings of this bot are used by each thread, except for the callback of
+    /// [`on_terminal_found`][otf], which is not called.
+    ///
+    /// This method requires the `rayon` feature.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `threads` is `0` or the thread pool could not be created.
+    ///
+    /// ```rust
+    /// use rubot::{Bot, ToCompletion, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 4).with_children(&[
+    ///         Node::new(true, 0),
+    ///     ]),
+    ///     Node::new(false, 1),
+    ///     Node::new(false, 3).with_children(&[
+    ///         Node::new(true, 2),
+    ///         Node::new(true, 7),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut bot = Bot::new(true);
+    /// assert_eq!(bot.select_parallel(&tree, ToCompletion, 2), Some(2));
+    /// assert_eq!(bot.select(&tree, ToCompletion), Some(2));
+    /// ```
+    ///
+    /// [sel]: struct.Bot.html#method.select
+    /// [steps]: ../struct.Steps.html
+    /// [otf]: struct.Bot.html#method.on_terminal_found
+    #[cfg(feature = "rayon")]
+    pub fn select_parallel<U>(
+        &mut self,
+        state: &T,
+        condition: U,
+        threads: usize,
+    ) -> Option<T::Action>
+    where
+        T: Send + Sync,
+        T::Player: Send + Sync,
+        T::Action: Send + Sync,
+        T::Fitness: Send + Sync,
+        U: IntoRunCondition<T::Fitness> + Clone + Send,
+    {
+        use rayon::prelude::*;
+
+        assert!(
+            threads > 0,
+            "`select_parallel` requires at least one thread"
+        );
+        let actions = self.root_actions(state, None).ok()?;
+        // Each search only knows its own actions, so ties between them are broken here, see `Ctxt::root_order`.
+        let mut order: Vec<_> = actions
+            .iter()
+            .map(|act| (act.fitness, act.path[0].clone()))
+            .collect();
+        order.sort_by_key(|&(fitness, _)| Reverse(fitness));
+
+        let threads = threads.min(actions.len());
+        let mut splits: Vec<_> = (0..threads)
+            .map(|_| (Vec::new(), self.fork(), condition.clone()))
+            .collect();
+        for (i, action) in actions.into_iter().enumerate() {
+            splits[i % threads].0.push(action);
+        }
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("unable to create the thread pool");
+        let results: Vec<_> = pool.install(|| {
+            splits
+                .into_par_iter()
+                .map(|(actions, mut bot, condition)| {
+                    let options = SearchOptions {
+                        actions: Some(actions),
+                        ..SearchOptions::default()
+                    };
+                    bot.search(state, condition, options).ok()
+                })
+                .collect()
+        });
+
+        // Combine the results in the order of the actions, so the result does not depend on the thread timings.
+        let rank = |act: &Action<T>| {
+            if state.is_upper_bound(act.fitness, self.player) {
+                2
+            } else if state.is_lower_bound(act.fitness, self.player) {
+                0
+            } else {
+                1
+            }
+        };
+        let rarity = |act: &Action<T>| state.rarity(act.path.last().unwrap(), self.player);
+        let index = |act: &Action<T>| {
+            let action = act.path.last().unwrap();
+            order.iter().position(|(_, root)| root == action)
+        };
+        results
+            .into_iter()
+            .flatten()
+            .fold(None, |best: Option<Action<T>>, act| match best {
+                Some(best) => {
+                    let ord = rank(&best)
+                        .cmp(&rank(&act))
+                        .then_with(|| match rank(&best) {
+                            // Prefer the shortest win and the longest loss.
+                            2 => act.path.len().cmp(&best.path.len()),
+                            0 => best.path.len().cmp(&act.path.len()),
+                            _ => best
+                                .fitness
+                                .cmp(&act.fitness)
+                                .then_with(|| rarity(&best).cmp(&rarity(&act)))
+                                .then_with(|| index(&act).cmp(&index(&best))),
+                        });
+                    Some(if ord == Ordering::Less { act } else { best })
+                }
+                None => Some(act),
+            })
+            .map(|mut act| act.path.pop().unwrap())
+    }
+
+    /// Creates a new bot with the same settings, used by the parallel searches.
+    ///
+    /// The callback of `Bot::on_terminal_found` is not `Sync`, so it is not used by the new bot.
+    #[cfg(feature = "rayon")]
+    fn fork(&self) -> Self {
+        Bot {
+            player: self.player,
+            max_depth: self.max_depth,
+            root_action_limit: self.root_action_limit,
+            repetition_penalty: self.repetition_penalty,
+            repetition_draw: self.repetition_draw,
+            contempt: self.contempt,
+            futility_margin: self.futility_margin,
+            null_window: self.null_window,
+            partial_order: self.partial_order,
+            trust_action_order: self.trust_action_order,
+            order_key: self.order_key,
+            history_heuristic: self.history_heuristic,
+            undo: self.undo,
+            irreversible_limit: self.irreversible_limit,
+            transpositions: self
+                .transpositions
+                .as_ref()
+                .map(TranspositionTable::empty_like),
+            persistent_transpositions: false,
+            pondered: None,
+            on_terminal: None,
+            path_cache: Vec::new(),
+            root_buffer: Vec::new(),
+        }
+    }
+
+    fn inner_select<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Option<Action<T>> {
+        self.search(state, condition, SearchOptions::default()).ok()
+    }
+
+    fn search<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+        options: SearchOptions<'_, T>,
+    ) -> Result<Action<T>, SelectError> {
+        let mut condition = condition.into_run_condition();
+
+        let actions = match options.actions {
+            Some(actions) => actions,
+            None => self.root_actions(state, options.allowed)?,
+        };
+
+        let max_depth = self.max_depth;
+        let mut ctxt = self.context(state, actions);
+        ctxt.root_cutoff = options.root_cutoff;
+        ctxt.stop_decisive = options.stop_decisive;
+        ctxt.tie_break = options.tie_break;
+        if let Some(root_order) = options.root_order {
+            ctxt.root_order = root_order;
+        }
+        if let Some(root) = options.root {
+            root.clear();
+            root.extend(ctxt.unfinished.iter().map(|act| Action {
+                fitness: act.fitness,
+                path: act.path.clone(),
+            }));
+            ctxt.root = Some(root);
+        }
+        if options.analysis.is_some() {
+            ctxt.analysis = Some(Analysis::new());
+        }
+        let mut killers = options.killers;
+        if let Some(killers) = killers.as_deref_mut() {
+            ctxt.killers = mem::take(killers);
+        }
+        if let Some(root_actions) = options.root_actions {
+            root_actions.clear();
+            root_actions.extend(
+                ctxt.unfinished
+                    .iter()
+                    .map(|act| act.path.last().unwrap().clone()),
+            );
+        }
+
+        let start = options.start;
+        let start = max_depth.map_or(start, |max| cmp::min(max.saturating_sub(1), start));
+        let action = ctxt.deepen(&mut condition, start, max_depth);
+        condition.search_finished(&action.fitness, action.path.len());
+        if let Some(depth) = options.depth {
+            *depth = ctxt.depth;
+        }
+        if let Some(proven) = options.proven {
+            *proven = ctxt.proven;
+        }
+        if let Some(analysis) = options.analysis {
+            // A proven win ends the search before the current depth is completed.
+            *analysis = ctxt.analysis.take().and_then(|a| a.finish(ctxt.proven));
+        }
+        if let Some(killers) = killers {
+            *killers = mem::take(&mut ctxt.killers);
+        }
+        let (root_buffer, path_cache) = ctxt.into_buffers();
+        self.root_buffer = root_buffer;
+        self.path_cache = path_cache;
+        Ok(action)
+    }
+
+    /// Creates the context of a search starting at `state` with the given root `actions`,
+    /// using the settings and buffers of this bot.
+    ///
+    /// The buffers should be given back to the bot using `Ctxt::into_buffers` once the search is finished.
+    fn context<'a>(&'a mut self, state: &'a T, actions: Vec<Action<T>>) -> Ctxt<'a, T> {
+        let (ponder_hit, pondered_action) = self.ponder_hit(state);
+        let mut ctxt = Ctxt::new(state, self.player, actions);
+        ctxt.pondered_action = pondered_action;
+        ctxt.path_cache = mem::take(&mut self.path_cache);
+        ctxt.repetition_penalty = self.repetition_penalty;
+        ctxt.repetition_draw = self.repetition_draw;
+        ctxt.contempt = self.contempt;
+        ctxt.futility_margin = self.futility_margin;
+        ctxt.null_window = self.null_window(state);
+        ctxt.partial_order = self.partial_order;
+        ctxt.trust_action_order = self.trust_action_order;
+        ctxt.order_key = self.order_key;
+        if self.history_heuristic {
+            ctxt.history = vec![0; HISTORY_SIZE];
+        }
+        ctxt.undo = self.undo;
+        ctxt.irreversible_limit = self.irreversible_limit;
+        if let Some(on_terminal) = self.on_terminal.as_deref_mut() {
+            ctxt.on_terminal = Some(on_terminal);
+        }
+        if let Some(table) = self.transpositions.as_mut() {
+            if !self.persistent_transpositions && !ponder_hit {
+                table.clear();
+            }
+            // With these settings, the result of a state depends on the path used to reach it.
+            if self.repetition_penalty.is_none()
+                && self.repetition_draw.is_none()
+                && self.irreversible_limit.is_none()
+            {
+                ctxt.transpositions = Some(table);
+            }
+        }
+        ctxt
+    }
+
+    /// Exchanges the transposition table and buffers of this bot with the ones of `context`.
+    fn swap_context(&mut self, context: &mut SearchContext<T>) {
+        mem::swap(&mut self.transpositions, &mut context.transpositions);
+        mem::swap(&mut self.path_cache, &mut context.path_cache);
+        mem::swap(&mut self.root_buffer, &mut context.root_buffer);
+    }
+
+    /// Returns whether the results of the last call to `Bot::ponder` are reused when searching `state`,
+    /// together with the action which is analysed first in this case.
+    ///
+    /// The pondered state is forgotten afterwards, so only the following search reuses its results.
+    fn ponder_hit(&mut self, state: &T) -> (bool, Option<T::Action>) {
+        let (pondered, key) = match (self.pondered.take(), state.key(self.player)) {
+            (Some(pondered), Some(key)) => (pondered, key),
+            _ => return (false, None),
+        };
+        match self.transpositions.as_ref() {
+            Some(table) if pondered == key || table.contains(key) => {
+                (true, table.deepest_action(key).cloned())
+            }
+            _ => (false, None),
+        }
+    }
+
+    /// Returns the null window used when searching `state`, see `Bot::with_fitness_step`.
+    fn null_window(&self, state: &T) -> Option<NullWindow<T>> {
+        let (step, add, sub) = self.null_window?;
+        Some((step.or_else(|| state.fitness_step())?, add, sub))
+    }
+
+    /// Returns the actions of `state` analysed by the bot, see `Bot::with_root_action_limit`
+    /// and `Bot::select_subset`.
+    fn root_actions(
+        &mut self,
+        state: &T,
+        allowed: Option<&[T::Action]>,
+    ) -> Result<Vec<Action<T>>, SelectError> {
+        if state.status(self.player).is_over() {
+            return Err(SelectError::Terminal);
+        }
+
+        let (active, new_actions) = state.actions(self.player);
+        if !active {
+            return Err(SelectError::NotActivePlayer);
+        }
+
+        let mut actions = mem::take(&mut self.root_buffer);
+        if let Some(max) = state.max_actions() {
+            actions.reserve(max);
+        }
+        let (player, path_cache) = (self.player, &mut self.path_cache);
+        let mut legal = false;
+        let is_allowed =
+            |action: &T::Action| allowed.map_or(true, |allowed| allowed.contains(action));
+        let new_actions = new_actions.into_iter().inspect(|_| legal = true);
+        actions.extend(new_actions.filter(is_allowed).map(|action| {
+            let mut path = path_cache.pop().unwrap_or_default();
+            path.push(action);
+            Action {
+                fitness: state.look_ahead(path.last().unwrap(), player),
+                path,
+            }
+        }));
+
+        if !legal {
+            return Err(SelectError::Terminal);
+        }
+
+        if let Some(limit) = self.root_action_limit {
+            actions.sort_by_key(|act| Reverse(act.fitness));
+            actions.truncate(limit);
+        }
+
+        if actions.is_empty() {
+            return Err(SelectError::NoActions);
+        }
+
+        Ok(actions)
+    }
+}
+
+/// The state kept between searches using [`Bot::select_with`][sw].
+///
+/// This consists of a [transposition table][tt], the actions which recently caused cutoffs,
+/// the root actions and reached depth of the last search, see [`Bot::widen_and_continue`][wc],
+/// and buffers which are reused to avoid allocations. Dropping a context discards all of them.
+///
+/// The results of previous searches are only reused by bots of the player which did these searches,
+/// so using a context for a different player first forgets them.
+///
+/// [sw]: struct.Bot.html#method.select_with
+/// [tt]: struct.Bot.html#method.with_transposition_table
+/// [wc]: struct.Bot.html#method.widen_and_continue
+pub struct SearchContext<T: Game> {
+    transpositions: Option<TranspositionTable<T>>,
+    /// The killer actions of each ply, see `Ctxt::killers`.
+    killers: Vec<[Option<T::Action>; 2]>,
+    /// The root actions analysed by the last search.
+    root_actions: Vec<T::Action>,
+    /// The depth reached by the last search, see `Ctxt::depth`.
+    depth: u32,
+    /// The player of the last search, which the stored results belong to.
+    player: Option<T::Player>,
+    path_cache: Vec<Vec<T::Action>>,
+    root_buffer: Vec<Action<T>>,
+}
+
+impl<T: Game> SearchContext<T> {
+    /// Creates a new context without a transposition table.
+    pub fn new() -> Self {
+        SearchContext {
+            transpositions: None,
+            killers: Vec::new(),
+            root_actions: Vec::new(),
+            depth: 0,
+            player: None,
+            path_cache: Vec::new(),
+            root_buffer: Vec::new(),
+        }
+    }
+
+    /// Creates a new context with a transposition table of the given `size`,
+    /// see [`Bot::with_transposition_table`][tt].
+    ///
+    /// [tt]: struct.Bot.html#method.with_transposition_table
+    pub fn with_transposition_table(size: usize) -> Self {
+        SearchContext {
+            transpositions: Some(TranspositionTable::new(size)),
+            ..Self::new()
+        }
+    }
+
+    /// Forgets the results of previous searches if they were done for a different player
+    /// and remembers `player` as the player of the next search.
+    fn claim(&mut self, player: T::Player)
+    where
+        T::Player: PartialEq,
+    {
+        if self.player.is_some_and(|last| last != player) {
+            if let Some(table) = self.transpositions.as_mut() {
+                table.clear();
+            }
+            self.killers.clear();
+            self.root_actions.clear();
+            self.depth = 0;
+        }
+        self.player = Some(player);
+    }
+}
+
+impl<T: Game> Default for SearchContext<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Remembers whether `condition` stopped the search, used by `Bot::solve`.
+struct Stopped<U> {
+    condition: U,
+    stopped: bool,
+}
+
+impl<U: RunCondition> Stopped<U> {
+    fn new(condition: U) -> Self {
+        Stopped {
+            condition,
+            stopped: false,
+        }
+    }
+}
+
+impl<U: RunCondition> RunCondition for &mut Stopped<U> {
+    type Fitness = U::Fitness;
+
+    fn step(&mut self) -> bool {
+        let running = self.condition.step();
+        self.stopped |= !running;
+        running
+    }
+
+    fn depth(&mut self, depth: u32) -> bool {
+        let running = self.condition.depth(depth);
+        self.stopped |= !running;
+        running
+    }
+
+    fn completed_depth(&mut self, depth: u32, best: Option<&Self::Fitness>) -> bool {
+        let running = self.condition.completed_depth(depth, best);
+        self.stopped |= !running;
+        running
+    }
+
+    fn cutoff(&mut self) {
+        self.condition.cutoff();
+    }
+
+    fn leaf(&mut self) {
+        self.condition.leaf();
+    }
+}
+
+/// Changes how `Bot::search` behaves, used by the different public search methods.
+struct SearchOptions<'r, T: Game> {
+    /// Stores the latest fitness of each root action if set.
+    root: Option<&'r mut Vec<Action<T>>>,
+    /// Whether root actions may be cut off.
+    root_cutoff: bool,
+    /// Whether to stop once the best action is `Game::is_decisive`.
+    stop_decisive: bool,
+    /// Stores whether the returned action is known to be the best one if set.
+    proven: Option<&'r mut bool>,
+    /// Compares root actions with an equal fitness if set.
+    tie_break: Option<&'r TieBreak<'r, T>>,
+    /// Stores the analysed states if set.
+    analysis: Option<&'r mut Option<AnalysisTree<T>>>,
+    /// The root actions to analyse instead of all actions of the state if set.
+    actions: Option<Vec<Action<T>>>,
+    /// Only analyses root actions which are part of this slice if set.
+    allowed: Option<&'r [T::Action]>,
+    /// The killer actions used and updated by the search if set.
+    killers: Option<&'r mut Vec<[Option<T::Action>; 2]>>,
+    /// Stores the analysed root actions if set.
+    root_actions: Option<&'r mut Vec<T::Action>>,
+    /// The depth at which iterative deepening starts.
+    start: u32,
+    /// Replaces `Ctxt::root_order` if set.
+    root_order: Option<Vec<T::Action>>,
+    /// Stores the depth reached by the search if set, see `Ctxt::depth`.
+    depth: Option<&'r mut u32>,
+}
+
+impl<T: Game> Default for SearchOptions<'_, T> {
+    fn default() -> Self {
+        Self {
+            root: None,
+            root_cutoff: true,
+            stop_decisive: true,
+            proven: None,
+            tie_break: None,
+            analysis: None,
+            actions: None,
+            allowed: None,
+            killers: None,
+            root_actions: None,
+            start: 0,
+            depth: None,
+            root_order: None,
+        }
+    }
+}
+
+/// The expected outcome of the action returned by [`Bot::select_with_verdict`][swv] and [`Bot::explain`][explain].
+///
+/// [swv]: struct.Bot.html#method.select_with_verdict
+/// [explain]: struct.Bot.html#method.explain
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// The bot expects to win, as the fitness of the action is an [upper bound][ub].
+    ///
+    /// [ub]: ../trait.Game.html#method.is_upper_bound
+    Winning,
+    /// The bot expects to lose, as the fitness of the action is a [lower bound][lb].
+    ///
+    /// [lb]: ../trait.Game.html#method.is_lower_bound
+    Losing,
+    /// The outcome of the game is not yet known.
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Winning => write!(f, "the bot expects to win"),
+            Verdict::Losing => write!(f, "the bot expects to lose"),
+            Verdict::Unknown => write!(f, "the outcome is unknown"),
+        }
+    }
+}
+
+/// The action chosen by [`Bot::explain`][explain] together with the reasons for choosing it.
+///
+/// [explain]: struct.Bot.html#method.explain
+pub struct Explanation<T: Game> {
+    /// The chosen action.
+    pub action: T::Action,
+    /// The expected fitness after `action`, see [`Action::fitness`][fit].
+    ///
+    /// [fit]: struct.Action.html#structfield.fitness
+    pub fitness: T::Fitness,
+    /// The actions expected to follow `action` during optimal play,
+    /// starting with the answer of the opponent.
+    pub line: Vec<T::Action>,
+    /// The expected outcome of the game.
+    pub verdict: Verdict,
+}
+
+impl<T: Game> fmt::Display for Explanation<T>
+where
+    T::Action: fmt::Debug,
+    T::Fitness: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:?} with fitness {:?} ({})",
+            self.action, self.fitness, self.verdict
+        )?;
+        for (i, action) in self.line.iter().enumerate() {
+            let separator = if i == 0 { ", expecting" } else { "," };
+            write!(f, "{} {:?}", separator, action)?;
         }
+        Ok(())
+    }
+}
 
-        unreachable!();
+/// The reason why [`Bot::try_select`][ts] did not return an action.
+///
+/// [ts]: struct.Bot.html#method.try_select
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelectError {
+    /// The bot is currently not the active player.
+    NotActivePlayer,
+    /// The game is over, as [`Game::status`][st] says so or [`Game::actions`][act] did not return any actions.
+    ///
+    /// [st]: ../trait.Game.html#method.status
+    /// [act]: ../trait.Game.html#tymethod.actions
+    Terminal,
+    /// There are possible actions, but none of them may be chosen, as the
+    /// [root action limit][limit] is `0` or none of them were allowed by [`select_subset`][sub].
+    ///
+    /// [limit]: struct.Bot.html#method.with_root_action_limit
+    /// [sub]: struct.Bot.html#method.select_subset
+    NoActions,
+}
+
+impl fmt::Display for SelectError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SelectError::NotActivePlayer => write!(f, "the bot is not the active player"),
+            SelectError::Terminal => write!(f, "the game is already over"),
+            SelectError::NoActions => write!(f, "no action may be chosen"),
+        }
     }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for SelectError {}
+
+/// The kind of outcome reported to [`Bot::on_terminal_found`][otf].
+///
+/// [otf]: struct.Bot.html#method.on_terminal_found
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TerminalKind {
+    /// The given action is a forced win.
+    Win,
+    /// Every action is a forced loss, the given action takes the longest to lose.
+    Loss,
+}
+
+/// A suspicious pattern found by [`Bot::select_with_diagnostics`][swd].
+///
+/// These usually indicate a problem with the [`Game`][game] implementation,
+/// especially with the fitness returned by [`Game::execute`][exe].
+///
+/// [swd]: struct.Bot.html#method.select_with_diagnostics
+/// [game]: ../trait.Game.html
+/// [exe]: ../trait.Game.html#tymethod.execute
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Diagnostic {
+    /// All root actions have the same fitness, so the bot is effectively choosing at random.
+    FlatEvaluation,
+    /// A root action is better than an [upper bound][ub] or worse than a [lower bound][lb].
+    ///
+    /// This means that the bounds are wrong, or that the fitness of an action is so large
+    /// that it is not distinguishable from a won or lost game.
+    ///
+    /// [ub]: ../trait.Game.html#method.is_upper_bound
+    /// [lb]: ../trait.Game.html#method.is_lower_bound
+    FitnessNearBounds,
+}
+
+/// The order in which partially terminated actions are retried, see [`Bot::with_partial_order`][wpo].
+///
+/// [wpo]: struct.Bot.html#method.with_partial_order
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetryOrder {
+    /// Start with the action with the lowest maximum fitness. This is the default.
+    Ascending,
+    /// Start with the action with the highest maximum fitness.
+    Descending,
+}
+
 /// A top level action.
+///
+/// With the `serde` feature, this implements `Serialize` and `Deserialize`
+/// if both `T::Action` and `T::Fitness` do.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T::Action: serde::Serialize, T::Fitness: serde::Serialize",
+        deserialize = "T::Action: serde::Deserialize<'de>, T::Fitness: serde::Deserialize<'de>"
+    ))
+)]
 pub struct Action<T: Game> {
     /// The current fitness of a given action.
     ///
@@ -226,10 +3025,11 @@ impl<T: Game> MiniMax<T> {
         fitness: T::Fitness,
     ) -> MiniMax<T> {
         match self {
-            MiniMax::DeadEnd => MiniMax::Terminated(
-                ctxt.new_path().tap(|p| p.push(action)),
-                Branch::Equal(fitness),
-            ),
+            MiniMax::DeadEnd => {
+                let mut path = ctxt.new_path();
+                path.push(action);
+                MiniMax::Terminated(path, Branch::Equal(fitness))
+            }
             MiniMax::Open(mut actions, branch) => {
                 actions.push(action);
                 MiniMax::Open(actions, branch)
@@ -242,6 +3042,32 @@ impl<T: Game> MiniMax<T> {
     }
 }
 
+/// A game state reached by executing `action`, together with the resulting fitness.
+type GameState<T> = (T, <T as Game>::Action, <T as Game>::Fitness);
+
+/// A possible action of a state, which is only executed once it is analysed
+/// when using `Bot::with_order_key` or `Bot::with_undo`.
+enum Child<T: Game> {
+    Executed(GameState<T>),
+    /// An action which has not been executed yet, together with the fitness used to sort it.
+    Pending(T::Action, T::Fitness),
+}
+
+impl<T: Game> Child<T> {
+    fn action(&self) -> &T::Action {
+        match self {
+            Child::Executed((_, action, _)) | Child::Pending(action, _) => action,
+        }
+    }
+
+    /// The fitness used to sort actions.
+    fn order(&self) -> T::Fitness {
+        match *self {
+            Child::Executed((_, _, fitness)) | Child::Pending(_, fitness) => fitness,
+        }
+    }
+}
+
 /// A fitness and how it was calculated,
 /// this is used if we want to know whether a cutoff occurred.
 enum Branch<T: Game> {
@@ -299,16 +3125,96 @@ struct Ctxt<'a, T: Game> {
     /// In case all paths lead to defeat, we store the action which takes the longest,
     /// so the bot doesn't start doing weird stuff once it realized it's lost.
     losing_action: Option<Action<T>>,
+    /// The winning action with the shortest path found so far.
+    ///
+    /// As the search is extended at states which are not quiet, a win can be found before
+    /// shorter wins of other actions, so it is only returned once no shorter win is possible.
+    winning_action: Option<Action<T>>,
     /// We create and discard a lot of paths.
     ///
     /// As an optimization, we therefore can reuse these paths.
     /// The paths stored here are always empty. This causes an about
-    /// 2% performance increase.
+    /// 2% performance increase. The cache is kept by the `Bot`, so it is
+    /// also reused by later searches.
     path_cache: Vec<Vec<T::Action>>,
+    /// The penalty for states reached after a repetition, see `Bot::set_repetition_penalty`.
+    repetition_penalty: Option<Offset<T>>,
+    /// The fitness of repeated states, see `Bot::set_repetition_draw`.
+    repetition_draw: Option<T::Fitness>,
+    /// The keys of all states on the current search path, only used with a `repetition_penalty`
+    /// or a `repetition_draw`.
+    path_keys: Vec<u64>,
+    /// The amount of repeated states on the current search path.
+    repetitions: u32,
+    /// The offset subtracted from the fitness of drawn states, see `Bot::set_contempt`.
+    contempt: Option<Offset<T>>,
+    /// Called once the game is decided, see `Bot::on_terminal_found`.
+    on_terminal: Option<&'a mut OnTerminal<T>>,
+    /// The margin used for futility pruning, see `Bot::with_futility_margin`.
+    futility_margin: Option<Offset<T>>,
+    /// The step used for null window searches, see `Bot::with_principal_variation_search`.
+    null_window: Option<NullWindow<T>>,
+    /// The order in which partially terminated actions are retried, see `Bot::with_partial_order`.
+    partial_order: RetryOrder,
+    /// Whether actions are analysed in the order of `Game::actions`, see `Bot::trust_action_order`.
+    trust_action_order: bool,
+    /// Whether actions are sorted using `Game::order_key`, see `Bot::with_order_key`.
+    order_key: bool,
+    /// The reward of each `Game::hash_action` modulo `HISTORY_SIZE` for causing cutoffs.
+    ///
+    /// This is empty unless `Bot::with_history_heuristic` is used.
+    history: Vec<u32>,
+    /// Reverts actions which were executed in place, see `Bot::with_undo`.
+    undo: Option<UndoFn<T>>,
+    /// The amount of irreversible actions after which a state is a leaf, see `Bot::with_irreversible_limit`.
+    irreversible_limit: Option<u32>,
+    /// The amount of irreversible actions on the current search path.
+    irreversible: u32,
+    /// Whether root actions may be cut off, see `Bot::select_no_root_cutoff`.
+    root_cutoff: bool,
+    /// Whether to stop deepening once the best action is decisive, see `Game::is_decisive`.
+    stop_decisive: bool,
+    /// The latest fitness and path of each root action, see `Bot::select_with_range`.
+    root: Option<&'a mut Vec<Action<T>>>,
+    /// Whether the returned action is known to be the best one, see `Bot::select_checked`.
+    proven: bool,
+    /// Compares root actions with an equal fitness, see `Bot::select_by`.
+    tie_break: Option<&'a TieBreak<'a, T>>,
+    /// The root actions sorted by their fitness after executing them, using the order of `Game::actions`
+    /// for actions with the same fitness, or in a random order, see `Bot::select_random`.
+    /// This is the final tie-breaker between root actions.
+    root_order: Vec<T::Action>,
+    /// Caches the results of `minimax`, see `Bot::with_transposition_table`.
+    transpositions: Option<&'a mut TranspositionTable<T>>,
+    /// The root action which is analysed first at the lowest depth, see `Bot::ponder`.
+    pondered_action: Option<T::Action>,
+    /// The depth currently analysed by iterative deepening, which is the amount of completed depths.
+    depth: u32,
+    /// The distance between the currently analysed state and the initial gamestate.
+    ply: usize,
+    /// The amount of actions analysed past the lowest depth on the current path, see `Ctxt::quiescence`.
+    quiescence: u32,
+    /// The last two actions which caused a cutoff at each ply, the most recent one first.
+    ///
+    /// As similar states often share good actions, these actions are analysed early, see `Ctxt::generate_game_states`.
+    killers: Vec<[Option<T::Action>; 2]>,
+    /// Records the analysed states, see `Bot::analyze`.
+    analysis: Option<Analysis<T>>,
+    /// The best action after the last completed depth and the amount of consecutive
+    /// completed depths it was the best action, see `RunCondition::stable_depths`.
+    stable: Option<(T::Action, u32)>,
 }
 
 impl<'a, T: Game> Ctxt<'a, T> {
-    fn new(state: &T, player: T::Player, unfinished: Vec<Action<T>>) -> Ctxt<T> {
+    fn new(state: &T, player: T::Player, unfinished: Vec<Action<T>>) -> Ctxt<'_, T> {
+        // The fitness of each action is still the result of `Game::look_ahead` and
+        // `sort_by_key` is stable, so actions with the same fitness keep the order of `Game::actions`.
+        let mut root_order: Vec<_> = unfinished.iter().map(|act| (act.fitness, act)).collect();
+        root_order.sort_by_key(|&(fitness, _)| Reverse(fitness));
+        let root_order = root_order
+            .into_iter()
+            .map(|(_, act)| act.path.last().unwrap().clone())
+            .collect();
         Ctxt {
             state,
             player,
@@ -316,8 +3222,185 @@ impl<'a, T: Game> Ctxt<'a, T> {
             unfinished,
             terminated: None,
             losing_action: None,
+            winning_action: None,
             partially_terminated: Vec::new(),
             path_cache: Vec::new(),
+            repetition_penalty: None,
+            repetition_draw: None,
+            path_keys: Vec::new(),
+            repetitions: 0,
+            contempt: None,
+            futility_margin: None,
+            null_window: None,
+            partial_order: RetryOrder::Ascending,
+            trust_action_order: false,
+            order_key: false,
+            history: Vec::new(),
+            undo: None,
+            irreversible_limit: None,
+            irreversible: 0,
+            on_terminal: None,
+            root_cutoff: true,
+            stop_decisive: true,
+            root: None,
+            proven: false,
+            tie_break: None,
+            root_order,
+            transpositions: None,
+            pondered_action: None,
+            depth: 0,
+            ply: 0,
+            quiescence: 0,
+            killers: Vec::new(),
+            analysis: None,
+            stable: None,
+        }
+    }
+
+    /// Returns the empty buffers of this search, which are reused by the next search of the bot.
+    fn into_buffers(mut self) -> (Vec<Action<T>>, Vec<Vec<T::Action>>) {
+        let mut actions = mem::take(&mut self.unfinished);
+        actions.append(&mut self.partially_terminated);
+        actions.extend(self.best.take());
+        actions.extend(self.terminated.take());
+        actions.extend(self.losing_action.take());
+        actions.extend(self.winning_action.take());
+        for act in actions.drain(..) {
+            self.discard_path(act.path);
+        }
+        (actions, self.path_cache)
+    }
+
+    /// Reports a decided game to `on_terminal`, returning `action` unchanged.
+    fn report(&mut self, kind: TerminalKind, mut action: Action<T>) -> Action<T> {
+        if let Some(on_terminal) = self.on_terminal.as_mut() {
+            // `action.path` is used as a stack, so we have to reverse it while reporting.
+            action.path.reverse();
+            on_terminal(kind, &action);
+            action.path.reverse();
+        }
+        action
+    }
+
+    /// Returns the key of `state` in case repetitions are penalized or treated as a draw.
+    fn repetition_key(&self, state: &T) -> Option<u64> {
+        if self.repetition_penalty.is_some() || self.repetition_draw.is_some() {
+            state.key(self.player)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether `state` was already seen on the current search path.
+    fn is_repeated(&self, state: &T) -> bool {
+        self.repetition_key(state)
+            .is_some_and(|key| self.path_keys.contains(&key))
+    }
+
+    /// Returns whether `state` is a repetition which should not be analysed any further,
+    /// see `Bot::set_repetition_draw`.
+    fn is_repetition_draw(&self, state: &T) -> bool {
+        self.repetition_draw.is_some() && self.is_repeated(state)
+    }
+
+    /// Applies the repetition penalty, the repetition draw and the contempt to the `fitness` of `state`,
+    /// which is a direct successor of the last state of the current search path.
+    fn penalize(&self, state: &T, fitness: T::Fitness) -> T::Fitness {
+        let repeated = self.is_repeated(state);
+        let (fitness, draw) = match (self.repetition_draw, self.repetition_penalty) {
+            (Some(draw), _) if repeated => (draw, true),
+            (_, Some((penalty, sub))) if self.repetitions > 0 || repeated => {
+                (sub(fitness, penalty), false)
+            }
+            _ => (fitness, false),
+        };
+
+        match self.contempt {
+            Some((contempt, sub)) if draw || state.is_draw(self.player) => sub(fitness, contempt),
+            _ => fitness,
+        }
+    }
+
+    /// Adds `state` to the current search path, returning `None` if it is not tracked
+    /// and whether it is a repetition otherwise.
+    fn enter(&mut self, state: &T) -> Option<bool> {
+        self.repetition_key(state).map(|key| {
+            let repeated = self.path_keys.contains(&key);
+            self.path_keys.push(key);
+            self.repetitions += repeated as u32;
+            repeated
+        })
+    }
+
+    /// Removes the last state entered using `enter` from the current search path.
+    fn exit(&mut self, entered: Option<bool>) {
+        if let Some(repeated) = entered {
+            self.path_keys.pop();
+            self.repetitions -= repeated as u32;
+        }
+    }
+
+    /// Searches the state reached by executing `action` using `search`,
+    /// where `fitness` is the fitness after executing `action`.
+    ///
+    /// In case `action` is `irreversible` and this reaches the limit of irreversible actions,
+    /// the state is instead treated as a leaf, see `Bot::with_irreversible_limit`.
+    fn search_child(
+        &mut self,
+        irreversible: bool,
+        action: &T::Action,
+        fitness: T::Fitness,
+        search: impl FnOnce(&mut Self) -> Result<MiniMax<T>, CancelledError>,
+    ) -> Result<MiniMax<T>, CancelledError> {
+        if let Some(analysis) = self.analysis.as_mut() {
+            analysis.push(action, fitness);
+        }
+
+        let result = match self.irreversible_limit {
+            Some(limit) => {
+                let irreversible = irreversible as u32;
+                self.irreversible += irreversible;
+                let result = if self.irreversible >= limit {
+                    Ok(MiniMax::DeadEnd)
+                } else {
+                    search(self)
+                };
+                self.irreversible -= irreversible;
+                result
+            }
+            None => search(self),
+        };
+
+        if let Some(analysis) = self.analysis.as_mut() {
+            analysis.pop(&result);
+        }
+        result
+    }
+
+    /// Returns whether executing `action` in `state` counts towards the limit of irreversible actions.
+    fn is_irreversible(&self, state: &T, action: &T::Action) -> bool {
+        self.irreversible_limit.is_some() && state.is_irreversible(action)
+    }
+
+    /// Records `action` as analysed without searching the resulting state, see `Bot::analyze`.
+    fn analyse_leaf(&mut self, action: &T::Action, branch: Branch<T>) {
+        if let Some(analysis) = self.analysis.as_mut() {
+            analysis.leaf(action, branch);
+        }
+    }
+
+    /// Returns the expected upper limit of an action with the given `fitness` in case
+    /// it should be skipped due to futility pruning.
+    fn futile(&self, state: &State<'_, T>, depth: u32, fitness: T::Fitness) -> Option<T::Fitness> {
+        match (self.futility_margin, state.alpha) {
+            (Some((margin, add)), Some(alpha))
+                if depth == 1
+                    && state.active
+                    && !state.state.is_upper_bound(fitness, self.player) =>
+            {
+                Some(add(fitness, margin)).filter(|&bound| bound < alpha)
+            }
+            _ => None,
         }
     }
 
@@ -326,7 +3409,7 @@ impl<'a, T: Game> Ctxt<'a, T> {
     pub fn new_path(&mut self) -> Vec<T::Action> {
         // While it would be possible to create new paths using `Vec::with_capacity(depth)`
         // here, this does not actually influence the benchmarks so I decided against it.
-        self.path_cache.pop().unwrap_or_else(Vec::new)
+        self.path_cache.pop().unwrap_or_default()
     }
 
     /// Discards a path, storing it in the cache.
@@ -339,20 +3422,27 @@ impl<'a, T: Game> Ctxt<'a, T> {
 
     /// Returns all partially terminated actions may be better than `self.best_unfinished`,
     /// and should therefore be retried at the current depth.
-    fn relevant_partials(&mut self) -> impl IntoIterator<Item = Action<T>> {
-        self.partially_terminated.sort_by_key(|act| act.fitness);
+    fn relevant_partials(&mut self) -> Vec<Action<T>> {
+        let mut partially_terminated = mem::take(&mut self.partially_terminated);
+        partially_terminated.sort_by(|a, b| self.compare(a, b));
+        self.partially_terminated = partially_terminated;
 
-        if let Some(ref best) = self.best {
+        let mut relevant = if let Some(ref best) = self.best {
             // We only care about partially terminated paths which may be better than the current best.
             let pos = self
                 .partially_terminated
                 .iter()
-                .position(|act| act.fitness > best.fitness)
+                .position(|act| self.compare(act, best) == Ordering::Greater)
                 .unwrap_or(self.partially_terminated.len());
             self.partially_terminated.split_off(pos)
         } else {
             mem::take(&mut self.partially_terminated)
+        };
+
+        if self.partial_order == RetryOrder::Descending {
+            relevant.reverse();
         }
+        relevant
     }
 
     /// Updates `self.terminated` in case the new action has a higher fitness.
@@ -363,13 +3453,13 @@ impl<'a, T: Game> Ctxt<'a, T> {
         if self
             .terminated
             .as_ref()
-            .map_or(true, |best| best.fitness < act.fitness)
+            .map_or(true, |best| self.compare(best, &act) == Ordering::Less)
         {
             // Remove a partially terminated which are worse than the new best terminated action.
             //
             // This pretty much a manual reimplementation of `Vec::drain_filter`, which is currently unstable.
             for i in (0..self.partially_terminated.len()).rev() {
-                if self.partially_terminated[i].fitness <= act.fitness {
+                if self.compare(&self.partially_terminated[i], &act) != Ordering::Greater {
                     let act = self.partially_terminated.swap_remove(i);
                     self.discard_path(act.path);
                 }
@@ -377,7 +3467,7 @@ impl<'a, T: Game> Ctxt<'a, T> {
 
             // `best` is expected to always be better than `terminated`.
             if let Some(best) = self.best.take() {
-                if best.fitness > act.fitness {
+                if self.compare(&best, &act) == Ordering::Greater {
                     // Still relevant, put it back in.
                     self.best = Some(best);
                 } else {
@@ -386,78 +3476,286 @@ impl<'a, T: Game> Ctxt<'a, T> {
                 }
             }
 
-            if let Some(term) = self.terminated.replace(act) {
-                self.discard_path(term.path);
+            if let Some(term) = self.terminated.replace(act) {
+                self.discard_path(term.path);
+            }
+        } else {
+            self.discard_path(act.path);
+        }
+    }
+
+    /// Adds a new partially finished action in case its maximum fitness is
+    /// greater than the fitness of the best completely terminated action.
+    fn add_partially_terminated(&mut self, act: Action<T>) {
+        if self
+            .terminated
+            .as_ref()
+            .map_or(true, |best| self.compare(best, &act) == Ordering::Less)
+        {
+            self.partially_terminated.push(act);
+        } else {
+            self.discard_path(act.path);
+        }
+    }
+
+    fn add_best(&mut self, act: Action<T>) {
+        if self
+            .best
+            .as_ref()
+            .or(self.terminated.as_ref())
+            .map_or(true, |best| self.compare(best, &act) == Ordering::Less)
+        {
+            // Move the previous best action back into `unfinished`.
+            self.unfinished.extend(self.best.replace(act));
+        } else {
+            self.unfinished.push(act);
+        }
+    }
+
+    /// Compares two root actions with an equal fitness, first using `Game::rarity`,
+    /// then the comparator given to `Bot::select_by` and finally using `root_order`.
+    fn tie_break(&self, a: &T::Action, b: &T::Action) -> Ordering {
+        let rarity = |act| self.state.rarity(act, self.player);
+        let index = |act| self.root_order.iter().position(|root| root == act);
+        rarity(a)
+            .cmp(&rarity(b))
+            .then_with(|| match self.tie_break {
+                Some(tie_break) => tie_break(a, b),
+                None => Ordering::Equal,
+            })
+            .then_with(|| index(b).cmp(&index(a)))
+    }
+
+    /// Compares root actions by their fitness, using `tie_break` for actions with the same fitness.
+    fn compare(&self, a: &Action<T>, b: &Action<T>) -> Ordering {
+        a.fitness
+            .cmp(&b.fitness)
+            .then_with(|| match (a.path.last(), b.path.last()) {
+                (Some(a), Some(b)) => self.tie_break(a, b),
+                _ => unreachable!("unexpected empty path"),
+            })
+    }
+
+    /// Stop computing and return the currently best action.
+    fn cancel(&mut self) -> Action<T> {
+        if let Some(winning) = self.winning_action.take() {
+            self.proven = true;
+            return self.report(TerminalKind::Win, winning);
+        }
+
+        self.best
+            .take()
+            .or(self.terminated.take())
+            .or_else(|| {
+                mem::take(&mut self.unfinished)
+                    .into_iter()
+                    .max_by(|a, b| self.compare(a, b))
+            })
+            .unwrap_or_else(|| {
+                // In case no other action exists,
+                // we need at least one guaranteed losing action.
+                self.losing_action.take().unwrap()
+            })
+    }
+
+    /// Stores `action`, which reaches an upper bound, if its path is shorter than the path
+    /// of the current winning action.
+    fn add_winning(&mut self, action: Action<T>) {
+        if self
+            .winning_action
+            .as_ref()
+            .map_or(true, |act| act.path.len() > action.path.len())
+        {
+            if let Some(act) = self.winning_action.replace(action) {
+                self.discard_path(act.path);
+            }
+        } else {
+            self.discard_path(action.path);
+        }
+    }
+
+    /// Returns the winning action if its path has at most `max_len` actions.
+    ///
+    /// Wins with a path of at most `depth + 1` actions are found while analysing the given depth,
+    /// so there is no shorter win once this length is reached.
+    fn shortest_win(&mut self, max_len: usize) -> Option<Action<T>> {
+        if self
+            .winning_action
+            .as_ref()
+            .is_some_and(|act| act.path.len() <= max_len)
+        {
+            self.proven = true;
+            let winning = self.winning_action.take().unwrap();
+            Some(self.report(TerminalKind::Win, winning))
+        } else {
+            None
+        }
+    }
+
+    fn exhausted(&mut self) -> Option<Action<T>> {
+        if self.best.is_none() && self.unfinished.is_empty() {
+            // We can only get partially terminated actions in
+            // case there is a better non terminated one.
+            assert!(self.partially_terminated.is_empty());
+
+            // There are no other actions left which could win faster.
+            if let Some(winning) = self.shortest_win(usize::MAX) {
+                return Some(winning);
+            }
+
+            self.proven = true;
+            Some(match self.terminated.take() {
+                Some(terminated) => terminated,
+                None => {
+                    let losing_action = self.losing_action.take().unwrap();
+                    self.report(TerminalKind::Loss, losing_action)
+                }
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the currently best action, whose fitness is given to `RunCondition::completed_depth`.
+    fn current_best(&self) -> Option<&Action<T>> {
+        self.winning_action
+            .as_ref()
+            .or(self.best.as_ref())
+            .or(self.terminated.as_ref())
+            .or(self.losing_action.as_ref())
+    }
+
+    /// Runs iterative deepening starting at depth `start` until the best action was found,
+    /// `condition` cancels the search, or `max_depth` is reached.
+    fn deepen<U: RunCondition<Fitness = T::Fitness>>(
+        &mut self,
+        condition: &mut U,
+        start: u32,
+        max_depth: Option<u32>,
+    ) -> Action<T> {
+        for depth in start.. {
+            self.depth = depth;
+            if depth > start {
+                if let Some(action) = self
+                    .current_best()
+                    .and_then(|best| best.path.last().cloned())
+                {
+                    let depths = match self.stable.take() {
+                        Some((previous, depths)) if previous == action => depths + 1,
+                        _ => 1,
+                    };
+                    condition.stable_depths(depths);
+                    self.stable = Some((action, depths));
+                }
+            }
+
+            let best = if depth > start {
+                self.current_best().map(|best| &best.fitness)
+            } else {
+                None
+            };
+
+            if !condition.completed_depth(depth, best) || max_depth.is_some_and(|max| depth >= max)
+            {
+                return self.cancel();
+            }
+
+            // All wins which are shorter than the winning action were found at earlier depths.
+            if let Some(winning) = self.shortest_win(depth as usize) {
+                return winning;
+            }
+
+            // Return early in case there is only one relevant action left.
+            // This is the case if we either only have one possible actions,
+            // or if all other possible actions are worse than the lower bound.
+            if let Some(exhausted) = self.exhausted() {
+                return exhausted;
+            }
+
+            // A decisive action is good enough, so there is no need to search any deeper.
+            if self.stop_decisive
+                && self.winning_action.is_none()
+                && self
+                    .best
+                    .as_ref()
+                    .is_some_and(|best| self.state.is_decisive(best.fitness, self.player))
+            {
+                return self.best.take().unwrap();
+            }
+
+            if let Some(analysis) = self.analysis.as_mut() {
+                analysis.start_depth(depth);
+            }
+
+            let mut unfinished = mem::take(&mut self.unfinished);
+            // Try unfinished actions with a high expected fitness first,
+            // as they are expected to give us a better alpha value.
+            unfinished.sort_by(|a, b| self.compare(b, a));
+            if let Some(pondered) = self.pondered_action.take() {
+                if let Some(idx) = unfinished
+                    .iter()
+                    .position(|act| act.path.last() == Some(&pondered))
+                {
+                    let action = unfinished.remove(idx);
+                    unfinished.insert(0, action);
+                }
+            }
+
+            if let Some(best) = self.best.take() {
+                // If computation is cancelled here, we don't know anything new,
+                // so we can just return the previous best action.
+                // A winning action is still better than the previous best action.
+                let on_cancel =
+                    |ctxt: &mut Ctxt<T>, act| ctxt.shortest_win(usize::MAX).unwrap_or(act);
+                if let Some(ret) = self.try_action(best, depth, condition, on_cancel) {
+                    return ret;
+                }
+            }
+
+            for action in unfinished.into_iter() {
+                // In case computation is cancelled here, we may not yet have computed the best action of
+                // the previous depth, to guard against this, we add the cancelled action back to `state.unfinished`
+                // in case it is still empty.
+                let on_cancel = |ctxt: &mut Ctxt<T>, act| {
+                    if ctxt.unfinished.is_empty() {
+                        ctxt.unfinished.push(act);
+                    }
+                    ctxt.cancel()
+                };
+
+                if let Some(ret) = self.try_action(action, depth, condition, on_cancel) {
+                    return ret;
+                }
+            }
+
+            // We only test partially terminated action which may still be better than the best
+            // fitness at the current depth.
+            //
+            // As the current best fitness does not come from a terminated path,
+            // we still have to keep the other partially terminated actions around,
+            // in case the best fitness of a later depth is lower.
+            for action in self.relevant_partials() {
+                // In case computation is cancelled here, we already tested at least some actions which were better than
+                // the cancelled partial action at the previous depth, so we can use `ctxt.cancel()` without any special
+                // considerations.
+                if let Some(ret) =
+                    self.try_action(action, depth, condition, |ctxt, _| ctxt.cancel())
+                {
+                    return ret;
+                }
+            }
+
+            if let Some(analysis) = self.analysis.as_mut() {
+                analysis.complete_depth();
             }
-        } else {
-            self.discard_path(act.path);
-        }
-    }
-
-    /// Adds a new partially finished action in case its maximum fitness is
-    /// greater than the fitness of the best completely terminated action.
-    fn add_partially_terminated(&mut self, act: Action<T>) {
-        if self
-            .terminated
-            .as_ref()
-            .map_or(true, |best| best.fitness < act.fitness)
-        {
-            self.partially_terminated.push(act);
-        } else {
-            self.discard_path(act.path);
-        }
-    }
-
-    fn add_best(&mut self, act: Action<T>) {
-        if self
-            .best
-            .as_ref()
-            .or(self.terminated.as_ref())
-            .map_or(true, |best| best.fitness < act.fitness)
-        {
-            // Move the previous best action back into `unfinished`.
-            self.unfinished.extend(self.best.replace(act));
-        } else {
-            self.unfinished.push(act);
         }
-    }
-
-    /// Stop computing and return the currently best action.
-    fn cancel(&mut self) -> Action<T> {
-        self.best
-            .take()
-            .or(self.terminated.take())
-            .or_else(|| {
-                mem::take(&mut self.unfinished)
-                    .into_iter()
-                    .max_by_key(|act| act.fitness)
-            })
-            .unwrap_or_else(|| {
-                // In case no other action exists,
-                // we need at least one guaranteed losing action.
-                self.losing_action.take().unwrap()
-            })
-    }
-
-    fn exhausted(&mut self) -> Option<Action<T>> {
-        if self.best.is_none() && self.unfinished.is_empty() {
-            // We can only get partially terminated actions in
-            // case there is a better non terminated one.
-            assert!(self.partially_terminated.is_empty());
 
-            Some(
-                self.terminated
-                    .take()
-                    .unwrap_or_else(|| self.losing_action.take().unwrap()),
-            )
-        } else {
-            None
-        }
+        unreachable!();
     }
 
     /// Tests the given action at the current depth, returns `Some`
     /// once we are finished.
-    fn try_action<U: RunCondition>(
+    fn try_action<U: RunCondition<Fitness = T::Fitness>>(
         &mut self,
         mut action: Action<T>,
         depth: u32,
@@ -467,22 +3765,74 @@ impl<'a, T: Game> Ctxt<'a, T> {
         let mut updated_state = self.state.clone();
         let (start, rest) = action.path.split_last().expect("unexpected empty path");
 
+        validate(&updated_state, start, self.player);
         let fitness = updated_state.execute(start, self.player);
-        match self.minimax_with_path(
-            rest.iter().cloned().rev(),
-            updated_state,
-            depth,
-            self.best
-                .as_ref()
-                .or(self.terminated.as_ref())
-                .map(|act| act.fitness),
-            None,
-            condition,
-        ) {
+        let entered = self.enter(self.state);
+        let fitness = self.penalize(&updated_state, fitness);
+        // Wins are compared by their length instead, see `Ctxt::add_winning`.
+        let (alpha, preferred, win) = match self.best.as_ref().or(self.terminated.as_ref()) {
+            Some(best) if self.root_cutoff => {
+                let win = self.state.is_upper_bound(best.fitness, self.player);
+                (
+                    Some(best.fitness),
+                    !win && self.tie_break(best.path.last().unwrap(), start) == Ordering::Less,
+                    win,
+                )
+            }
+            _ => (None, false, false),
+        };
+        // Paths given to `Bot::verify_line` may be longer than the current depth.
+        let irreversible = self.is_irreversible(self.state, start);
+        let result = self.search_child(irreversible, start, fitness, |ctxt| {
+            let line = || rest.iter().cloned().rev().take(depth as usize);
+            let state = &mut updated_state;
+            match ctxt.minimax_with_path(line(), state, depth, alpha, None, condition)? {
+                // An action which is preferred over the current best action is better even if
+                // both have the same fitness, so it has to be analysed again if it may be equally good.
+                MiniMax::Terminated(path, Branch::Worse(bound))
+                | MiniMax::Open(path, Branch::Worse(bound))
+                    if preferred && alpha.is_some_and(|alpha| bound >= alpha) =>
+                {
+                    ctxt.discard_path(path);
+                    ctxt.minimax_with_path(line(), state, depth, None, None, condition)
+                }
+                // The current best action may reach an upper bound without being terminated,
+                // e.g. when analysing states which are not quiet, in which case it
+                // must not prevent us from finding an actual win.
+                MiniMax::Terminated(path, Branch::Worse(bound))
+                    if win && alpha.is_some_and(|alpha| bound >= alpha) =>
+                {
+                    ctxt.discard_path(path);
+                    ctxt.minimax_with_path(line(), state, depth, None, None, condition)
+                }
+                minimax => Ok(minimax),
+            }
+        });
+        self.exit(entered);
+
+        if let (Some(root), Ok(minimax)) = (self.root.as_mut(), &result) {
+            let (fitness, path) = match minimax {
+                MiniMax::DeadEnd => (fitness, &[][..]),
+                MiniMax::Terminated(path, branch) | MiniMax::Open(path, branch) => {
+                    (branch.fitness(), &path[..])
+                }
+            };
+            if let Some(entry) = root.iter_mut().find(|act| act.path.last() == Some(start)) {
+                entry.fitness = fitness;
+                entry.path.clear();
+                entry.path.extend(path.iter().cloned());
+                entry.path.push(start.clone());
+            }
+        }
+
+        match result {
             Err(CancelledError) => Some(on_cancel(self, action)),
             Ok(MiniMax::DeadEnd) => {
+                // The fitness of `action` is not penalized, see `Ctxt::penalize`.
+                action.fitness = fitness;
                 if self.state.is_upper_bound(fitness, self.player) {
-                    Some(action)
+                    self.add_winning(action);
+                    self.shortest_win(depth as usize + 1)
                 } else if self.state.is_lower_bound(fitness, self.player) {
                     if self
                         .losing_action
@@ -490,7 +3840,9 @@ impl<'a, T: Game> Ctxt<'a, T> {
                         .map_or(true, |act| act.path.len() < action.path.len())
                     {
                         let act = self.losing_action.replace(action);
-                        act.map(|act| self.discard_path(act.path));
+                        if let Some(act) = act {
+                            self.discard_path(act.path)
+                        }
                     }
                     None
                 } else {
@@ -503,7 +3855,8 @@ impl<'a, T: Game> Ctxt<'a, T> {
                 self.discard_path(action.path);
                 let action = Action { fitness, path };
                 if self.state.is_upper_bound(fitness, self.player) {
-                    Some(action)
+                    self.add_winning(action);
+                    self.shortest_win(depth as usize + 1)
                 } else if self.state.is_lower_bound(fitness, self.player) {
                     if self
                         .losing_action
@@ -511,7 +3864,9 @@ impl<'a, T: Game> Ctxt<'a, T> {
                         .map_or(true, |act| act.path.len() < action.path.len())
                     {
                         let act = self.losing_action.replace(action);
-                        act.map(|act| self.discard_path(act.path));
+                        if let Some(act) = act {
+                            self.discard_path(act.path)
+                        }
                     }
                     None
                 } else {
@@ -548,41 +3903,269 @@ impl<'a, T: Game> Ctxt<'a, T> {
     }
 
     /// Computes the next possible steps and sorts them to maximize
-    /// cutoffs.
-    fn generate_game_states(&self, game_state: &T) -> (bool, Vec<(T, T::Action, T::Fitness)>) {
-        let (active, actions) = game_state.actions(self.player);
+    /// cutoffs, only using `Game::noisy_actions` if `noisy` is `true`.
+    fn generate_game_states(&self, game_state: &mut T, noisy: bool) -> (bool, Vec<Child<T>>) {
+        let (active, actions) = if noisy {
+            game_state.noisy_actions(self.player)
+        } else {
+            game_state.heuristic_actions(self.player)
+        };
 
-        let mut game_states: Vec<_> = actions
-            .into_iter()
-            .map(|action| {
-                let mut game_state = game_state.clone();
-                let fitness = game_state.execute(&action, self.player);
-                (game_state, action, fitness)
-            })
-            .collect();
+        let mut game_states = Vec::with_capacity(game_state.max_actions().unwrap_or(0));
+        if self.order_key || self.undo.is_some() {
+            game_states.extend(actions.into_iter().map(|action| {
+                let order = if self.order_key {
+                    game_state.order_key(&action, self.player)
+                } else {
+                    self.look_ahead(game_state, &action)
+                };
+                Child::Pending(action, order)
+            }));
+        } else {
+            game_states.extend(
+                actions
+                    .into_iter()
+                    .map(|action| Child::Executed(self.execute(game_state, action))),
+            );
+        }
 
         // Sort the actions so the most probable one is checked first.
         // This allows for faster cutoffs. Note that depending on the fitness
         // function, this can hit some fairly bad cases.
-        if active {
-            game_states.sort_by(|(_, _, a), (_, _, b)| b.cmp(a));
+        if self.trust_action_order {
+            // The game already returns its actions in a good order.
+            return (active, game_states);
+        } else if !self.history.is_empty() {
+            let history = |child: &Child<T>| Reverse(self.history_of(game_state, child.action()));
+            if active {
+                game_states.sort_by_cached_key(|child| (Reverse(child.order()), history(child)));
+            } else {
+                game_states.sort_by_cached_key(|child| (child.order(), history(child)));
+            }
+        } else if active {
+            game_states.sort_by_key(|child| Reverse(child.order()));
         } else {
-            game_states.sort_by(|(_, _, a), (_, _, b)| a.cmp(b));
+            game_states.sort_by_key(Child::order);
+        }
+
+        // Actions which recently caused a cutoff at the same ply are likely to do so again,
+        // so we analyse them right after the action with the best fitness.
+        if let Some(killers) = self.killers.get(self.ply) {
+            let mut start = 1.min(game_states.len());
+            for killer in killers.iter().flatten() {
+                if let Some(idx) = game_states[start..]
+                    .iter()
+                    .position(|child| child.action() == killer)
+                {
+                    game_states[start..=start + idx].rotate_right(1);
+                    start += 1;
+                }
+            }
         }
 
         (active, game_states)
     }
 
+    /// Generates the children of `game_state`, passing if there are no possible actions, see `Game::pass`.
+    ///
+    /// Returns the state whose children were generated, which is stored in `passed` after passing.
+    fn generate_or_pass<'s>(
+        &self,
+        game_state: &'s mut T,
+        passed: &'s mut Option<T>,
+    ) -> (&'s mut T, bool, Vec<Child<T>>) {
+        let (active, game_states) = self.generate_game_states(game_state, false);
+        if game_states.is_empty() {
+            if let Some(state) = self.pass(game_state) {
+                let state = passed.insert(state);
+                let (active, game_states) = self.generate_game_states(state, false);
+                return (state, active, game_states);
+            }
+        }
+        (game_state, active, game_states)
+    }
+
+    /// Returns the state reached by passing in `game_state`, if this is supported.
+    fn pass(&self, game_state: &T) -> Option<T> {
+        let mut passed = game_state.clone();
+        if passed.pass(self.player) {
+            Some(passed)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the action with the best immediate fitness for the active player of `game_state`,
+    /// which is used to evaluate states at the lowest depth.
+    fn best_leaf(&self, game_state: &mut T) -> Option<(T::Action, T::Fitness)> {
+        let (active, actions) = game_state.heuristic_actions(self.player);
+        let actions = actions.into_iter().map(|action| {
+            let fitness = self.look_ahead(game_state, &action);
+            (action, game_state.fitness_key(fitness), fitness)
+        });
+        // Only compare the fitness itself if the cheaper keys are equal.
+        let cmp = |(_, a_key, a): &(_, u64, T::Fitness), (_, b_key, b): &(_, u64, _)| {
+            let ord = a_key.cmp(b_key);
+            debug_assert!(
+                ord == Ordering::Equal || ord == a.cmp(b),
+                "`Game::fitness_key` is not order preserving"
+            );
+            ord.then_with(|| a.cmp(b))
+        };
+        let selected = if active {
+            actions.max_by(cmp)
+        } else {
+            actions.min_by(cmp)
+        };
+        selected.map(|(action, _, fitness)| (action, fitness))
+    }
+
+    /// Executes `action` in a clone of `game_state`.
+    fn execute(&self, game_state: &T, action: T::Action) -> GameState<T> {
+        let mut game_state = game_state.clone();
+        let fitness = self.execute_in_place(&mut game_state, &action);
+        let fitness = self.penalize(&game_state, fitness);
+        (game_state, action, fitness)
+    }
+
+    /// Executes `action` in `game_state` itself.
+    ///
+    /// In debug builds, this checks that `Game::look_ahead` returns the same fitness as `Game::execute`,
+    /// as a mismatch causes the bot to silently select bad actions.
+    fn execute_in_place(&self, game_state: &mut T, action: &T::Action) -> T::Fitness {
+        validate(game_state, action, self.player);
+        #[cfg(debug_assertions)]
+        let expected = game_state.look_ahead(action, self.player);
+        let fitness = game_state.execute(action, self.player);
+        #[cfg(debug_assertions)]
+        assert!(
+            expected == fitness,
+            "`Game::look_ahead` and `Game::execute` returned a different fitness for the same action"
+        );
+        fitness
+    }
+
+    /// Returns the fitness after executing `action` in `game_state`, including the repetition penalty.
+    fn look_ahead(&self, game_state: &mut T, action: &T::Action) -> T::Fitness {
+        if let Some(undo) = self.undo {
+            let fitness = self.execute_in_place(game_state, action);
+            let fitness = self.penalize(game_state, fitness);
+            undo(game_state, action, self.player);
+            fitness
+        } else if self.repetition_penalty.is_some()
+            || self.repetition_draw.is_some()
+            || self.contempt.is_some()
+        {
+            // We need the resulting state to check for repetitions and draws.
+            let mut state = game_state.clone();
+            validate(&state, action, self.player);
+            let fitness = state.execute(action, self.player);
+            self.penalize(&state, fitness)
+        } else {
+            game_state.look_ahead(action, self.player)
+        }
+    }
+
+    /// Returns the state reached by `child`, executing its action if necessary.
+    ///
+    /// When using `Bot::with_undo`, the action is instead executed in `game_state` itself,
+    /// in which case `None` is returned and the action must be reverted using `Ctxt::revert`.
+    fn execute_child(
+        &self,
+        game_state: &mut T,
+        child: Child<T>,
+    ) -> (Option<T>, T::Action, T::Fitness) {
+        match child {
+            Child::Executed((state, action, fitness)) => (Some(state), action, fitness),
+            Child::Pending(action, _) if self.undo.is_some() => {
+                let fitness = self.execute_in_place(game_state, &action);
+                let fitness = self.penalize(game_state, fitness);
+                (None, action, fitness)
+            }
+            Child::Pending(action, _) => {
+                let (state, action, fitness) = self.execute(game_state, action);
+                (Some(state), action, fitness)
+            }
+        }
+    }
+
+    /// Reverts `action` in case `Ctxt::execute_child` executed it in `game_state` itself.
+    fn revert(&self, game_state: &mut T, executed: &Option<T>, action: &T::Action) {
+        if let (None, Some(undo)) = (executed, self.undo) {
+            undo(game_state, action, self.player);
+        }
+    }
+
+    /// Returns the index of `action` in `self.history`, see `Bot::with_history_heuristic`.
+    fn history_index(&self, game_state: &T, action: &T::Action) -> Option<usize> {
+        game_state
+            .hash_action(action, self.player)
+            .map(|key| (key % HISTORY_SIZE as u64) as usize)
+    }
+
+    /// Returns how often `action` caused cutoffs, weighted by their depth.
+    fn history_of(&self, game_state: &T, action: &T::Action) -> u32 {
+        self.history_index(game_state, action)
+            .map_or(0, |idx| self.history[idx])
+    }
+
+    /// Remembers the action of `game_state`, which is at the current ply and the given `depth`,
+    /// which caused `cutoff`.
+    fn add_cutoff(&mut self, game_state: &T, depth: u32, cutoff: &MiniMax<T>) {
+        let action = match cutoff {
+            MiniMax::Terminated(path, _) | MiniMax::Open(path, _) => match path.last() {
+                Some(action) => action,
+                None => return,
+            },
+            _ => return,
+        };
+
+        if !self.history.is_empty() {
+            if let Some(idx) = self.history_index(game_state, action) {
+                self.history[idx] = self.history[idx].saturating_add(depth.saturating_mul(depth));
+            }
+        }
+
+        if self.killers.len() <= self.ply {
+            self.killers.resize_with(self.ply + 1, Default::default);
+        }
+        let killers = &mut self.killers[self.ply];
+        if killers[0].as_ref() != Some(action) {
+            killers[1] = killers[0].replace(action.clone());
+        }
+    }
+
     /// As we want to ignore as many possible subtrees as possible,
     /// we start each depth by taking the best possible path of the
     /// previous depth.
     ///
     /// As this path is hopefully also a good choice at this depth,
     /// we very quickly get a good alpha/lower limit.
-    fn minimax_with_path<U: RunCondition>(
+    fn minimax_with_path<U: RunCondition<Fitness = T::Fitness>>(
+        &mut self,
+        path: impl Iterator<Item = T::Action>,
+        game_state: &mut T,
+        depth: u32,
+        alpha: Option<T::Fitness>,
+        beta: Option<T::Fitness>,
+        condition: &mut U,
+    ) -> Result<MiniMax<T>, CancelledError> {
+        if self.is_repetition_draw(game_state) {
+            return Ok(MiniMax::DeadEnd);
+        }
+
+        let entered = self.enter(game_state);
+        self.ply += 1;
+        let result = self.minimax_with_path_inner(path, game_state, depth, alpha, beta, condition);
+        self.ply -= 1;
+        self.exit(entered);
+        result
+    }
+
+    fn minimax_with_path_inner<U: RunCondition<Fitness = T::Fitness>>(
         &mut self,
         mut path: impl Iterator<Item = T::Action>,
-        game_state: T,
+        game_state: &mut T,
         depth: u32,
         alpha: Option<T::Fitness>,
         beta: Option<T::Fitness>,
@@ -595,14 +4178,20 @@ impl<'a, T: Game> Ctxt<'a, T> {
         let action = if let Some(action) = path.next() {
             action
         } else {
-            return self.minimax(game_state, depth, alpha, beta, condition);
+            return self.minimax_inner(game_state, depth, alpha, beta, None, condition);
         };
 
+        // The rest of the path is ignored if the game is already over.
+        if game_state.status(self.player).is_over() {
+            return Ok(MiniMax::DeadEnd);
+        }
+
         if depth == 0 {
             unreachable!("lowest depth with non empty path");
         }
 
-        let (active, mut game_states) = self.generate_game_states(&game_state);
+        let mut passed = None;
+        let (game_state, active, mut game_states) = self.generate_or_pass(game_state, &mut passed);
 
         let mut state = State::new(
             self.new_path(),
@@ -612,33 +4201,216 @@ impl<'a, T: Game> Ctxt<'a, T> {
             None,
             active,
         );
-        match game_states.iter().position(|(_, a, _)| *a == action) {
+        match game_states
+            .iter()
+            .position(|child| *child.action() == action)
+        {
             Some(idx) => {
-                let (game_state, action, fitness) = game_states.remove(idx);
-
-                let minimax = self
-                    .minimax_with_path(
-                        path,
-                        game_state,
-                        depth - 1,
-                        state.alpha,
-                        state.beta,
-                        condition,
-                    )?
-                    .with(self, action, fitness);
+                let child = game_states.remove(idx);
+                let irreversible = self.is_irreversible(state.state, child.action());
+                let (mut executed, action, fitness) = self.execute_child(state.state, child);
+
+                let (alpha, beta) = (state.alpha, state.beta);
+                let minimax = self.search_child(irreversible, &action, fitness, |ctxt| {
+                    let game_state = executed.as_mut().unwrap_or(&mut *state.state);
+                    ctxt.minimax_with_path(path, game_state, depth - 1, alpha, beta, condition)
+                });
+                self.revert(state.state, &executed, &action);
+                let minimax = minimax?.with(self, action, fitness);
 
                 if let Some(cutoff) = state.bind(self, minimax) {
+                    condition.cutoff();
+                    self.add_cutoff(state.state, depth, &cutoff);
                     return Ok(cutoff);
                 }
             }
             None => unreachable!("path segment not found"),
         }
 
-        for (game_state, action, fitness) in game_states {
-            let minimax = self
-                .minimax(game_state, depth - 1, state.alpha, state.beta, condition)?
-                .with(self, action, fitness);
+        for child in game_states {
+            let irreversible = self.is_irreversible(state.state, child.action());
+            let (mut executed, action, fitness) = self.execute_child(state.state, child);
+            if let Some(bound) = self.futile(&state, depth, fitness) {
+                self.revert(state.state, &executed, &action);
+                self.analyse_leaf(&action, Branch::Worse(bound));
+                let mut path = self.new_path();
+                path.push(action);
+                let minimax = MiniMax::Open(path, Branch::Worse(bound));
+                if let Some(cutoff) = state.bind(self, minimax) {
+                    condition.cutoff();
+                    return Ok(cutoff);
+                }
+                continue;
+            }
+
+            let (alpha, beta) = (state.alpha, state.beta);
+            let scout = state.scout();
+            let minimax = self.search_child(irreversible, &action, fitness, |ctxt| {
+                let game_state = executed.as_mut().unwrap_or(&mut *state.state);
+                ctxt.minimax_scout(scout, game_state, depth - 1, alpha, beta, condition)
+            });
+            self.revert(state.state, &executed, &action);
+            let minimax = minimax?.with(self, action, fitness);
+            if let Some(cutoff) = state.bind(self, minimax) {
+                condition.cutoff();
+                self.add_cutoff(state.state, depth, &cutoff);
+                return Ok(cutoff);
+            }
+        }
+
+        Ok(state.consume())
+    }
+
+    fn minimax<U: RunCondition<Fitness = T::Fitness>>(
+        &mut self,
+        game_state: &mut T,
+        depth: u32,
+        alpha: Option<T::Fitness>,
+        beta: Option<T::Fitness>,
+        condition: &mut U,
+    ) -> Result<MiniMax<T>, CancelledError> {
+        if self.is_repetition_draw(game_state) {
+            return Ok(MiniMax::DeadEnd);
+        }
+
+        // States at the lowest depth are cheaper to compute than to cache.
+        let key = match self.transpositions {
+            Some(_) if depth > 0 => game_state.key(self.player),
+            _ => None,
+        };
+        let mut best = None;
+        if let Some(key) = key {
+            let table = self.transpositions.take().unwrap();
+            let cached = table
+                .get(key, depth, alpha, beta)
+                .map(|result| match result {
+                    MiniMax::DeadEnd => MiniMax::DeadEnd,
+                    result => transposition::copy(result, self.new_path()),
+                });
+            if cached.is_none() {
+                best = table.best_action(key, depth).cloned();
+            }
+            self.transpositions = Some(table);
+            if let Some(cached) = cached {
+                return Ok(cached);
+            }
+        }
+
+        let entered = self.enter(game_state);
+        self.ply += 1;
+        let result = self.minimax_inner(game_state, depth, alpha, beta, best, condition);
+        self.ply -= 1;
+        self.exit(entered);
+
+        if let (Some(key), Ok(result), Some(table)) = (key, &result, self.transpositions.as_mut()) {
+            table.insert(key, depth, alpha, beta, result);
+        }
+        result
+    }
+
+    /// Searches `game_state` like `minimax`, first using a null window if principal variation search
+    /// is used and the previous action of the parent state is expected to be the best one.
+    ///
+    /// `scout` is `Some(true)` if the parent state is active and `Some(false)` if it is not,
+    /// see `Bot::with_principal_variation_search`.
+    fn minimax_scout<U: RunCondition<Fitness = T::Fitness>>(
+        &mut self,
+        scout: Option<bool>,
+        game_state: &mut T,
+        depth: u32,
+        alpha: Option<T::Fitness>,
+        beta: Option<T::Fitness>,
+        condition: &mut U,
+    ) -> Result<MiniMax<T>, CancelledError> {
+        let window = match (self.null_window, scout, alpha, beta) {
+            (Some((step, add, _)), Some(true), Some(alpha), beta)
+                if !game_state.is_upper_bound(alpha, self.player) =>
+            {
+                Some((alpha, add(alpha, step)))
+                    .filter(|&(_, upper)| beta.map_or(true, |beta| upper < beta))
+            }
+            (Some((step, _, sub)), Some(false), alpha, Some(beta))
+                if !game_state.is_lower_bound(beta, self.player) =>
+            {
+                Some((sub(beta, step), beta))
+                    .filter(|&(lower, _)| alpha.map_or(true, |alpha| alpha < lower))
+            }
+            _ => None,
+        };
+
+        if let Some((lower, upper)) = window {
+            // The null window only checks whether this action is better than the current best action,
+            // in which case it has to be analysed again to get its actual fitness.
+            match self.minimax(game_state, depth, Some(lower), Some(upper), condition)? {
+                MiniMax::Terminated(path, branch) | MiniMax::Open(path, branch)
+                    if scout == Some(true) && branch.fitness() > lower
+                        || scout == Some(false) && branch.fitness() < upper =>
+                {
+                    self.discard_path(path)
+                }
+                minimax => return Ok(minimax),
+            }
+        }
+
+        self.minimax(game_state, depth, alpha, beta, condition)
+    }
+
+    /// Analyses the noisy actions of `game_state`, which is at the lowest depth but not quiet, see `Game::is_quiet`.
+    ///
+    /// The active player is not forced to use a noisy action, so the usual evaluation of a leaf,
+    /// the best immediate fitness of all actions, is used as a bound, also known as standing pat.
+    fn quiescence<U: RunCondition<Fitness = T::Fitness>>(
+        &mut self,
+        game_state: &mut T,
+        alpha: Option<T::Fitness>,
+        beta: Option<T::Fitness>,
+        condition: &mut U,
+    ) -> Result<MiniMax<T>, CancelledError> {
+        let mut passed = None;
+        let mut stand_pat = self.best_leaf(game_state);
+        if stand_pat.is_none() {
+            passed = self.pass(game_state);
+            stand_pat = passed.as_mut().and_then(|passed| self.best_leaf(passed));
+        }
+        let (action, fitness) = match stand_pat {
+            Some(stand_pat) => stand_pat,
+            None => return Ok(MiniMax::DeadEnd),
+        };
+        let game_state = passed.as_mut().unwrap_or(game_state);
+
+        condition.leaf();
+        self.analyse_leaf(&action, Branch::Equal(fitness));
+        let (active, game_states) = self.generate_game_states(game_state, true);
+        let mut state = State::new(
+            self.new_path(),
+            game_state,
+            self.player,
+            alpha,
+            beta,
+            active,
+        );
+        let mut path = self.new_path();
+        path.push(action);
+        if let Some(cutoff) = state.bind(self, MiniMax::Open(path, Branch::Equal(fitness))) {
+            condition.cutoff();
+            return Ok(cutoff);
+        }
+
+        for child in game_states {
+            let irreversible = self.is_irreversible(state.state, child.action());
+            let (mut executed, action, fitness) = self.execute_child(state.state, child);
+            let (alpha, beta) = (state.alpha, state.beta);
+            let minimax = self.search_child(irreversible, &action, fitness, |ctxt| {
+                let game_state = executed.as_mut().unwrap_or(&mut *state.state);
+                ctxt.quiescence += 1;
+                let minimax = ctxt.minimax(game_state, 0, alpha, beta, condition);
+                ctxt.quiescence -= 1;
+                minimax
+            });
+            self.revert(state.state, &executed, &action);
+            let minimax = minimax?.with(self, action, fitness);
             if let Some(cutoff) = state.bind(self, minimax) {
+                condition.cutoff();
                 return Ok(cutoff);
             }
         }
@@ -646,30 +4418,38 @@ impl<'a, T: Game> Ctxt<'a, T> {
         Ok(state.consume())
     }
 
-    fn minimax<U: RunCondition>(
+    fn minimax_inner<U: RunCondition<Fitness = T::Fitness>>(
         &mut self,
-        game_state: T,
+        game_state: &mut T,
         depth: u32,
         alpha: Option<T::Fitness>,
         beta: Option<T::Fitness>,
+        best: Option<T::Action>,
         condition: &mut U,
     ) -> Result<MiniMax<T>, CancelledError> {
         if !condition.step() {
             return Err(CancelledError);
         }
 
-        if depth == 0 {
-            let (active, actions) = game_state.actions(self.player);
-            let actions = actions.into_iter().map(|action| {
-                let fitness = game_state.look_ahead(&action, self.player);
-                (action, fitness)
-            });
-            let selected = if active {
-                actions.max_by_key(|(_, fitness)| *fitness)
-            } else {
-                actions.min_by_key(|(_, fitness)| *fitness)
+        if game_state.status(self.player).is_over() {
+            return Ok(MiniMax::DeadEnd);
+        }
+
+        // States which are not quiet are analysed further, even if they are at the lowest depth.
+        if depth == 0 && self.quiescence < QUIESCENCE_LIMIT && !game_state.is_quiet(self.player) {
+            return self.quiescence(game_state, alpha, beta, condition);
+        } else if depth == 0 {
+            condition.leaf();
+            let selected = match self.best_leaf(game_state) {
+                None => self
+                    .pass(game_state)
+                    .and_then(|mut passed| self.best_leaf(&mut passed)),
+                selected => selected,
             };
 
+            if let Some((action, fitness)) = selected.as_ref() {
+                self.analyse_leaf(action, Branch::Equal(*fitness));
+            }
             return Ok(selected.map_or(MiniMax::DeadEnd, |(action, fitness)| {
                 let mut path = self.new_path();
                 path.push(action);
@@ -677,12 +4457,21 @@ impl<'a, T: Game> Ctxt<'a, T> {
             }));
         }
 
-        let (active, game_states) = self.generate_game_states(&game_state);
+        let mut passed = None;
+        let (game_state, active, mut game_states) = self.generate_or_pass(game_state, &mut passed);
 
         if game_states.is_empty() {
             return Ok(MiniMax::DeadEnd);
         }
 
+        // The best action found by a previous search of this state is likely to still be good,
+        // so it is analysed first, similar to the path of the previous depth in `minimax_with_path`.
+        if let Some(best) = best {
+            if let Some(idx) = game_states.iter().position(|child| *child.action() == best) {
+                game_states[..=idx].rotate_right(1);
+            }
+        }
+
         let mut state = State::new(
             self.new_path(),
             game_state,
@@ -691,11 +4480,34 @@ impl<'a, T: Game> Ctxt<'a, T> {
             beta,
             active,
         );
-        for (game_state, action, fitness) in game_states {
-            let minimax = self
-                .minimax(game_state, depth - 1, state.alpha, state.beta, condition)?
-                .with(self, action, fitness);
+        for child in game_states {
+            let irreversible = self.is_irreversible(state.state, child.action());
+            let (mut executed, action, fitness) = self.execute_child(state.state, child);
+            if let Some(bound) = self.futile(&state, depth, fitness) {
+                self.revert(state.state, &executed, &action);
+                self.analyse_leaf(&action, Branch::Worse(bound));
+                let mut path = self.new_path();
+                path.push(action);
+                let minimax = MiniMax::Open(path, Branch::Worse(bound));
+                if let Some(cutoff) = state.bind(self, minimax) {
+                    condition.cutoff();
+                    return Ok(cutoff);
+                }
+                continue;
+            }
+
+            let (alpha, beta) = (state.alpha, state.beta);
+            let scout = state.scout();
+            let minimax = self.search_child(irreversible, &action, fitness, |ctxt| {
+                let game_state = executed.as_mut().unwrap_or(&mut *state.state);
+                let depth = depth.saturating_sub(1);
+                ctxt.minimax_scout(scout, game_state, depth, alpha, beta, condition)
+            });
+            self.revert(state.state, &executed, &action);
+            let minimax = minimax?.with(self, action, fitness);
             if let Some(cutoff) = state.bind(self, minimax) {
+                condition.cutoff();
+                self.add_cutoff(state.state, depth, &cutoff);
                 return Ok(cutoff);
             }
         }
@@ -704,8 +4516,8 @@ impl<'a, T: Game> Ctxt<'a, T> {
     }
 }
 
-struct State<T: Game> {
-    state: T,
+struct State<'s, T: Game> {
+    state: &'s mut T,
     player: T::Player,
     alpha: Option<T::Fitness>,
     beta: Option<T::Fitness>,
@@ -715,10 +4527,10 @@ struct State<T: Game> {
     active: bool,
 }
 
-impl<T: Game> State<T> {
+impl<'s, T: Game> State<'s, T> {
     fn new(
         path: Vec<T::Action>,
-        state: T,
+        state: &'s mut T,
         player: T::Player,
         alpha: Option<T::Fitness>,
         beta: Option<T::Fitness>,
@@ -747,6 +4559,12 @@ impl<T: Game> State<T> {
         self.best_fitness = Some(fitness);
     }
 
+    /// Returns whether the remaining actions may be searched using a null window,
+    /// which is the case once the first action has been analysed.
+    fn scout(&self) -> Option<bool> {
+        self.best_fitness.map(|_| self.active)
+    }
+
     fn bind(&mut self, ctxt: &mut Ctxt<'_, T>, value: MiniMax<T>) -> Option<MiniMax<T>> {
         match value {
             MiniMax::DeadEnd => unreachable!(),
@@ -794,15 +4612,9 @@ impl<T: Game> State<T> {
         };
 
         if self.terminated {
-            Some(MiniMax::Terminated(
-                mem::replace(&mut self.path, Vec::new()),
-                branch,
-            ))
+            Some(MiniMax::Terminated(mem::take(&mut self.path), branch))
         } else {
-            Some(MiniMax::Open(
-                mem::replace(&mut self.path, Vec::new()),
-                branch,
-            ))
+            Some(MiniMax::Open(mem::take(&mut self.path), branch))
         }
     }
 
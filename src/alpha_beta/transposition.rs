@@ -0,0 +1,201 @@
+use crate::Game;
+
+use super::{Branch, MiniMax};
+
+use alloc::vec::Vec;
+
+/// A previously computed result of `Ctxt::minimax`.
+struct Entry<T: Game> {
+    key: u64,
+    depth: u32,
+    alpha: Option<T::Fitness>,
+    beta: Option<T::Fitness>,
+    result: MiniMax<T>,
+}
+
+impl<T: Game> Entry<T> {
+    /// Whether the result does not depend on the bounds used to compute it.
+    fn is_exact(&self) -> bool {
+        matches!(
+            self.result,
+            MiniMax::Terminated(_, Branch::Equal(_))
+                | MiniMax::Open(_, Branch::Equal(_))
+                | MiniMax::DeadEnd
+        )
+    }
+
+    /// Whether the result is also correct for any search with a depth of at least `self.depth`,
+    /// which is the case if every considered path reached the end of the game.
+    fn is_final(&self) -> bool {
+        is_final(&self.result)
+    }
+
+    fn matches(
+        &self,
+        key: u64,
+        depth: u32,
+        alpha: Option<T::Fitness>,
+        beta: Option<T::Fitness>,
+    ) -> bool {
+        self.key == key
+            && (self.depth == depth
+                && (self.is_exact() || (self.alpha == alpha && self.beta == beta))
+                || self.depth < depth && self.is_final())
+    }
+}
+
+/// A fixed size cache of `Ctxt::minimax` results, see `Bot::with_transposition_table`.
+///
+/// A result is reused for the same state and depth if it either is exact or was computed using the same bounds.
+/// Final results are also reused for deeper searches. Once two results are stored at the same position,
+/// the older one is replaced, unless it is a final result of the same state.
+pub(super) struct TranspositionTable<T: Game> {
+    entries: Vec<Option<Entry<T>>>,
+}
+
+impl<T: Game> TranspositionTable<T> {
+    pub(super) fn new(size: usize) -> Self {
+        TranspositionTable {
+            entries: (0..size.max(1)).map(|_| None).collect(),
+        }
+    }
+
+    /// Creates a new empty table with the same size as `self`.
+    #[cfg(feature = "rayon")]
+    pub(super) fn empty_like(&self) -> Self {
+        Self::new(self.entries.len())
+    }
+
+    pub(super) fn clear(&mut self) {
+        for entry in self.entries.iter_mut() {
+            *entry = None;
+        }
+    }
+
+    /// As results at depth `0` are never stored, final results
+    /// use the position of depth `0`, allowing them to be found from any depth.
+    fn index(&self, key: u64, depth: u32) -> usize {
+        let hash = key ^ u64::from(depth).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        (hash % self.entries.len() as u64) as usize
+    }
+
+    /// Returns the result stored for the given arguments.
+    pub(super) fn get(
+        &self,
+        key: u64,
+        depth: u32,
+        alpha: Option<T::Fitness>,
+        beta: Option<T::Fitness>,
+    ) -> Option<&MiniMax<T>> {
+        [self.index(key, depth), self.index(key, 0)]
+            .iter()
+            .filter_map(|&idx| self.entries[idx].as_ref())
+            .find(|entry| entry.matches(key, depth, alpha, beta))
+            .map(|entry| &entry.result)
+    }
+
+    /// Returns the first action of a result stored for the given state, even if it can not be reused
+    /// for the given depth, preferring results of the same depth over the ones of the previous depth.
+    ///
+    /// As this action was the best one or caused a cutoff, it is analysed first, see `Ctxt::minimax`.
+    pub(super) fn best_action(&self, key: u64, depth: u32) -> Option<&T::Action> {
+        [
+            self.index(key, depth),
+            self.index(key, depth.saturating_sub(1)),
+            self.index(key, 0),
+        ]
+        .iter()
+        .filter_map(|&idx| self.entries[idx].as_ref())
+        .filter(|entry| entry.key == key)
+        .find_map(|entry| match entry.result {
+            MiniMax::Terminated(ref path, _) | MiniMax::Open(ref path, _) => path.last(),
+            MiniMax::DeadEnd => None,
+        })
+    }
+
+    /// Returns whether a result is stored for the given state at any depth.
+    pub(super) fn contains(&self, key: u64) -> bool {
+        self.entries.iter().flatten().any(|entry| entry.key == key)
+    }
+
+    /// Returns the first action of the deepest result stored for the given state.
+    pub(super) fn deepest_action(&self, key: u64) -> Option<&T::Action> {
+        self.entries
+            .iter()
+            .flatten()
+            .filter(|entry| entry.key == key)
+            .filter_map(|entry| match entry.result {
+                MiniMax::Terminated(ref path, _) | MiniMax::Open(ref path, _) => {
+                    Some((entry.depth, path.last()?))
+                }
+                MiniMax::DeadEnd => None,
+            })
+            .max_by_key(|&(depth, _)| depth)
+            .map(|(_, action)| action)
+    }
+
+    /// Stores a copy of `result` for the given arguments.
+    pub(super) fn insert(
+        &mut self,
+        key: u64,
+        depth: u32,
+        alpha: Option<T::Fitness>,
+        beta: Option<T::Fitness>,
+        result: &MiniMax<T>,
+    ) {
+        let idx = if is_final(result) {
+            self.index(key, 0)
+        } else {
+            self.index(key, depth)
+        };
+        if let Some(ref entry) = self.entries[idx] {
+            if entry.key == key && entry.is_final() && entry.depth <= depth {
+                return;
+            }
+        }
+
+        // Reuse the path of the replaced entry to avoid allocations.
+        let path = match self.entries[idx].take() {
+            Some(Entry {
+                result: MiniMax::Terminated(path, _),
+                ..
+            })
+            | Some(Entry {
+                result: MiniMax::Open(path, _),
+                ..
+            }) => path,
+            _ => Vec::new(),
+        };
+
+        self.entries[idx] = Some(Entry {
+            key,
+            depth,
+            alpha,
+            beta,
+            result: copy(result, path),
+        });
+    }
+}
+
+fn is_final<T: Game>(result: &MiniMax<T>) -> bool {
+    matches!(
+        *result,
+        MiniMax::Terminated(_, Branch::Equal(_)) | MiniMax::DeadEnd
+    )
+}
+
+/// Copies `result`, storing its path in `path`.
+pub(super) fn copy<T: Game>(result: &MiniMax<T>, mut path: Vec<T::Action>) -> MiniMax<T> {
+    path.clear();
+    match *result {
+        MiniMax::Terminated(ref actions, branch) => {
+            path.extend(actions.iter().cloned());
+            MiniMax::Terminated(path, branch)
+        }
+        MiniMax::Open(ref actions, branch) => {
+            path.extend(actions.iter().cloned());
+            MiniMax::Open(path, branch)
+        }
+        MiniMax::DeadEnd => MiniMax::DeadEnd,
+    }
+}
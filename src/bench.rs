@@ -0,0 +1,156 @@
+//! Hardware independent benchmarks of [`alpha_beta::Bot`][bot].
+//!
+//! Instead of measuring the time needed by a search, [`measure`][measure] counts the calls to
+//! [`RunCondition::step`][step], which only depends on the analysed states. This makes it possible
+//! to notice changes in the pruning quality of the bot regardless of the used machine.
+//! The condition given to `measure` should therefore also not depend on the time,
+//! for example [`Depth`][depth], [`NodeCount`][nc] or [`ToCompletion`][complete].
+//!
+//! This module requires the `bench` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rubot::{bench, Bot, Depth, tree::Node};
+//!
+//! # #[rustfmt::skip]
+//! let tree = Node::root().with_children(&[
+//!     Node::new(false, 7).with_children(&[
+//!         Node::new(true, 4).with_children(&[Node::new(false, 4)]),
+//!         Node::new(true, 2).with_children(&[Node::new(false, 2)]),
+//!     ]),
+//!     Node::new(false, 5).with_children(&[
+//!         Node::new(true, 8).with_children(&[Node::new(false, 8)]),
+//!         Node::new(true, 9).with_children(&[Node::new(false, 9)]),
+//!     ]),
+//! ]);
+//!
+//! let measurement = bench::measure(&mut Bot::new(true), &tree, Depth(2));
+//! assert_eq!(measurement.action, Some(1));
+//! assert_eq!(measurement.depth_steps.len(), 2);
+//! assert_eq!(measurement.steps, measurement.depth_steps.iter().sum());
+//! assert!(!measurement.completed);
+//! assert!(measurement.branching_factor().unwrap() > 1.0);
+//! ```
+//!
+//! [bot]: ../alpha_beta/struct.Bot.html
+//! [measure]: fn.measure.html
+//! [step]: ../trait.RunCondition.html#tymethod.step
+//! [depth]: ../struct.Depth.html
+//! [nc]: ../struct.NodeCount.html
+//! [complete]: ../struct.ToCompletion.html
+use crate::{alpha_beta::Bot, Game, IntoRunCondition, RunCondition};
+
+use alloc::vec::Vec;
+
+/// The result of [`measure`][measure].
+///
+/// [measure]: fn.measure.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Measurement<A> {
+    /// The action selected by the bot.
+    pub action: Option<A>,
+    /// The total amount of steps used by the search.
+    pub steps: u64,
+    /// The amount of steps used by each completed depth, starting with depth `0`.
+    ///
+    /// Once the best action is found, the search may stop before analysing the whole last depth.
+    pub depth_steps: Vec<u64>,
+    /// Whether the search stopped on its own instead of being stopped by the run condition.
+    pub completed: bool,
+}
+
+impl<A> Measurement<A> {
+    /// Returns the effective branching factor, which is the amount of steps used by the last completed depth
+    /// divided by the amount of steps used by the depth before it.
+    ///
+    /// A smaller branching factor means that more states were pruned.
+    /// Returns `None` if less than two depths were completed.
+    pub fn branching_factor(&self) -> Option<f64> {
+        match *self.depth_steps.as_slice() {
+            [.., previous, last] if previous > 0 => Some(last as f64 / previous as f64),
+            _ => None,
+        }
+    }
+}
+
+/// Selects an action for `state` using `bot`, counting the steps used by each depth.
+pub fn measure<T: Game, U: IntoRunCondition<T::Fitness>>(
+    bot: &mut Bot<T>,
+    state: &T,
+    condition: U,
+) -> Measurement<T::Action> {
+    let mut counter = Counter {
+        condition: condition.into_run_condition(),
+        steps: 0,
+        depth_start: None,
+        depth_steps: Vec::new(),
+        completed: true,
+    };
+    let action = bot.select(state, &mut counter);
+    if let Some(start) = counter.depth_start.filter(|_| counter.completed) {
+        // The search stopped during the last depth as it was not needed anymore.
+        if counter.steps > start {
+            counter.depth_steps.push(counter.steps - start);
+        }
+    }
+
+    Measurement {
+        action,
+        steps: counter.steps,
+        depth_steps: counter.depth_steps,
+        completed: counter.completed,
+    }
+}
+
+/// Counts the steps of each depth, see `measure`.
+struct Counter<U> {
+    condition: U,
+    steps: u64,
+    /// The amount of steps before the current depth started.
+    depth_start: Option<u64>,
+    depth_steps: Vec<u64>,
+    completed: bool,
+}
+
+impl<U: RunCondition> RunCondition for &mut Counter<U> {
+    type Fitness = U::Fitness;
+
+    fn step(&mut self) -> bool {
+        self.steps += 1;
+        self.completed &= self.condition.step();
+        self.completed
+    }
+
+    fn depth(&mut self, depth: u32) -> bool {
+        self.completed_depth(depth, None)
+    }
+
+    fn completed_depth(&mut self, depth: u32, best: Option<&Self::Fitness>) -> bool {
+        // This is called before analysing `depth`, so `depth - 1` was just completed.
+        if let Some(start) = self.depth_start.replace(self.steps) {
+            self.depth_steps.push(self.steps - start);
+        }
+        if !self.condition.completed_depth(depth, best) {
+            self.depth_start = None;
+            self.completed = false;
+        }
+        self.completed
+    }
+
+    fn search_finished(&mut self, fitness: &Self::Fitness, pv_len: usize) {
+        self.condition.search_finished(fitness, pv_len);
+    }
+
+    fn stable_depths(&mut self, depths: u32) {
+        self.condition.stable_depths(depths);
+    }
+
+    fn cutoff(&mut self) {
+        self.condition.cutoff();
+    }
+
+    fn leaf(&mut self) {
+        self.condition.leaf();
+    }
+}
@@ -1,8 +1,9 @@
 //! This module contains a bot which simply brute forces every possible action, this bot should only be used for testing.
 use crate::Game;
 
-use std::cmp::{self, Ordering};
-use std::fmt;
+use alloc::{vec, vec::Vec};
+use core::cmp::{self, Ordering};
+use core::fmt;
 
 /// A bot which uses brute force to calculate the optimal move
 pub struct Brute<T: Game> {
@@ -20,22 +21,93 @@ impl<T: Game> Brute<T> {
             return None;
         }
 
-        let mut actions = actions.into_iter();
+        self.best(state, true, actions, depth)
+            .map(|(action, _)| action)
+    }
 
-        let mut best = {
-            let action = actions.next()?;
-            let value = self.minimax(state, &action, depth);
-            (action, value)
-        };
+    /// The fitness of the best action at `depth`, which is the fitness `select` would
+    /// report for the action it returns.
+    pub fn best_fitness(&mut self, state: &T, depth: u32) -> Option<T::Fitness> {
+        let (active, actions) = state.actions(self.player);
+        if !active {
+            return None;
+        }
+
+        self.best(state, true, actions, depth)
+            .map(|(_, fitness)| fitness)
+    }
+
+    /// The actions taken during optimal play of both sides when looking `depth` actions ahead,
+    /// starting with the result of `select`.
+    ///
+    /// In case multiple actions are equally good, the first one is chosen,
+    /// so this is only one of possibly many principal variations.
+    pub fn principal_variation(&mut self, state: &T, depth: u32) -> Vec<T::Action> {
+        let mut path = Vec::new();
+        let (active, actions) = state.actions(self.player);
+        if !active {
+            return path;
+        }
+
+        let mut state = state.clone();
+        let mut actions: Vec<_> = actions.into_iter().collect();
+        let mut active = active;
+        let mut depth = Some(depth);
+        while let Some(remaining) = depth {
+            let action = match self.best(&state, active, actions, remaining) {
+                Some((action, _)) => action,
+                None => break,
+            };
+            state.execute(&action, self.player);
+            path.push(action);
+            if state.status(self.player).is_over() {
+                break;
+            }
+
+            let (next_active, next_actions) = self.actions_or_pass(&mut state);
+            active = next_active;
+            actions = next_actions;
+            depth = remaining.checked_sub(1);
+        }
+
+        path
+    }
 
+    /// The first best action of `actions` together with its fitness at `depth`, preferring the
+    /// action with the highest fitness if the bot is `active` and the lowest one otherwise.
+    fn best<I: IntoIterator<Item = T::Action>>(
+        &mut self,
+        state: &T,
+        active: bool,
+        actions: I,
+        depth: u32,
+    ) -> Option<(T::Action, T::Fitness)> {
+        let mut best: Option<(T::Action, T::Fitness)> = None;
         for action in actions {
             let new = self.minimax(state, &action, depth);
-            if new > best.1 {
-                best = (action, new);
+            let is_better = match best {
+                None => true,
+                Some((_, fitness)) if active => new > fitness,
+                Some((_, fitness)) => new < fitness,
+            };
+            if is_better {
+                best = Some((action, new));
             }
         }
 
-        Some(best.0)
+        best
+    }
+
+    /// The actions of `state`, passing if there are none and passing is allowed.
+    fn actions_or_pass(&self, state: &mut T) -> (bool, Vec<T::Action>) {
+        let (active, actions) = state.actions(self.player);
+        let actions: Vec<_> = actions.into_iter().collect();
+        if actions.is_empty() && state.pass(self.player) {
+            let (active, actions) = state.actions(self.player);
+            (active, actions.into_iter().collect())
+        } else {
+            (active, actions)
+        }
     }
 
     pub fn check_if_best(&mut self, state: &T, best: Option<&T::Action>, depth: u32) -> bool {
@@ -69,63 +141,57 @@ impl<T: Game> Brute<T> {
             return vec![None];
         }
 
-        let mut actions = actions.into_iter();
+        // The fitness of each action at `completed_depth` and `completed_depth + 1`.
+        let fitnesses: Vec<_> = actions
+            .into_iter()
+            .map(|action| {
+                let completed = self.minimax(state, &action, completed_depth);
+                let next = self.minimax(state, &action, completed_depth + 1);
+                (action, completed, next)
+            })
+            .collect();
 
-        let mut best_actions = Vec::new();
-        let mut best = if let Some(action) = actions.next() {
-            let value = self.minimax(state, &action, completed_depth);
-            best_actions.push(action);
-            value
-        } else {
-            return vec![None];
+        let best = match fitnesses.iter().map(|&(_, completed, _)| completed).max() {
+            Some(best) => best,
+            None => return vec![None],
         };
 
-        for action in actions {
-            let new = self.minimax(state, &action, completed_depth);
-            match new.cmp(&best) {
-                Ordering::Equal => best_actions.push(action),
-                Ordering::Greater => {
-                    best_actions.clear();
-                    best_actions.push(action);
-                    best = new;
-                }
-                Ordering::Less => (),
-            }
-        }
-
-        let worst_allowed = best_actions
-            .into_iter()
-            .map(|action| self.minimax(state, &action, completed_depth + 1))
+        let worst_allowed = fitnesses
+            .iter()
+            .filter(|&&(_, completed, _)| completed == best)
+            .map(|&(_, _, next)| next)
             .min()
             .unwrap();
 
-        let mut actions: Vec<_> = state
-            .actions(self.player)
-            .1
+        fitnesses
             .into_iter()
-            .filter(|action| self.minimax(state, action, completed_depth + 1) >= worst_allowed)
-            .map(Some)
-            .collect();
-        actions
+            .filter(|&(_, _, next)| next >= worst_allowed)
+            .map(|(action, _, _)| Some(action))
+            .collect()
     }
 
     fn minimax(&mut self, state: &T, action: &T::Action, depth: u32) -> T::Fitness {
         if depth == 0 {
-            state.look_ahead(&action, self.player)
+            state.look_ahead(action, self.player)
         } else {
             let mut state = state.clone();
-            let fitness = state.execute(&action, self.player);
-            let (active, actions) = state.actions(self.player);
+            let fitness = state.execute(action, self.player);
+            if state.status(self.player).is_over() {
+                return fitness;
+            }
+
+            let (active, actions) = self.actions_or_pass(&mut state);
 
             let iter = actions
-                .into_iter()
-                .map(|action| self.minimax(&state, &action, depth - 1));
+                .iter()
+                .map(|action| self.minimax(&state, action, depth - 1));
 
             if active { iter.max() } else { iter.min() }.unwrap_or(fitness)
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl<T: Game> Brute<T>
 where
     T::Fitness: fmt::Debug,
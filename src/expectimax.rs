@@ -0,0 +1,270 @@
+//! A game bot for games with chance nodes, for example dice rolls, using [expectimax][em].
+//!
+//! Random events are modeled using [`Game::chance_outcomes`][co]. Instead of selecting the best action
+//! at these states, the bot uses the average fitness of all outcomes, weighted by their probability.
+//! As this requires adding fitness values, it is converted to `f64` using `Into`.
+//!
+//! [em]: https://en.wikipedia.org/wiki/Expectiminimax
+//! [co]: ../trait.Game.html#method.chance_outcomes
+use crate::{Game, IntoRunCondition, RunCondition};
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// A game bot using expectimax with iterative deepening, see the [module level documentation][mod].
+///
+/// Chance nodes do not count towards the analysed depth.
+///
+/// # Examples
+///
+/// ```rust
+/// use rubot::{expectimax::Bot, Game, ToCompletion};
+///
+/// /// Either take `5` points or roll a die, getting as many points as rolled.
+/// #[derive(Clone)]
+/// struct Gamble {
+///     points: u8,
+///     rolling: bool,
+///     done: bool,
+/// }
+///
+/// impl Game for Gamble {
+///     type Player = ();
+///     type Action = u8;
+///     type Fitness = u8;
+///     type Actions = Vec<u8>;
+///
+///     fn actions(&self, _: ()) -> (bool, Vec<u8>) {
+///         if self.done {
+///             (true, vec![])
+///         } else {
+///             // `0` is rolling the die, `5` takes the points.
+///             (true, vec![0, 5])
+///         }
+///     }
+///
+///     fn execute(&mut self, &action: &u8, _: ()) -> u8 {
+///         if self.rolling {
+///             self.rolling = false;
+///             self.done = true;
+///         } else if action == 0 {
+///             self.rolling = true;
+///         } else {
+///             self.done = true;
+///         }
+///         self.points += action;
+///         self.points
+///     }
+///
+///     fn chance_outcomes(&self, _: ()) -> Option<Vec<(f64, u8)>> {
+///         if self.rolling {
+///             Some((1..=6).map(|roll| (1.0 / 6.0, roll)).collect())
+///         } else {
+///             None
+///         }
+///     }
+/// }
+///
+/// let state = Gamble { points: 0, rolling: false, done: false };
+/// // The expected roll is `3.5`, so taking the points is better.
+/// assert_eq!(Bot::new(()).select(&state, ToCompletion), Some(5));
+/// ```
+///
+/// [mod]: index.html
+pub struct Bot<T: Game> {
+    player: T::Player,
+}
+
+/// The search was stopped by the `RunCondition`.
+struct CancelledError;
+
+/// The expected fitness of a state and whether every analysed line reached the end of the game.
+#[derive(Clone, Copy)]
+struct Value {
+    fitness: f64,
+    terminated: bool,
+}
+
+impl<T: Game> Bot<T>
+where
+    T::Fitness: Into<f64>,
+{
+    /// Creates a new `Bot` for the given `player`.
+    pub fn new(player: T::Player) -> Self {
+        Self { player }
+    }
+
+    /// Returns a chosen action based on the given game state.
+    ///
+    /// Returns `None` if no action is possible, the bot is currently not the active player,
+    /// or the next action is decided by chance.
+    ///
+    /// This method runs until either the whole game was analysed
+    /// or one of `RunCondition::depth` and `RunCondition::step` returned `false`.
+    /// In the latter case, the best action of the last completed depth is returned.
+    pub fn select<U: IntoRunCondition<f64>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Option<T::Action> {
+        let mut condition = condition.into_run_condition();
+
+        if state.chance_outcomes(self.player).is_some() {
+            return None;
+        }
+        let (active, actions) = state.actions(self.player);
+        if !active {
+            return None;
+        }
+
+        let children: Vec<_> = actions
+            .into_iter()
+            .map(|action| {
+                let mut state = state.clone();
+                let fitness = state.execute(&action, self.player).into();
+                (state, action, fitness)
+            })
+            .collect();
+
+        // Before completing any depth, we only know the immediate fitness of each action.
+        let (mut best, mut value) = select_best(
+            true,
+            children.iter().map(|&(_, ref action, fitness)| {
+                let value = Value {
+                    fitness,
+                    terminated: false,
+                };
+                (action, value)
+            }),
+        )?;
+
+        for depth in 0.. {
+            let fitness = if depth > 0 {
+                Some(&value.fitness)
+            } else {
+                None
+            };
+            if value.terminated || !condition.completed_depth(depth, fitness) {
+                break;
+            }
+
+            let mut values = Vec::with_capacity(children.len());
+            for (state, action, fitness) in children.iter() {
+                match self.expectimax(state, depth, &mut condition) {
+                    Ok(value) => values.push((
+                        action,
+                        value.unwrap_or(Value {
+                            fitness: *fitness,
+                            terminated: true,
+                        }),
+                    )),
+                    Err(CancelledError) => break,
+                }
+            }
+
+            if values.len() == children.len() {
+                let (action, new) = select_best(true, values.into_iter()).unwrap();
+                best = action;
+                value = new;
+            } else {
+                break;
+            }
+        }
+
+        Some(best.clone())
+    }
+
+    /// Returns the expected value of `state`, or `None` if there are no possible actions.
+    ///
+    /// At depth `0`, the active player selects the action with the best immediate fitness.
+    fn expectimax<U: RunCondition<Fitness = f64>>(
+        &self,
+        state: &T,
+        depth: u32,
+        condition: &mut U,
+    ) -> Result<Option<Value>, CancelledError> {
+        if !condition.step() {
+            return Err(CancelledError);
+        }
+
+        if let Some(outcomes) = state.chance_outcomes(self.player) {
+            let mut expected = Value {
+                fitness: 0.0,
+                terminated: true,
+            };
+            for (probability, action) in outcomes {
+                let value = self.child(state, &action, depth, condition)?;
+                expected.fitness += probability * value.fitness;
+                expected.terminated &= value.terminated;
+            }
+            return Ok(Some(expected));
+        }
+
+        let (active, actions) = state.actions(self.player);
+        let mut values = Vec::new();
+        for action in actions {
+            let value = if depth == 0 {
+                Value {
+                    fitness: state.look_ahead(&action, self.player).into(),
+                    terminated: false,
+                }
+            } else {
+                self.child(state, &action, depth - 1, condition)?
+            };
+            values.push((action, value));
+        }
+
+        Ok(select_best(active, values.into_iter()).map(|(_, value)| value))
+    }
+
+    /// Returns the expected value of the state reached by executing `action`.
+    fn child<U: RunCondition<Fitness = f64>>(
+        &self,
+        state: &T,
+        action: &T::Action,
+        depth: u32,
+        condition: &mut U,
+    ) -> Result<Value, CancelledError> {
+        let mut state = state.clone();
+        let fitness = state.execute(action, self.player).into();
+        Ok(self.expectimax(&state, depth, condition)?.unwrap_or(Value {
+            fitness,
+            terminated: true,
+        }))
+    }
+}
+
+/// Returns the action with the highest fitness if `active` and the one with the lowest fitness otherwise.
+///
+/// The returned value is only terminated if all `values` are terminated.
+fn select_best<A>(active: bool, values: impl Iterator<Item = (A, Value)>) -> Option<(A, Value)> {
+    let mut terminated = true;
+    let mut best: Option<(A, Value)> = None;
+    for (action, value) in values {
+        terminated &= value.terminated;
+        let better = match best {
+            None => true,
+            Some((_, ref best)) => {
+                let ordering = value.fitness.total_cmp(&best.fitness);
+                if active {
+                    ordering == Ordering::Greater
+                } else {
+                    ordering == Ordering::Less
+                }
+            }
+        };
+        if better {
+            best = Some((action, value));
+        }
+    }
+
+    best.map(|(action, value)| {
+        (
+            action,
+            Value {
+                terminated,
+                ..value
+            },
+        )
+    })
+}
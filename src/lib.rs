@@ -1,4 +1,5 @@
 #![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "std"), no_std)]
 //! An easily reusable game bot for deterministic games.
 //!
 //! It is required to implement the trait [`Game`][game] for your game to use this crate.
@@ -43,27 +44,84 @@
 //! ```
 //!
 //! Please visit the [examples folder][ex] or the [`trait Game`][game] documentation
-//! for more realistic examples.
+//! for more realistic examples. For single player puzzles which require a whole sequence
+//! of actions, like mazes, [`solver`][solver] is usually a better fit.
+//!
+//! # Features
+//!
+//! - `std`, enabled by default: adds the time based [`RunCondition`s][rc], [`Logger`][logger],
+//!   [`mcts`][mcts] and [`test_util`][tu]. Without this feature, this crate only depends on `alloc`,
+//!   so it can be used in `no_std` environments. The run conditions [`Steps`][steps], [`NodeCount`][nc],
+//!   [`Depth`][depth], [`Moves`][moves], [`StableFor`][stable], [`ToCompletion`][complete], [`Cancel`][cancel],
+//!   [`Or`][or], [`And`][and] and [`Progress`][progress] are always available.
+//! - `rayon`: adds methods which analyse actions in parallel, like [`Bot::select_parallel`][sp].
+//!   This feature requires `std`.
+//! - `serde`: implements `Serialize` and `Deserialize` for [`alpha_beta::Action`][action]
+//!   and the output of [`Bot::analyze`][analyze].
+//! - `bench`: adds [`bench`][bench], which measures the amount of analysed states instead of the time
+//!   needed by a search. This feature requires `std`.
 //!
 //! [ab]:alpha_beta/struct.Bot.html
+//! [action]:alpha_beta/struct.Action.html
+//! [analyze]:alpha_beta/struct.Bot.html#method.analyze
+//! [bench]:bench/index.html
 //! [ex]:https://github.com/lcnr/rubot/tree/master/examples
 //! [game]:trait.Game.html
+//! [rc]:trait.RunCondition.html
+//! [logger]:struct.Logger.html
+//! [mcts]:mcts/index.html
+//! [tu]:test_util/index.html
+//! [steps]:struct.Steps.html
+//! [nc]:struct.NodeCount.html
+//! [depth]:struct.Depth.html
+//! [moves]:struct.Moves.html
+//! [stable]:struct.StableFor.html
+//! [complete]:struct.ToCompletion.html
+//! [cancel]:struct.Cancel.html
+//! [or]:struct.Or.html
+//! [and]:struct.And.html
+//! [progress]:struct.Progress.html
+//! [solver]:solver/index.html
+//! [sp]:alpha_beta/struct.Bot.html#method.select_parallel
+extern crate alloc;
+
 pub mod alpha_beta;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod expectimax;
+pub mod max_n;
+#[cfg(feature = "std")]
+pub mod mcts;
+pub mod negamax;
+pub mod solver;
+#[cfg(feature = "std")]
+pub mod test_util;
 pub mod tree;
 
 #[allow(unused)]
 #[doc(hidden)]
 pub mod brute;
-#[cfg(test)]
+mod rng;
+#[cfg(all(test, feature = "std"))]
 mod tests;
 
-use std::cmp::PartialEq;
-use std::fmt::{self, Debug};
-use std::ops::Drop;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cmp::{Ordering, PartialEq};
+use core::fmt::{self, Debug};
+use core::marker::PhantomData;
+use core::ops::{Add, Sub};
+use core::sync::atomic::{self, AtomicBool};
+#[cfg(feature = "std")]
 use std::time::{Duration, Instant};
 
 /// An interface required to interact with [`GameBot`s][bot].
 ///
+/// The bot only ever creates new game states by cloning existing ones and modifying the clone using
+/// [`execute`][exe], [`pass`][pass] or, when using [`Bot::with_undo`][wu], [`Undo::undo`][undo].
+/// Any history stored in the game state, for example to detect repetitions inside of `execute`,
+/// is therefore available during the search as long as `Clone` copies it and `undo` restores it.
+///
 /// # Examples
 ///
 /// Implementing this trait for `21 flags`. The game has the following rules:
@@ -186,6 +244,10 @@ use std::time::{Duration, Instant};
 /// }
 /// ```
 /// [bot]: trait.GameBot.html
+/// [exe]: trait.Game.html#tymethod.execute
+/// [pass]: trait.Game.html#method.pass
+/// [wu]: alpha_beta/struct.Bot.html#method.with_undo
+/// [undo]: trait.Undo.html#tymethod.undo
 /// [act]: trait.Game.html#associatedtype.Action
 /// [player]: trait.Game.html#associatedtype.player
 /// [examples]: https://github.com/lcnr/rubot/tree/master/examples
@@ -214,11 +276,112 @@ pub trait Game: Clone {
     /// [actions]: trait.Game.html#tymethod.actions
     fn execute(&mut self, action: &Self::Action, player: Self::Player) -> Self::Fitness;
 
+    /// Returns `false` if `action` can not be executed by `player` in the current state.
+    ///
+    /// In debug builds, [`alpha_beta::Bot`][ab] checks this before each call to [`execute`][exe]
+    /// and panics if the action is invalid. This catches bugs where [`actions`][act] or [`undo`][undo]
+    /// are implemented incorrectly before they cause confusing errors inside of `execute`.
+    /// Release builds never call this method.
+    ///
+    /// The default implementation always returns `true`.
+    ///
+    /// ```rust
+    /// # use rubot::Game;
+    /// #[derive(Clone)]
+    /// struct Countdown(u8);
+    ///
+    /// impl Game for Countdown {
+    ///     type Player = ();
+    ///     type Action = u8;
+    ///     type Fitness = bool;
+    ///     type Actions = std::ops::RangeInclusive<u8>;
+    ///
+    ///     fn actions(&self, _: ()) -> (bool, Self::Actions) {
+    ///         (true, 1..=self.0.min(3))
+    ///     }
+    ///
+    ///     fn execute(&mut self, action: &u8, _: ()) -> bool {
+    ///         self.0 -= action;
+    ///         self.0 == 0
+    ///     }
+    ///
+    ///     fn validate_action(&self, &action: &u8, _: ()) -> bool {
+    ///         (1..=3).contains(&action) && action <= self.0
+    ///     }
+    /// }
+    ///
+    /// assert!(!Countdown(2).validate_action(&3, ()));
+    /// ```
+    ///
+    /// [ab]: alpha_beta/struct.Bot.html
+    /// [exe]: trait.Game.html#tymethod.execute
+    /// [act]: trait.Game.html#tymethod.actions
+    /// [undo]: trait.Undo.html#tymethod.undo
+    #[inline]
+    fn validate_action(&self, action: &Self::Action, player: Self::Player) -> bool {
+        let _ = (action, player);
+        true
+    }
+
+    /// Returns the actions considered when analysing this state during the search, which must be a subset of
+    /// [`actions`][act] containing at least one action if there are any possible actions.
+    ///
+    /// This is used for all states except the current state given to the bot, so all possible actions
+    /// are still considered by [`Bot::select`][sel]. This can be used to only consider plausible actions
+    /// in games with a lot of possible actions.
+    ///
+    /// Ignoring some actions turns the search into a heuristic one: the bot may miss the best action, and
+    /// results claimed to be optimal, for example by [`Bot::select_checked`][chk], are only optimal
+    /// if the opponent also only uses these actions.
+    ///
+    /// The default implementation returns all possible actions.
+    ///
+    /// [act]: trait.Game.html#tymethod.actions
+    /// [sel]: alpha_beta/struct.Bot.html#method.select
+    /// [chk]: alpha_beta/struct.Bot.html#method.select_checked
+    #[inline]
+    fn heuristic_actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+        self.actions(player)
+    }
+
+    /// Returns an upper limit on the amount of [`actions`][act] of any state of this game,
+    /// or `None` if there is no such limit.
+    ///
+    /// This is used to allocate the buffers storing the actions of each state with enough capacity, which
+    /// avoids reallocations if the size hint of [`Actions`][acts] is inaccurate. A smaller limit is allowed,
+    /// but causes reallocations, while a larger limit wastes memory.
+    ///
+    /// The default implementation returns `None`.
+    ///
+    /// [act]: trait.Game.html#tymethod.actions
+    /// [acts]: trait.Game.html#associatedtype.Actions
+    #[inline]
+    fn max_actions(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns the smallest possible difference between two different fitness values,
+    /// or `None` if there is no such step.
+    ///
+    /// For integer fitness values this is `1`. This allows the search to use windows which only contain
+    /// a single fitness value, see [`Bot::with_fitness_step`][wfs].
+    ///
+    /// The default implementation returns `None`.
+    ///
+    /// [wfs]: alpha_beta/struct.Bot.html#method.with_fitness_step
+    #[inline]
+    fn fitness_step(&self) -> Option<Self::Fitness> {
+        None
+    }
+
     /// Returns the fitness after `action` is executed.
     /// The returned fitness is always from the perspective of `player`,
     /// even if the `player` is not active.
     ///
     /// This function should always return the same [`Fitness`][fit] as calling [`execute`][exe].
+    /// The default implementation calls `execute` on a clone of `self`, so it sees the same history
+    /// as `execute` itself, which is required when overriding this method.
+    /// In debug builds, [`alpha_beta::Bot`][ab] panics if this is not the case.
     ///
     /// ```rust
     /// # use rubot::Game;
@@ -249,11 +412,101 @@ pub trait Game: Clone {
     /// ```
     /// [fit]: trait.Game.html#associatedtype.Fitness
     /// [exe]: trait.Game.html#tymethod.execute
+    /// [ab]: alpha_beta/struct.Bot.html
     #[inline]
     fn look_ahead(&self, action: &Self::Action, player: Self::Player) -> Self::Fitness {
         self.clone().execute(action, player)
     }
 
+    /// Returns the fitness after each of the given `actions` is executed, in the order of `actions`.
+    ///
+    /// Every returned fitness must be equal to the result of [`look_ahead`][la] for the same action.
+    /// The default implementation simply calls `look_ahead` for each action, which clones the state every time
+    /// unless `look_ahead` is overridden. Games can override this method to evaluate many actions more cheaply,
+    /// for example by reusing a single scratch state.
+    ///
+    /// ```rust
+    /// use rubot::{Game, tree::Node};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 7),
+    ///     Node::new(false, 5),
+    ///     Node::new(false, -2),
+    /// ]);
+    ///
+    /// assert_eq!(tree.look_ahead_many(&[2, 0], true), vec![-2, 7]);
+    /// ```
+    ///
+    /// [la]: trait.Game.html#method.look_ahead
+    fn look_ahead_many(
+        &self,
+        actions: &[Self::Action],
+        player: Self::Player,
+    ) -> Vec<Self::Fitness> {
+        actions
+            .iter()
+            .map(|action| self.look_ahead(action, player))
+            .collect()
+    }
+
+    /// Returns an estimate of the fitness after `action` is executed, used to decide which actions are analysed first
+    /// when using [`Bot::with_order_key`][wok].
+    ///
+    /// Unlike [`look_ahead`][la], this does not have to be accurate, for example chess engines
+    /// usually analyse captures of valuable pieces first. As this is called for every possible action,
+    /// it should be a lot cheaper than cloning the state and executing `action`.
+    ///
+    /// The default implementation calls [`look_ahead`][la].
+    ///
+    /// [wok]: alpha_beta/struct.Bot.html#method.with_order_key
+    /// [la]: trait.Game.html#method.look_ahead
+    #[inline]
+    fn order_key(&self, action: &Self::Action, player: Self::Player) -> Self::Fitness {
+        self.look_ahead(action, player)
+    }
+
+    /// Returns a key identifying `action`, or `None` if this is not supported. This is used by
+    /// [`Bot::with_history_heuristic`][whh] to remember which actions often caused cutoffs.
+    ///
+    /// Actions which are considered to be the same in different states, for example moving a piece
+    /// between the same two squares, should return the same key. Different actions may share a key,
+    /// but this makes the heuristic less effective.
+    ///
+    /// [whh]: alpha_beta/struct.Bot.html#method.with_history_heuristic
+    #[inline]
+    fn hash_action(&self, action: &Self::Action, player: Self::Player) -> Option<u64> {
+        let _ = (action, player);
+        None
+    }
+
+    /// Returns a key identifying the current state, or `None` if this is not supported.
+    ///
+    /// Equal states must return the same key, while different states should return different keys,
+    /// so this is usually some kind of hash of the state. As this is used to detect repetitions,
+    /// the key should not depend on the history of the game.
+    #[inline]
+    fn key(&self, player: Self::Player) -> Option<u64> {
+        let _ = player;
+        None
+    }
+
+    /// Returns a cheap key used to compare `fitness` with other fitness values of the same `Game`.
+    ///
+    /// This is useful if comparing [`Fitness`][fit] is expensive, as the bot then only has to
+    /// compare the actual fitness if both keys are equal. The key must be order preserving,
+    /// meaning that if the key of `a` is less than the key of `b`, `a` must also be less than `b`.
+    /// This is checked using `debug_assert`.
+    ///
+    /// The default implementation always returns `0`, so the fitness itself is always compared.
+    ///
+    /// [fit]: trait.Game.html#associatedtype.Fitness
+    #[inline]
+    fn fitness_key(&self, fitness: Self::Fitness) -> u64 {
+        let _ = fitness;
+        0
+    }
+
     /// Returns `true` if the given `fitness` is one of the best currently possible outcomes for the given `player`.
     ///
     /// A good example is a checkmate in chess, as there does not exist a better game state than having won.
@@ -271,6 +524,203 @@ pub trait Game: Clone {
         let _ = (fitness, player);
         false
     }
+
+    /// Returns `true` if the given `fitness` is good enough for the given `player` that searching
+    /// any deeper is not worth it, for example because the game is practically won.
+    ///
+    /// Once the best action is decisive after a completed depth, [`Bot::select`][sel] returns it
+    /// instead of searching deeper, even if the [`RunCondition`][rc] would allow it.
+    ///
+    /// Unlike [`is_upper_bound`][ub], this does not claim that no better fitness exists.
+    /// A decisive fitness is therefore never used to cut off other actions, it only
+    /// prevents further iterative deepening. As a decisive fitness is not necessarily the value of the game,
+    /// it is ignored by [`Bot::solve`][solve].
+    ///
+    /// [sel]: alpha_beta/struct.Bot.html#method.select
+    /// [rc]: trait.RunCondition.html
+    /// [ub]: trait.Game.html#method.is_upper_bound
+    /// [solve]: alpha_beta/struct.Bot.html#method.solve
+    #[inline]
+    fn is_decisive(&self, fitness: Self::Fitness, player: Self::Player) -> bool {
+        let _ = (fitness, player);
+        false
+    }
+
+    /// Returns how unusual the state reached by `action` is, used as a tie-breaker
+    /// between root actions with an equal fitness. Higher values are preferred.
+    ///
+    /// This can be used to steer the bot towards positions the opponent is less likely to be prepared for,
+    /// without ever choosing a worse action. As an action with a higher rarity has to be analysed again
+    /// if it may be as good as the best action at the root, overriding this method slightly reduces the amount of pruning.
+    ///
+    /// By default all actions are equally rare.
+    #[inline]
+    fn rarity(&self, action: &Self::Action, player: Self::Player) -> i32 {
+        let _ = (action, player);
+        0
+    }
+
+    /// Returns `true` if `action` can never be undone, for example a capture or a pawn move in chess.
+    ///
+    /// This is used by [`Bot::with_irreversible_limit`][irr] to limit the search
+    /// to states at most a given amount of irreversible actions away. By default, no action is irreversible.
+    ///
+    /// [irr]: alpha_beta/struct.Bot.html#method.with_irreversible_limit
+    #[inline]
+    fn is_irreversible(&self, action: &Self::Action) -> bool {
+        let _ = action;
+        false
+    }
+
+    /// Returns the possible outcomes of a random event together with their probability,
+    /// or `None` if the next action is chosen by a player.
+    ///
+    /// This is only used by [`expectimax::Bot`][em], which uses the average fitness of all outcomes,
+    /// weighted by their probability. The probabilities should add up to `1`.
+    /// Other bots ignore random events, so they should only be used for deterministic games.
+    /// By default, there are no random events.
+    ///
+    /// [em]: expectimax/struct.Bot.html
+    #[inline]
+    fn chance_outcomes(&self, player: Self::Player) -> Option<Vec<(f64, Self::Action)>> {
+        let _ = player;
+        None
+    }
+
+    /// Returns `true` if the immediate fitness of the actions of this state is reliable.
+    ///
+    /// At the deepest analysed depth, states are evaluated using the best immediate fitness of their actions.
+    /// This fails if a state is in the middle of an exchange, for example after a capture in chess
+    /// which can still be answered by a recapture. States which are not quiet are instead analysed further
+    /// using their [`noisy_actions`][na], until only quiet states remain.
+    ///
+    /// Games should only return `false` if the next action is likely to change the fitness by a lot.
+    /// To guarantee that the search ends, at most `16` actions are analysed past the lowest depth,
+    /// after which all states are treated as quiet. By default, all states are quiet.
+    ///
+    /// [na]: trait.Game.html#method.noisy_actions
+    #[inline]
+    fn is_quiet(&self, player: Self::Player) -> bool {
+        let _ = player;
+        true
+    }
+
+    /// Returns the actions analysed past the lowest depth if this state is not [quiet][iq],
+    /// for example all captures in chess. This must be a subset of [`heuristic_actions`][ha].
+    ///
+    /// The active player is not forced to use one of these actions, so the usual evaluation of the state,
+    /// the best immediate fitness of all its actions, is also considered, which is known as standing pat.
+    ///
+    /// The default implementation returns [`heuristic_actions`][ha].
+    ///
+    /// [iq]: trait.Game.html#method.is_quiet
+    /// [ha]: trait.Game.html#method.heuristic_actions
+    #[inline]
+    fn noisy_actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+        self.heuristic_actions(player)
+    }
+
+    /// Returns whether the game is over, from the perspective of the given `player`.
+    ///
+    /// A state which is not [`Status::Ongoing`][on] is the end of the game, so its actions are never analysed
+    /// by [`alpha_beta::Bot`][ab]. This allows games where a player may have to skip their turn to always
+    /// return a pass action from [`actions`][act] and to only end the game once both players are unable to move.
+    ///
+    /// The outcome itself is still decided by the fitness returned by [`execute`][exe],
+    /// so the fitness of a won state should be an [upper bound][ub] and the fitness of a lost state
+    /// a [lower bound][lb] to stop searching for better actions.
+    ///
+    /// By default, the game is only over once there are no possible actions.
+    ///
+    /// [on]: enum.Status.html#variant.Ongoing
+    /// [ab]: alpha_beta/struct.Bot.html
+    /// [act]: trait.Game.html#tymethod.actions
+    /// [exe]: trait.Game.html#tymethod.execute
+    /// [ub]: trait.Game.html#method.is_upper_bound
+    /// [lb]: trait.Game.html#method.is_lower_bound
+    #[inline]
+    fn status(&self, player: Self::Player) -> Status {
+        let _ = player;
+        Status::Ongoing
+    }
+
+    /// Returns `true` if this state is a draw or very likely to end in one, for example
+    /// a threefold repetition or insufficient material in chess.
+    ///
+    /// This is only used by [`Bot::set_contempt`][con] to avoid or seek out draws.
+    /// By default, a state is a draw if its [`status`][st] is [`Status::Draw`][draw].
+    ///
+    /// [con]: alpha_beta/struct.Bot.html#method.set_contempt
+    /// [st]: trait.Game.html#method.status
+    /// [draw]: enum.Status.html#variant.Draw
+    #[inline]
+    fn is_draw(&self, player: Self::Player) -> bool {
+        self.status(player) == Status::Draw
+    }
+
+    /// Skips the turn of the active player, who does not have any possible actions, returning `false`
+    /// if this is not supported, in which case `self` must not be modified.
+    ///
+    /// When a state without any actions is not over according to [`status`][st], [`alpha_beta::Bot`][ab]
+    /// tries to pass and continues searching the resulting state, where the other player should be active.
+    /// If there are still no possible actions after passing, the game is over.
+    ///
+    /// Passing does not count towards the search depth and does not show up in the [path][path]
+    /// of an action. If the bot itself has to pass, [`Bot::select`][sel] returns `None`, so the
+    /// caller has to pass instead.
+    ///
+    /// By default, passing is not supported and a state without any actions is the end of the game.
+    ///
+    /// [st]: trait.Game.html#method.status
+    /// [ab]: alpha_beta/struct.Bot.html
+    /// [path]: alpha_beta/struct.Action.html#structfield.path
+    /// [sel]: alpha_beta/struct.Bot.html#method.select
+    #[inline]
+    fn pass(&mut self, player: Self::Player) -> bool {
+        let _ = player;
+        false
+    }
+}
+
+/// A game whose actions can be reverted, required by [`Bot::with_undo`][wu].
+///
+/// [wu]: alpha_beta/struct.Bot.html#method.with_undo
+pub trait Undo: Game {
+    /// Reverts `action`, which was the last action executed using [`execute`][exe],
+    /// so the state is afterwards equal to the state before executing it.
+    ///
+    /// This is used by [`Bot::with_undo`][wu], which executes actions in place and
+    /// undoes them afterwards instead of cloning the state for each action.
+    /// This is a lot faster for games with a large state.
+    ///
+    /// [exe]: trait.Game.html#tymethod.execute
+    /// [wu]: alpha_beta/struct.Bot.html#method.with_undo
+    fn undo(&mut self, action: &Self::Action, player: Self::Player);
+}
+
+/// Whether a game is over, returned by [`Game::status`][st].
+///
+/// [st]: trait.Game.html#method.status
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// The game is not over yet, unless there are no possible actions and
+    /// [passing][pass] is not supported.
+    ///
+    /// [pass]: trait.Game.html#method.pass
+    Ongoing,
+    /// The game is won by the given player.
+    Win,
+    /// The game is lost by the given player.
+    Loss,
+    /// The game ended in a draw.
+    Draw,
+}
+
+impl Status {
+    /// Returns `true` if the game is over.
+    pub fn is_over(self) -> bool {
+        self != Status::Ongoing
+    }
 }
 
 /// Converts a type into a [`RunCondition`][rc] used by [`Bot::select`][sel].
@@ -282,7 +732,7 @@ pub trait Game: Clone {
 /// # struct Game;
 /// # struct Bot;
 /// # impl Bot {
-/// #   fn select<U: rubot::IntoRunCondition>(&mut self, state: &Game, condition: U) -> Option<()> {
+/// #   fn select<U: rubot::IntoRunCondition<i8>>(&mut self, state: &Game, condition: U) -> Option<()> {
 /// #       Some(())
 /// #   }
 /// # }
@@ -303,18 +753,28 @@ pub trait Game: Clone {
 /// - [`ToCompletion`][complete]: `select` runs until it found the perfect action
 /// - [`Depth`][depth]: `select` analyses up the to given depth and returns to best action at that depth
 /// - [`Instant`][instant]: `select` runs until the given `Instant` is in the past
+/// - [`Deadline`][deadline]: like `Instant`, but uses a custom [`Clock`][clock]
 /// - [`Logger`][logger]: takes another run condition and stores information about the last call to `select`
 ///
+/// `Duration`, `Instant`, `Deadline` and `Logger` require the `std` feature, as does [`TimeBudget`][tb].
+///
 /// [rc]: trait.RunCondition.html
 /// [dur]: https://doc.rust-lang.org/std/time/struct.Duration.html
 /// [complete]: struct.ToCompletion.html
 /// [depth]: struct.Depth.html
 /// [instant]: https://doc.rust-lang.org/std/time/struct.Instant.html
+/// [deadline]: struct.Deadline.html
+/// [clock]: trait.Clock.html
 /// [logger]: struct.Logger.html
+/// [tb]: struct.TimeBudget.html
 /// [sel]: alpha_beta/struct.Bot.html#method.select
 ///
-pub trait IntoRunCondition {
-    type RunCondition: RunCondition;
+/// `F` is the fitness used by the bot, which is [`Game::Fitness`][fit] for most bots.
+/// All conditions mentioned above can be used with any fitness.
+///
+/// [fit]: trait.Game.html#associatedtype.Fitness
+pub trait IntoRunCondition<F> {
+    type RunCondition: RunCondition<Fitness = F>;
 
     /// consumes `self` and returns a `RunCondition`.
     ///
@@ -322,7 +782,7 @@ pub trait IntoRunCondition {
     fn into_run_condition(self) -> Self::RunCondition;
 }
 
-impl<T> IntoRunCondition for T
+impl<T> IntoRunCondition<T::Fitness> for T
 where
     T: RunCondition,
 {
@@ -333,112 +793,156 @@ where
     }
 }
 
-/// Can be converted into [`RunCondition`][rc] which returns `true` for the first `self.0` steps.
-/// This should only be used for debugging and testing as unlike `Duration`, `ToCompletion` or `Depth`,
-/// the total amount of steps needed is not directly indicative of search depth and can change between minor versions.
+/// The part of a [`RunCondition`][rc] used by the conditions of this crate,
+/// which do not depend on the fitness and can therefore be used with every bot.
 ///
 /// [rc]: trait.RunCondition.html
-#[derive(Clone, Copy, Debug)]
-pub struct Steps(pub u32);
+#[doc(hidden)]
+pub trait IndependentCondition {
+    fn step(&mut self) -> bool;
 
-/// The [`RunCondition`][rc] created by [`Steps`][steps]`::into_run_condition`
+    fn depth(&mut self, depth: u32) -> bool;
+
+    #[inline]
+    fn stable_depths(&mut self, depths: u32) {
+        let _ = depths;
+    }
+}
+
+/// The [`RunCondition`][rc] created by conditions which do not depend on the fitness.
 ///
 /// [rc]: trait.RunCondition.html
-/// [steps]: struct.Steps.html
 #[doc(hidden)]
 #[derive(Debug)]
-pub struct InnerSteps(u32, u32);
+pub struct InnerIndependent<T, F>(T, PhantomData<fn(&F)>);
 
-impl IntoRunCondition for Steps {
-    type RunCondition = InnerSteps;
+impl<T, F> InnerIndependent<T, F> {
+    fn new(condition: T) -> Self {
+        InnerIndependent(condition, PhantomData)
+    }
 
-    fn into_run_condition(self) -> InnerSteps {
-        InnerSteps(0, self.0)
+    /// consumes `self` and returns the wrapped condition
+    pub fn into_inner(self) -> T {
+        self.0
     }
 }
 
-impl RunCondition for InnerSteps {
+impl<T: IndependentCondition, F> RunCondition for InnerIndependent<T, F> {
+    type Fitness = F;
+
     #[inline]
     fn step(&mut self) -> bool {
-        self.0 += 1;
-        self.0 < self.1
+        self.0.step()
     }
 
     #[inline]
-    fn depth(&mut self, _: u32) -> bool {
-        true
+    fn depth(&mut self, depth: u32) -> bool {
+        self.0.depth(depth)
     }
-}
-
-/// Creates a [`RunCondition`][rc] which returns `true` until this `Duration` has passed.
-///
-/// [rc]: trait.RunCondition.html
-impl IntoRunCondition for Duration {
-    type RunCondition = Instant;
 
-    fn into_run_condition(self) -> Instant {
-        Instant::now() + self
+    #[inline]
+    fn stable_depths(&mut self, depths: u32) {
+        self.0.stable_depths(depths);
     }
 }
 
-/// A condition which indicates if [`Bot::select`][sel] should keep on running.
-/// It is recommended to use [`Duration`][dur] for nearly all use cases.
+/// Can be converted into [`RunCondition`][rc] which returns `true` for the first `self.0` steps.
+/// This should only be used for debugging and testing as unlike `Duration`, `ToCompletion` or `Depth`,
+/// the total amount of steps needed is not directly indicative of search depth and can change between minor versions.
 ///
-/// A list of all already implemented `RunCondition`s can be found [here][into].
+/// [rc]: trait.RunCondition.html
+#[derive(Clone, Copy, Debug)]
+pub struct Steps(pub u32);
+
+/// The [`RunCondition`][rc] created by [`Steps`][steps]`::into_run_condition`
 ///
-/// [sel]: alpha_beta/struct.Bot.html#method.select
-/// [dur]: https://doc.rust-lang.org/std/time/struct.Duration.html
-/// [into]: trait.IntoRunCondition.html#implementations-1
-pub trait RunCondition {
-    /// Called at each search step, instantly stops all calculations by returning `false`.
-    fn step(&mut self) -> bool;
-    /// Called after every finished search depth, instantly stops all calculations by returning `false`.
-    fn depth(&mut self, depth: u32) -> bool;
+/// [rc]: trait.RunCondition.html
+/// [steps]: struct.Steps.html
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct InnerSteps(u32, u32);
+
+impl<F> IntoRunCondition<F> for Steps {
+    type RunCondition = InnerIndependent<InnerSteps, F>;
+
+    fn into_run_condition(self) -> Self::RunCondition {
+        InnerIndependent::new(InnerSteps(0, self.0))
+    }
 }
 
-/// Returns `true` while the `Instant` is still in the future
-impl RunCondition for Instant {
+impl IndependentCondition for InnerSteps {
     #[inline]
     fn step(&mut self) -> bool {
-        Instant::now() < *self
+        self.0 += 1;
+        self.0 < self.1
     }
 
     #[inline]
     fn depth(&mut self, _: u32) -> bool {
-        Instant::now() < *self
+        true
     }
 }
 
-/// A struct implementing [`RunCondition`][rc] which always returns `true`.
+/// Can be converted into a [`RunCondition`][rc] which stops the computation once `self.0` nodes were visited.
 ///
-/// This means that the bot will always run until the best action was found.
+/// Each call to [`RunCondition::step`][step] visits one node, so the computation is
+/// cancelled during the `self.0`th call. Unlike [`Steps`][steps], this is a stable limit
+/// whose semantics will not change, which is useful for reproducible benchmarks and
+/// environments where the time is too noisy to use `Duration`.
+///
+/// Note that the amount of nodes needed to reach a given depth can still change with new versions.
 ///
 /// # Examples
 ///
 /// ```rust
-/// # use rubot::{Bot, tree::Node, ToCompletion};
+/// # use rubot::{Bot, tree::Node, Logger, NodeCount};
+/// # #[rustfmt::skip]
 /// let tree = Node::root().with_children(&[
-///     Node::new(false, 7).with_children(&[
+///     Node::new(false, 1).with_children(&[
 ///         Node::new(true, 4),
-///         Node::new(true, 2),
-///     ]),
-///     Node::new(false, 5).with_children(&[
-///         Node::new(true, 8),
-///         Node::new(true, 9)
 ///     ]),
+///     Node::new(false, 3),
+///     Node::new(false, 2),
 /// ]);
 ///
-/// let mut bot = Bot::new(true);
-/// assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+/// let mut first = Logger::new(NodeCount(3));
+/// let action = Bot::new(true).select(&tree, &mut first);
+/// assert_eq!(first.steps(), 3);
+/// assert!(!first.completed());
+///
+/// // The same limit always results in the same action.
+/// let mut second = Logger::new(NodeCount(3));
+/// assert_eq!(Bot::new(true).select(&tree, &mut second), action);
+/// assert_eq!(second.steps(), 3);
 /// ```
+///
 /// [rc]: trait.RunCondition.html
+/// [step]: trait.RunCondition.html#tymethod.step
+/// [steps]: struct.Steps.html
 #[derive(Clone, Copy, Debug)]
-pub struct ToCompletion;
+pub struct NodeCount(pub u64);
 
-impl RunCondition for ToCompletion {
+/// The [`RunCondition`][rc] created by [`NodeCount`][nc]`::into_run_condition`
+///
+/// [rc]: trait.RunCondition.html
+/// [nc]: struct.NodeCount.html
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct InnerNodeCount(u64);
+
+impl<F> IntoRunCondition<F> for NodeCount {
+    type RunCondition = InnerIndependent<InnerNodeCount, F>;
+
+    fn into_run_condition(self) -> Self::RunCondition {
+        InnerIndependent::new(InnerNodeCount(self.0))
+    }
+}
+
+impl IndependentCondition for InnerNodeCount {
     #[inline]
     fn step(&mut self) -> bool {
-        true
+        self.0 = self.0.saturating_sub(1);
+        self.0 > 0
     }
 
     #[inline]
@@ -447,47 +951,993 @@ impl RunCondition for ToCompletion {
     }
 }
 
-/// A struct implementing [`RunCondition`][rc] returning cancelling the computation once the depth `self.0`
-/// is reached.
+/// Creates a [`RunCondition`][rc] which returns `true` until this `Duration` has passed.
+///
+/// [rc]: trait.RunCondition.html
+#[cfg(feature = "std")]
+impl<F> IntoRunCondition<F> for Duration {
+    type RunCondition = InnerIndependent<Instant, F>;
+
+    fn into_run_condition(self) -> Self::RunCondition {
+        InnerIndependent::new(Instant::now() + self)
+    }
+}
+
+/// Can be converted into a [`RunCondition`][rc] with both a `soft` and a `hard` time limit.
+///
+/// Once the `soft` limit has passed, no new depth is started, while the current depth
+/// is only cancelled once the `hard` limit has passed. This allows the bot to mostly stop cleanly
+/// between depths, while still guaranteeing that it never takes longer than `hard`.
+/// Both limits start once the bot starts searching.
 ///
 /// # Examples
 ///
 /// ```rust
-/// # use rubot::{Bot, tree::Node, Depth};
+/// # use rubot::{Bot, tree::Node, Logger, TimeBudget};
+/// # use std::time::Duration;
 /// let tree = Node::root().with_children(&[
-///     Node::new(false, 7).with_children(&[
-///         Node::new(true, 4),
-///         Node::new(true, 2),
-///     ]),
-///     Node::new(false, 5).with_children(&[
-///         Node::new(true, 8),
-///         Node::new(true, 9)
-///     ]),
+///     Node::new(false, 3),
+///     Node::new(false, 8),
+///     Node::new(false, 1),
 /// ]);
 ///
-/// let mut bot = Bot::new(true);
-/// assert_eq!(bot.select(&tree, Depth(0)), Some(0));
+/// // The soft limit has already passed, so no depth is started,
+/// // while the hard limit would have left enough time to complete the search.
+/// let budget = TimeBudget {
+///     soft: Duration::from_secs(0),
+///     hard: Duration::from_secs(5),
+/// };
+/// let mut logger = Logger::new(budget);
+/// assert!(Bot::new(true).select(&tree, &mut logger).is_some());
+/// assert_eq!(logger.depth(), 0);
+/// assert!(!logger.completed());
+/// ```
+///
+/// [rc]: trait.RunCondition.html
+#[derive(Clone, Copy, Debug)]
+#[cfg(feature = "std")]
+pub struct TimeBudget {
+    /// The time after which no new depth is started.
+    pub soft: Duration,
+    /// The time after which the search is cancelled.
+    pub hard: Duration,
+}
+
+/// The [`RunCondition`][rc] created by [`TimeBudget`][tb]`::into_run_condition`
+///
+/// [rc]: trait.RunCondition.html
+/// [tb]: struct.TimeBudget.html
+#[doc(hidden)]
+#[derive(Debug)]
+#[cfg(feature = "std")]
+pub struct InnerTimeBudget {
+    soft: Instant,
+    hard: Instant,
+}
+
+#[cfg(feature = "std")]
+impl<F> IntoRunCondition<F> for TimeBudget {
+    type RunCondition = InnerIndependent<InnerTimeBudget, F>;
+
+    fn into_run_condition(self) -> Self::RunCondition {
+        let now = Instant::now();
+        InnerIndependent::new(InnerTimeBudget {
+            soft: now + self.soft,
+            hard: now + self.hard,
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl IndependentCondition for InnerTimeBudget {
+    #[inline]
+    fn step(&mut self) -> bool {
+        SystemClock.now() < self.hard
+    }
+
+    #[inline]
+    fn depth(&mut self, _: u32) -> bool {
+        let now = SystemClock.now();
+        now < self.soft && now < self.hard
+    }
+}
+
+/// A [`RunCondition`][rc] which stops the computation once the corresponding
+/// [`CancelHandle`][handle] was used, which is possible from any thread.
+///
+/// Just like with any other run condition, a cancelled [`Bot::select`][sel]
+/// still returns the best action found so far.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rubot::{Bot, tree::Node, Or, Cancel};
+/// # use std::{thread, time::Duration};
+/// # #[rustfmt::skip]
+/// let tree = Node::root().with_children(&[
+///     Node::new(false, 2).with_children(&[
+///         Node::new(true, 1),
+///         Node::new(true, 6),
+///     ]),
+///     Node::new(false, 4),
+/// ]);
+///
+/// let (cancel, handle) = Cancel::new();
+/// let worker = thread::spawn(move || {
+///     Bot::new(true).select(&tree, Or(Duration::from_secs(5), cancel))
+/// });
+/// // Stop the bot early, for example because the user pressed a button.
+/// handle.cancel();
+/// assert!(worker.join().unwrap().is_some());
+/// ```
+///
+/// [rc]: trait.RunCondition.html
+/// [handle]: struct.CancelHandle.html
+/// [sel]: alpha_beta/struct.Bot.html#method.select
+#[derive(Debug)]
+pub struct Cancel(Arc<AtomicBool>);
+
+impl Cancel {
+    /// Creates a new `Cancel` together with the handle used to cancel it.
+    pub fn new() -> (Cancel, CancelHandle) {
+        let flag = Arc::new(AtomicBool::new(false));
+        (Cancel(Arc::clone(&flag)), CancelHandle(flag))
+    }
+}
+
+impl<F> IntoRunCondition<F> for Cancel {
+    type RunCondition = InnerIndependent<Self, F>;
+
+    fn into_run_condition(self) -> Self::RunCondition {
+        InnerIndependent::new(self)
+    }
+}
+
+impl IndependentCondition for Cancel {
+    #[inline]
+    fn step(&mut self) -> bool {
+        !self.0.load(atomic::Ordering::Relaxed)
+    }
+
+    #[inline]
+    fn depth(&mut self, _: u32) -> bool {
+        !self.0.load(atomic::Ordering::Relaxed)
+    }
+}
+
+/// Used to stop a search using [`Cancel`][cancel] from any thread.
+///
+/// [cancel]: struct.Cancel.html
+#[derive(Clone, Debug)]
+pub struct CancelHandle(Arc<AtomicBool>);
+
+impl CancelHandle {
+    /// Stops all searches using the corresponding [`Cancel`][cancel].
+    ///
+    /// [cancel]: struct.Cancel.html
+    pub fn cancel(&self) {
+        self.0.store(true, atomic::Ordering::Relaxed);
+    }
+
+    /// Returns `true` if [`cancel`][cancel] was already called.
+    ///
+    /// [cancel]: struct.CancelHandle.html#method.cancel
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(atomic::Ordering::Relaxed)
+    }
+}
+
+/// A condition which indicates if [`Bot::select`][sel] should keep on running.
+/// It is recommended to use [`Duration`][dur] for nearly all use cases.
+///
+/// A list of all already implemented `RunCondition`s can be found [here][into].
+///
+/// [sel]: alpha_beta/struct.Bot.html#method.select
+/// [dur]: https://doc.rust-lang.org/std/time/struct.Duration.html
+/// [into]: trait.IntoRunCondition.html#implementations-1
+pub trait RunCondition {
+    /// The fitness used by the bot, which is [`Game::Fitness`][fit] for most bots.
+    ///
+    /// [fit]: trait.Game.html#associatedtype.Fitness
+    type Fitness;
+
+    /// Called at each search step, instantly stops all calculations by returning `false`.
+    fn step(&mut self) -> bool;
+    /// Called after every finished search depth, instantly stops all calculations by returning `false`.
+    fn depth(&mut self, depth: u32) -> bool;
+
+    /// Called by the bot instead of [`depth`][depth], additionally receiving the fitness
+    /// of the currently best action, instantly stops all calculations by returning `false`.
+    ///
+    /// `best` is `None` if no depth was completed yet. This allows conditions to stop once
+    /// the best action is good enough or to extend the search while the fitness is still changing.
+    /// By default, this simply calls `depth`.
+    ///
+    /// ```rust
+    /// # use rubot::{Bot, tree::Node, Logger, RunCondition};
+    /// /// Stops once the best action has a fitness of at least `10`.
+    /// struct GoodEnough;
+    ///
+    /// impl RunCondition for GoodEnough {
+    ///     type Fitness = i8;
+    ///
+    ///     fn step(&mut self) -> bool {
+    ///         true
+    ///     }
+    ///
+    ///     fn depth(&mut self, _: u32) -> bool {
+    ///         true
+    ///     }
+    ///
+    ///     fn completed_depth(&mut self, _: u32, best: Option<&i8>) -> bool {
+    ///         best < Some(&10)
+    ///     }
+    /// }
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(true, 0).with_children(&[
+    ///         Node::new(true, 10).with_children(&[
+    ///             Node::new(true, 0),
+    ///         ]),
+    ///     ]),
+    ///     Node::new(true, 5),
+    /// ]);
+    ///
+    /// let mut logger = Logger::new(GoodEnough);
+    /// // `[0]` has a fitness of `10` after depth 0, so the bot does not notice that `[1]` is better.
+    /// assert_eq!(Bot::new(true).select(&tree, &mut logger), Some(0));
+    /// assert_eq!(logger.depth(), 1);
+    /// ```
+    ///
+    /// [depth]: trait.RunCondition.html#tymethod.depth
+    #[inline]
+    fn completed_depth(&mut self, depth: u32, best: Option<&Self::Fitness>) -> bool {
+        let _ = best;
+        self.depth(depth)
+    }
+
+    /// Called once the bot has selected an action, with the fitness of this action
+    /// and the length of its principal variation.
+    ///
+    /// This is ignored by default.
+    #[inline]
+    fn search_finished(&mut self, fitness: &Self::Fitness, pv_len: usize) {
+        let _ = (fitness, pv_len);
+    }
+
+    /// Called after every completed depth right before [`completed_depth`][cd], with the amount of
+    /// consecutive completed depths after which the currently best action was the best action.
+    ///
+    /// This is `1` if the best action just changed. As a best action which stays the same over
+    /// multiple depths is unlikely to change again, this can be used to stop the search early.
+    /// This is ignored by default.
+    ///
+    /// ```rust
+    /// # use rubot::{Bot, tree::Node, RunCondition, ToCompletion};
+    /// /// Stops once the best action did not change for `2` depths.
+    /// struct Stable(bool);
+    ///
+    /// impl RunCondition for Stable {
+    ///     type Fitness = i8;
+    ///
+    ///     fn step(&mut self) -> bool {
+    ///         true
+    ///     }
+    ///
+    ///     fn depth(&mut self, _: u32) -> bool {
+    ///         !self.0
+    ///     }
+    ///
+    ///     fn stable_depths(&mut self, depths: u32) {
+    ///         self.0 = depths >= 2;
+    ///     }
+    /// }
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 6).with_children(&[
+    ///             Node::new(false, 6).with_children(&[
+    ///                 Node::new(true, 0),
+    ///             ]),
+    ///         ]),
+    ///     ]),
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 5).with_children(&[
+    ///             Node::new(false, 5).with_children(&[
+    ///                 Node::new(true, 9),
+    ///             ]),
+    ///         ]),
+    ///     ]),
+    /// ]);
+    ///
+    /// // `[0]` is the best action after both depth 0 and 1.
+    /// assert_eq!(Bot::new(true).select(&tree, Stable(false)), Some(0));
+    /// assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(1));
+    /// ```
+    ///
+    /// [cd]: trait.RunCondition.html#method.completed_depth
+    #[inline]
+    fn stable_depths(&mut self, depths: u32) {
+        let _ = depths;
+    }
+
+    /// Called each time the remaining actions of a state are skipped as the already
+    /// analysed actions are good enough, also known as a beta cutoff.
+    ///
+    /// Together with [`leaf`][leaf], this shows how well the search is pruning.
+    /// This is ignored by default.
+    ///
+    /// [leaf]: trait.RunCondition.html#method.leaf
+    #[inline]
+    fn cutoff(&mut self) {}
+
+    /// Called for each state at the lowest depth of the search, which is evaluated
+    /// without analysing its actions further.
+    ///
+    /// This is ignored by default.
+    #[inline]
+    fn leaf(&mut self) {}
+}
+
+/// Returns `true` while the `Instant` is still in the future
+#[cfg(feature = "std")]
+impl<F> IntoRunCondition<F> for Instant {
+    type RunCondition = InnerIndependent<Self, F>;
+
+    fn into_run_condition(self) -> Self::RunCondition {
+        InnerIndependent::new(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl IndependentCondition for Instant {
+    #[inline]
+    fn step(&mut self) -> bool {
+        SystemClock.now() < *self
+    }
+
+    #[inline]
+    fn depth(&mut self, _: u32) -> bool {
+        SystemClock.now() < *self
+    }
+}
+
+/// A source of the current time used by [`Deadline`][deadline].
+///
+/// This is mostly useful to test time based logic without depending on the actual time,
+/// in which case a clock can simply advance by a fixed amount each time `now` is called.
+///
+/// [deadline]: struct.Deadline.html
+#[cfg(feature = "std")]
+pub trait Clock {
+    /// Returns the current time.
+    fn now(&mut self) -> Instant;
+}
+
+/// The [`Clock`][clock] using the actual system time, which is used by `Instant` and `Duration`.
+///
+/// [clock]: trait.Clock.html
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg(feature = "std")]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    #[inline]
+    fn now(&mut self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A [`RunCondition`][rc] which returns `true` until `clock` reaches a given `Instant`.
+///
+/// With the default [`SystemClock`][sys], this behaves exactly like `Instant`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rubot::{Bot, tree::Node, Clock, Deadline};
+/// use std::time::{Duration, Instant};
+///
+/// /// A clock which advances by one millisecond each time it is used.
+/// struct Ticks(Instant);
+///
+/// impl Clock for Ticks {
+///     fn now(&mut self) -> Instant {
+///         self.0 += Duration::from_millis(1);
+///         self.0
+///     }
+/// }
+///
+/// # #[rustfmt::skip]
+/// let tree = Node::root().with_children(&[
+///     Node::new(true, 0),
+///     Node::new(true, 1),
+/// ]);
+///
+/// let deadline = Deadline::new(Duration::from_millis(100), Ticks(Instant::now()));
+/// assert_eq!(Bot::new(true).select(&tree, deadline), Some(1));
+/// ```
+/// [rc]: trait.RunCondition.html
+/// [sys]: struct.SystemClock.html
+#[derive(Clone, Copy, Debug)]
+#[cfg(feature = "std")]
+pub struct Deadline<C: Clock = SystemClock> {
+    end: Instant,
+    clock: C,
+}
+
+#[cfg(feature = "std")]
+impl<C: Clock> Deadline<C> {
+    /// Creates a new `Deadline` which ends once `duration` has passed on `clock`.
+    pub fn new(duration: Duration, mut clock: C) -> Self {
+        Self {
+            end: clock.now() + duration,
+            clock,
+        }
+    }
+
+    /// Creates a new `Deadline` which ends once `clock` reaches `end`.
+    pub fn until(end: Instant, clock: C) -> Self {
+        Self { end, clock }
+    }
+
+    /// Returns the `Instant` at which this deadline ends.
+    pub fn end(&self) -> Instant {
+        self.end
+    }
+
+    /// Consumes `self` and returns the used clock.
+    pub fn into_clock(self) -> C {
+        self.clock
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: Clock, F> IntoRunCondition<F> for Deadline<C> {
+    type RunCondition = InnerIndependent<Self, F>;
+
+    fn into_run_condition(self) -> Self::RunCondition {
+        InnerIndependent::new(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: Clock> IndependentCondition for Deadline<C> {
+    #[inline]
+    fn step(&mut self) -> bool {
+        self.clock.now() < self.end
+    }
+
+    #[inline]
+    fn depth(&mut self, _: u32) -> bool {
+        self.clock.now() < self.end
+    }
+}
+
+/// A struct implementing [`RunCondition`][rc] which always returns `true`.
+///
+/// This means that the bot will always run until the best action was found.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rubot::{Bot, tree::Node, ToCompletion};
+/// let tree = Node::root().with_children(&[
+///     Node::new(false, 7).with_children(&[
+///         Node::new(true, 4),
+///         Node::new(true, 2),
+///     ]),
+///     Node::new(false, 5).with_children(&[
+///         Node::new(true, 8),
+///         Node::new(true, 9)
+///     ]),
+/// ]);
+///
+/// let mut bot = Bot::new(true);
+/// assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+/// ```
+/// [rc]: trait.RunCondition.html
+#[derive(Clone, Copy, Debug)]
+pub struct ToCompletion;
+
+impl<F> IntoRunCondition<F> for ToCompletion {
+    type RunCondition = InnerIndependent<Self, F>;
+
+    fn into_run_condition(self) -> Self::RunCondition {
+        InnerIndependent::new(self)
+    }
+}
+
+impl IndependentCondition for ToCompletion {
+    #[inline]
+    fn step(&mut self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn depth(&mut self, _: u32) -> bool {
+        true
+    }
+}
+
+/// A struct implementing [`RunCondition`][rc] returning cancelling the computation once the depth `self.0`
+/// is reached.
+///
+/// Each depth contains one more action, regardless of the player executing it.
+/// Use [`Moves`][moves] to only count the actions of the bot itself.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rubot::{Bot, tree::Node, Depth};
+/// let tree = Node::root().with_children(&[
+///     Node::new(false, 7).with_children(&[
+///         Node::new(true, 4),
+///         Node::new(true, 2),
+///     ]),
+///     Node::new(false, 5).with_children(&[
+///         Node::new(true, 8),
+///         Node::new(true, 9)
+///     ]),
+/// ]);
+///
+/// let mut bot = Bot::new(true);
+/// assert_eq!(bot.select(&tree, Depth(0)), Some(0));
 /// assert_eq!(bot.select(&tree, Depth(1)), Some(1));
 /// ```
 /// [rc]: trait.RunCondition.html
-#[derive(Clone, Copy, Debug)]
-pub struct Depth(pub u32);
+/// [moves]: struct.Moves.html
+#[derive(Clone, Copy, Debug)]
+pub struct Depth(pub u32);
+
+impl<F> IntoRunCondition<F> for Depth {
+    type RunCondition = InnerIndependent<Self, F>;
+
+    fn into_run_condition(self) -> Self::RunCondition {
+        InnerIndependent::new(self)
+    }
+}
+
+impl IndependentCondition for Depth {
+    #[inline]
+    fn step(&mut self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn depth(&mut self, depth: u32) -> bool {
+        self.0 > depth
+    }
+}
+
+/// A struct implementing [`RunCondition`][rc] which cancels the computation once the bot looked
+/// `self.0` of its own actions ahead, each followed by the answer of its opponent.
+///
+/// [`Depth`][depth] counts every action, regardless of the player executing it, so looking
+/// two of its own actions ahead requires `Depth(3)`, as the bot already considers the
+/// answer to each of its actions at depth `0`. `Moves(n)` is equal to `Depth(2 * n - 1)`,
+/// which assumes that the players alternate after each action.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rubot::{Bot, tree::Node, Depth, Moves};
+/// # #[rustfmt::skip]
+/// let tree = Node::root().with_children(&[
+///     Node::new(false, 6).with_children(&[
+///         Node::new(true, 6).with_children(&[
+///             Node::new(false, 6).with_children(&[Node::new(true, 0)]),
+///         ]),
+///     ]),
+///     Node::new(false, 5).with_children(&[
+///         Node::new(true, 5).with_children(&[
+///             Node::new(false, 5).with_children(&[Node::new(true, 5)]),
+///         ]),
+///     ]),
+/// ]);
+///
+/// let mut bot = Bot::new(true);
+/// assert_eq!(bot.select(&tree, Moves(1)), Some(0));
+/// // The second answer of the opponent makes the first action worse.
+/// assert_eq!(bot.select(&tree, Moves(2)), Some(1));
+/// assert_eq!(bot.select(&tree, Depth(3)), Some(1));
+/// ```
+/// [rc]: trait.RunCondition.html
+/// [depth]: struct.Depth.html
+#[derive(Clone, Copy, Debug)]
+pub struct Moves(pub u32);
+
+impl<F> IntoRunCondition<F> for Moves {
+    type RunCondition = InnerIndependent<Self, F>;
+
+    fn into_run_condition(self) -> Self::RunCondition {
+        InnerIndependent::new(self)
+    }
+}
+
+impl IndependentCondition for Moves {
+    #[inline]
+    fn step(&mut self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn depth(&mut self, depth: u32) -> bool {
+        self.0.saturating_mul(2).saturating_sub(1) > depth
+    }
+}
+
+/// Can be converted into a [`RunCondition`][rc] which stops the computation once the best action
+/// stayed the same for `self.0` consecutive completed depths, see [`RunCondition::stable_depths`][sd].
+///
+/// As the best action rarely changes once it is stable, this is a good way to save time,
+/// especially when combined with a time limit using [`Or`][or].
+///
+/// # Examples
+///
+/// ```rust
+/// # use rubot::{Bot, Or, tree::Node, StableFor};
+/// # use std::time::Duration;
+/// # #[rustfmt::skip]
+/// let tree = Node::root().with_children(&[
+///     Node::new(false, 7).with_children(&[
+///         Node::new(true, 6).with_children(&[
+///             Node::new(false, 6).with_children(&[
+///                 Node::new(true, 0),
+///             ]),
+///         ]),
+///     ]),
+///     Node::new(false, 5).with_children(&[
+///         Node::new(true, 5).with_children(&[
+///             Node::new(false, 5).with_children(&[
+///                 Node::new(true, 9),
+///             ]),
+///         ]),
+///     ]),
+/// ]);
+///
+/// let mut bot = Bot::new(true);
+/// // `[0]` is the best action after both depth 0 and 1, so the bot stops before noticing that `[1]` is better.
+/// assert_eq!(bot.select(&tree, StableFor(2)), Some(0));
+/// assert_eq!(bot.select(&tree, Or(StableFor(3), Duration::from_secs(10))), Some(1));
+/// ```
+///
+/// [rc]: trait.RunCondition.html
+/// [sd]: trait.RunCondition.html#method.stable_depths
+/// [or]: struct.Or.html
+#[derive(Clone, Copy, Debug)]
+pub struct StableFor(pub u32);
+
+/// The [`RunCondition`][rc] created by [`StableFor`][sf]`::into_run_condition`
+///
+/// [rc]: trait.RunCondition.html
+/// [sf]: struct.StableFor.html
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct InnerStableFor(u32, u32);
+
+impl<F> IntoRunCondition<F> for StableFor {
+    type RunCondition = InnerIndependent<InnerStableFor, F>;
+
+    fn into_run_condition(self) -> Self::RunCondition {
+        InnerIndependent::new(InnerStableFor(0, self.0))
+    }
+}
+
+impl IndependentCondition for InnerStableFor {
+    #[inline]
+    fn step(&mut self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn depth(&mut self, _: u32) -> bool {
+        self.0 < self.1
+    }
+
+    #[inline]
+    fn stable_depths(&mut self, depths: u32) {
+        self.0 = depths;
+    }
+}
+
+/// A struct implementing [`IntoRunCondition`][irc] which stops the computation
+/// as soon as either of the two wrapped conditions does.
+///
+/// Both conditions are always called, so a [`Logger`][logger] wrapping one of them
+/// still records every step and depth.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rubot::{Bot, tree::Node, Or, Depth, Logger, ToCompletion};
+/// # #[rustfmt::skip]
+/// let tree = Node::root().with_children(&[
+///     Node::new(false, 2).with_children(&[
+///         Node::new(true, 6).with_children(&[Node::new(false, 1)]),
+///     ]),
+///     Node::new(false, 4).with_children(&[
+///         Node::new(true, 3).with_children(&[Node::new(false, 3)]),
+///     ]),
+/// ]);
+///
+/// // `Depth(1)` stops the search, which is still recorded by the logger.
+/// let mut logger = Logger::new(ToCompletion);
+/// assert_eq!(Bot::new(true).select(&tree, Or(Depth(1), &mut logger)), Some(0));
+/// assert_eq!(logger.depth(), 1);
+/// ```
+/// [irc]: trait.IntoRunCondition.html
+/// [logger]: struct.Logger.html
+#[derive(Clone, Copy, Debug)]
+pub struct Or<A, B>(pub A, pub B);
+
+/// The [`RunCondition`][rc] created by [`Or`][or]`::into_run_condition`
+///
+/// [rc]: trait.RunCondition.html
+/// [or]: struct.Or.html
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct InnerOr<A, B>(A, B);
+
+impl<F, A: IntoRunCondition<F>, B: IntoRunCondition<F>> IntoRunCondition<F> for Or<A, B> {
+    type RunCondition = InnerOr<A::RunCondition, B::RunCondition>;
+
+    fn into_run_condition(self) -> Self::RunCondition {
+        InnerOr(self.0.into_run_condition(), self.1.into_run_condition())
+    }
+}
+
+impl<A: RunCondition, B: RunCondition<Fitness = A::Fitness>> RunCondition for InnerOr<A, B> {
+    type Fitness = A::Fitness;
+
+    #[inline]
+    fn step(&mut self) -> bool {
+        // Not using `&&` so both conditions are always called.
+        self.0.step() & self.1.step()
+    }
+
+    #[inline]
+    fn depth(&mut self, depth: u32) -> bool {
+        self.0.depth(depth) & self.1.depth(depth)
+    }
+
+    #[inline]
+    fn completed_depth(&mut self, depth: u32, best: Option<&Self::Fitness>) -> bool {
+        self.0.completed_depth(depth, best) & self.1.completed_depth(depth, best)
+    }
+
+    #[inline]
+    fn search_finished(&mut self, fitness: &Self::Fitness, pv_len: usize) {
+        self.0.search_finished(fitness, pv_len);
+        self.1.search_finished(fitness, pv_len);
+    }
+
+    #[inline]
+    fn stable_depths(&mut self, depths: u32) {
+        self.0.stable_depths(depths);
+        self.1.stable_depths(depths);
+    }
+
+    #[inline]
+    fn cutoff(&mut self) {
+        self.0.cutoff();
+        self.1.cutoff();
+    }
+
+    #[inline]
+    fn leaf(&mut self) {
+        self.0.leaf();
+        self.1.leaf();
+    }
+}
+
+/// A struct implementing [`IntoRunCondition`][irc] which only stops the computation
+/// once both of the two wrapped conditions do.
+///
+/// Both conditions are always called, so a [`Logger`][logger] wrapping one of them
+/// still records every step and depth.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rubot::{Bot, tree::Node, And, Depth, Logger, Steps};
+/// # #[rustfmt::skip]
+/// let tree = Node::root().with_children(&[
+///     Node::new(false, 2).with_children(&[
+///         Node::new(true, 6).with_children(&[Node::new(false, 1)]),
+///     ]),
+///     Node::new(false, 4).with_children(&[
+///         Node::new(true, 3).with_children(&[Node::new(false, 3)]),
+///     ]),
+/// ]);
+///
+/// // `Depth(1)` would stop the search, but there are still steps left.
+/// let mut logger = Logger::new(Steps(1000));
+/// assert_eq!(Bot::new(true).select(&tree, And(Depth(1), &mut logger)), Some(1));
+/// assert_eq!(logger.depth(), 3);
+/// assert!(logger.completed());
+/// ```
+/// [irc]: trait.IntoRunCondition.html
+/// [logger]: struct.Logger.html
+#[derive(Clone, Copy, Debug)]
+pub struct And<A, B>(pub A, pub B);
+
+/// The [`RunCondition`][rc] created by [`And`][and]`::into_run_condition`
+///
+/// [rc]: trait.RunCondition.html
+/// [and]: struct.And.html
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct InnerAnd<A, B>(A, B);
+
+impl<F, A: IntoRunCondition<F>, B: IntoRunCondition<F>> IntoRunCondition<F> for And<A, B> {
+    type RunCondition = InnerAnd<A::RunCondition, B::RunCondition>;
+
+    fn into_run_condition(self) -> Self::RunCondition {
+        InnerAnd(self.0.into_run_condition(), self.1.into_run_condition())
+    }
+}
+
+impl<A: RunCondition, B: RunCondition<Fitness = A::Fitness>> RunCondition for InnerAnd<A, B> {
+    type Fitness = A::Fitness;
+
+    #[inline]
+    fn step(&mut self) -> bool {
+        // Not using `||` so both conditions are always called.
+        self.0.step() | self.1.step()
+    }
+
+    #[inline]
+    fn depth(&mut self, depth: u32) -> bool {
+        self.0.depth(depth) | self.1.depth(depth)
+    }
+
+    #[inline]
+    fn completed_depth(&mut self, depth: u32, best: Option<&Self::Fitness>) -> bool {
+        self.0.completed_depth(depth, best) | self.1.completed_depth(depth, best)
+    }
+
+    #[inline]
+    fn search_finished(&mut self, fitness: &Self::Fitness, pv_len: usize) {
+        self.0.search_finished(fitness, pv_len);
+        self.1.search_finished(fitness, pv_len);
+    }
+
+    #[inline]
+    fn stable_depths(&mut self, depths: u32) {
+        self.0.stable_depths(depths);
+        self.1.stable_depths(depths);
+    }
+
+    #[inline]
+    fn cutoff(&mut self) {
+        self.0.cutoff();
+        self.1.cutoff();
+    }
+
+    #[inline]
+    fn leaf(&mut self) {
+        self.0.leaf();
+        self.1.leaf();
+    }
+}
+
+/// A struct implementing [`IntoRunCondition`][irc] which calls `callback` after each
+/// completed depth while leaving the decision when to stop to the wrapped `condition`.
+///
+/// The `callback` receives the amount of completed depths, which is the same value
+/// stored by [`Logger::depth`][depth]. It is always called before the wrapped condition,
+/// so it also sees the last depth of a search.
+///
+/// # Examples
+///
+/// ```rust
+/// # use rubot::{Bot, tree::Node, Progress, ToCompletion};
+/// # #[rustfmt::skip]
+/// let tree = Node::root().with_children(&[
+///     Node::new(false, 2).with_children(&[
+///         Node::new(true, 6).with_children(&[Node::new(false, 1)]),
+///     ]),
+///     Node::new(false, 4).with_children(&[
+///         Node::new(true, 3).with_children(&[Node::new(false, 3)]),
+///     ]),
+/// ]);
+///
+/// let mut depths = Vec::new();
+/// let progress = Progress::new(ToCompletion, |depth| depths.push(depth));
+/// assert_eq!(Bot::new(true).select(&tree, progress), Some(1));
+/// assert_eq!(depths, &[0, 1, 2, 3]);
+/// ```
+/// [irc]: trait.IntoRunCondition.html
+/// [depth]: struct.Logger.html#method.depth
+#[derive(Clone, Copy)]
+pub struct Progress<T, F> {
+    condition: T,
+    callback: F,
+}
+
+impl<T: Debug, F> Debug for Progress<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Progress")
+            .field("condition", &self.condition)
+            .finish()
+    }
+}
+
+impl<T, F: FnMut(u32)> Progress<T, F> {
+    /// Creates a new `Progress` calling `callback` after each completed depth.
+    pub fn new(condition: T, callback: F) -> Self {
+        Self {
+            condition,
+            callback,
+        }
+    }
+}
+
+/// The [`RunCondition`][rc] created by [`Progress`][progress]`::into_run_condition`
+///
+/// [rc]: trait.RunCondition.html
+/// [progress]: struct.Progress.html
+#[doc(hidden)]
+pub struct InnerProgress<T, F>(T, F);
+
+impl<S, T: IntoRunCondition<S>, F: FnMut(u32)> IntoRunCondition<S> for Progress<T, F> {
+    type RunCondition = InnerProgress<T::RunCondition, F>;
+
+    fn into_run_condition(self) -> Self::RunCondition {
+        InnerProgress(self.condition.into_run_condition(), self.callback)
+    }
+}
+
+impl<T: RunCondition, F: FnMut(u32)> RunCondition for InnerProgress<T, F> {
+    type Fitness = T::Fitness;
 
-impl RunCondition for Depth {
     #[inline]
     fn step(&mut self) -> bool {
-        true
+        self.0.step()
     }
 
     #[inline]
     fn depth(&mut self, depth: u32) -> bool {
-        self.0 > depth
+        self.completed_depth(depth, None)
+    }
+
+    #[inline]
+    fn completed_depth(&mut self, depth: u32, best: Option<&Self::Fitness>) -> bool {
+        (self.1)(depth);
+        self.0.completed_depth(depth, best)
+    }
+
+    #[inline]
+    fn search_finished(&mut self, fitness: &Self::Fitness, pv_len: usize) {
+        self.0.search_finished(fitness, pv_len);
+    }
+
+    #[inline]
+    fn stable_depths(&mut self, depths: u32) {
+        self.0.stable_depths(depths);
+    }
+
+    #[inline]
+    fn cutoff(&mut self) {
+        self.0.cutoff();
+    }
+
+    #[inline]
+    fn leaf(&mut self) {
+        self.0.leaf();
     }
 }
 
 /// A struct implementing [`IntoRunCondition`] which can be used to log a call to [`select`][sel].
 /// For more details you can visit the individual methods.
 ///
+/// A `Logger` can be reused for many calls to [`select`][sel], as its statistics are
+/// [`reset`][reset] at the start of each search.
+///
 /// # Examples
 ///
 /// ```rust
@@ -513,15 +1963,25 @@ impl RunCondition for Depth {
 /// assert!(logger.duration() < Duration::from_secs(1));
 /// ```
 /// [sel]: alpha_beta/struct.Bot.html#method.select
-pub struct Logger<T: IntoRunCondition> {
+/// [reset]: struct.Logger.html#method.reset
+#[cfg(feature = "std")]
+pub struct Logger<T: IntoRunCondition<F>, F> {
     condition: T::RunCondition,
     steps: u32,
     depth: u32,
     completed: bool,
     duration: Duration,
+    depth_durations: Vec<Duration>,
+    score_history: Vec<(u32, F)>,
+    best_fitness: Option<F>,
+    pv_len: usize,
+    stable_depths: u32,
+    cutoffs: u32,
+    leaves: u32,
 }
 
-impl<T: IntoRunCondition> Debug for Logger<T>
+#[cfg(feature = "std")]
+impl<T: IntoRunCondition<F>, F: Debug> Debug for Logger<T, F>
 where
     T::RunCondition: Debug,
 {
@@ -532,11 +1992,19 @@ where
             .field("depth", &self.depth)
             .field("completed", &self.completed)
             .field("duration", &self.duration)
+            .field("depth_durations", &self.depth_durations)
+            .field("score_history", &self.score_history)
+            .field("best_fitness", &self.best_fitness)
+            .field("pv_len", &self.pv_len)
+            .field("stable_depths", &self.stable_depths)
+            .field("cutoffs", &self.cutoffs)
+            .field("leaves", &self.leaves)
             .finish()
     }
 }
 
-impl<T: IntoRunCondition> Logger<T> {
+#[cfg(feature = "std")]
+impl<T: IntoRunCondition<F>, F> Logger<T, F> {
     /// Creates a new `Logger` wrapping `condition`.
     pub fn new(condition: T) -> Self {
         Self {
@@ -545,6 +2013,13 @@ impl<T: IntoRunCondition> Logger<T> {
             depth: 0,
             completed: true,
             duration: Duration::from_secs(0),
+            depth_durations: Vec::new(),
+            score_history: Vec::new(),
+            best_fitness: None,
+            pv_len: 0,
+            stable_depths: 0,
+            cutoffs: 0,
+            leaves: 0,
         }
     }
 
@@ -578,6 +2053,223 @@ impl<T: IntoRunCondition> Logger<T> {
         self.duration
     }
 
+    /// Returns the time spent on each depth completed during the last call to [`select`][sel],
+    /// starting with the first completed depth.
+    ///
+    /// As the time needed usually grows exponentially with each depth, this can be used
+    /// to estimate whether another depth would fit into the available time.
+    ///
+    /// ```rust
+    /// # use rubot::{Bot, tree::Node, Depth, Logger};
+    /// # use std::time::Duration;
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 1).with_children(&[
+    ///         Node::new(true, 3).with_children(&[Node::new(false, 2)]),
+    ///     ]),
+    ///     Node::new(false, 2).with_children(&[
+    ///         Node::new(true, 0).with_children(&[Node::new(false, 6)]),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut logger = Logger::new(Depth(3));
+    /// Bot::new(true).select(&tree, &mut logger);
+    ///
+    /// let durations = logger.depth_durations();
+    /// assert_eq!(durations.len(), 3);
+    /// // A rough estimate, assuming that the next depth takes as long as all previous ones combined.
+    /// let next: Duration = durations.iter().sum();
+    /// assert!(logger.duration() >= next);
+    /// ```
+    ///
+    /// [sel]: alpha_beta/struct.Bot.html#method.select
+    pub fn depth_durations(&self) -> &[Duration] {
+        &self.depth_durations
+    }
+
+    /// Returns each depth completed during the last call to [`select`][sel],
+    /// together with the fitness of the best action after this depth.
+    ///
+    /// This shows how the evaluation changed with increasing depth.
+    ///
+    /// ```rust
+    /// # use rubot::{Bot, tree::Node, Depth, Logger};
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 2).with_children(&[
+    ///         Node::new(true, 6).with_children(&[Node::new(false, 1)]),
+    ///     ]),
+    ///     Node::new(false, 4).with_children(&[
+    ///         Node::new(true, 3).with_children(&[Node::new(false, 3)]),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut logger = Logger::new(Depth(3));
+    /// Bot::new(true).select(&tree, &mut logger);
+    /// // `[0]` only looks good until the following actions are considered.
+    /// assert_eq!(logger.score_history(), &[(0, 6), (1, 3), (2, 3)]);
+    /// ```
+    ///
+    /// [sel]: alpha_beta/struct.Bot.html#method.select
+    pub fn score_history(&self) -> &[(u32, F)] {
+        &self.score_history
+    }
+
+    /// Returns the fitness of the action returned by the last call to [`select`][sel].
+    ///
+    /// This is `None` if no action was returned.
+    ///
+    /// ```rust
+    /// # use rubot::{Bot, tree::Node, ToCompletion, Logger};
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 4).with_children(&[
+    ///         Node::new(true, -3),
+    ///     ]),
+    ///     Node::new(false, 2).with_children(&[
+    ///         Node::new(true, 2),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut logger = Logger::new(ToCompletion);
+    /// assert_eq!(Bot::new(true).select(&tree, &mut logger), Some(1));
+    /// assert_eq!(logger.best_fitness(), Some(2));
+    /// assert_eq!(logger.pv_len(), 2);
+    /// ```
+    ///
+    /// [sel]: alpha_beta/struct.Bot.html#method.select
+    pub fn best_fitness(&self) -> Option<F>
+    where
+        F: Copy,
+    {
+        self.best_fitness
+    }
+
+    /// Returns the length of the principal variation of the action returned by the last call to [`select`][sel],
+    /// which is `0` if no action was returned.
+    ///
+    /// [sel]: alpha_beta/struct.Bot.html#method.select
+    pub fn pv_len(&self) -> usize {
+        self.pv_len
+    }
+
+    /// Returns the amount of consecutive completed depths of the last call to [`select`][sel]
+    /// after which the best action was the same as after the deepest completed depth,
+    /// see [`RunCondition::stable_depths`][sd].
+    ///
+    /// This is `0` if no depth was completed.
+    ///
+    /// ```rust
+    /// # use rubot::{Bot, tree::Node, Depth, Logger};
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 2).with_children(&[
+    ///         Node::new(true, 6).with_children(&[Node::new(false, 1)]),
+    ///     ]),
+    ///     Node::new(false, 4).with_children(&[
+    ///         Node::new(true, 3).with_children(&[Node::new(false, 3)]),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut logger = Logger::new(Depth(3));
+    /// Bot::new(true).select(&tree, &mut logger);
+    /// // `[1]` is the best action after depth 1 and 2, but not after depth 0.
+    /// assert_eq!(logger.stable_depths(), 2);
+    /// ```
+    ///
+    /// [sel]: alpha_beta/struct.Bot.html#method.select
+    /// [sd]: trait.RunCondition.html#method.stable_depths
+    pub fn stable_depths(&self) -> u32 {
+        self.stable_depths
+    }
+
+    /// Returns the amount of beta cutoffs during the last call to [`select`][sel],
+    /// see [`RunCondition::cutoff`][cutoff].
+    ///
+    /// A better move ordering causes cutoffs earlier, which reduces the amount of [`steps`][steps]
+    /// needed for the same depth.
+    ///
+    /// [sel]: alpha_beta/struct.Bot.html#method.select
+    /// [cutoff]: trait.RunCondition.html#method.cutoff
+    /// [steps]: struct.Logger.html#method.steps
+    pub fn cutoffs(&self) -> u32 {
+        self.cutoffs
+    }
+
+    /// Returns the amount of states evaluated at the lowest depth during the last call
+    /// to [`select`][sel], see [`RunCondition::leaf`][leaf].
+    ///
+    /// ```rust
+    /// # use rubot::{Bot, tree::Node, Depth, Logger};
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 3).with_children(&[
+    ///         Node::new(true, 3).with_children(&[Node::new(false, 5)]),
+    ///         Node::new(true, 1).with_children(&[Node::new(false, 0)]),
+    ///         Node::new(true, 6).with_children(&[Node::new(false, 6)]),
+    ///     ]),
+    ///     Node::new(false, 1).with_children(&[
+    ///         Node::new(true, 2).with_children(&[Node::new(false, 2)]),
+    ///         Node::new(true, 4).with_children(&[Node::new(false, 4)]),
+    ///     ]),
+    /// ]);
+    ///
+    /// let mut logger = Logger::new(Depth(2));
+    /// assert_eq!(Bot::new(true).select(&tree, &mut logger), Some(1));
+    /// assert!(logger.leaves() > 0);
+    /// assert!(logger.cutoffs() > 0);
+    ///
+    /// // The effective branching factor of the search.
+    /// let branching_factor = f64::from(logger.steps()).powf(1.0 / f64::from(logger.depth()));
+    /// assert!(branching_factor > 1.0);
+    /// ```
+    ///
+    /// [sel]: alpha_beta/struct.Bot.html#method.select
+    /// [leaf]: trait.RunCondition.html#method.leaf
+    pub fn leaves(&self) -> u32 {
+        self.leaves
+    }
+
+    /// Restores all statistics of this `Logger` to their initial state
+    /// while keeping the already allocated memory.
+    ///
+    /// This is done automatically at the start of each search.
+    ///
+    /// ```rust
+    /// # use rubot::{Bot, tree::Node, Steps, Logger};
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 5).with_children(&[
+    ///         Node::new(true, 0),
+    ///         Node::new(true, 9),
+    ///     ]),
+    ///     Node::new(false, 2),
+    /// ]);
+    ///
+    /// let mut logger = Logger::new(Steps(3));
+    /// Bot::new(true).select(&tree, &mut logger);
+    /// assert!(!logger.completed());
+    ///
+    /// logger.reset();
+    /// assert!(logger.completed());
+    /// assert_eq!(logger.steps(), 0);
+    /// assert_eq!(logger.cutoffs(), 0);
+    /// assert!(logger.depth_durations().is_empty());
+    /// ```
+    pub fn reset(&mut self) {
+        self.steps = 0;
+        self.depth = 0;
+        self.completed = true;
+        self.duration = Duration::from_secs(0);
+        self.depth_durations.clear();
+        self.score_history.clear();
+        self.best_fitness = None;
+        self.pv_len = 0;
+        self.stable_depths = 0;
+        self.cutoffs = 0;
+        self.leaves = 0;
+    }
+
     /// consumes `self` and returns the wrapped `condition`
     pub fn into_inner(self) -> T::RunCondition {
         self.condition
@@ -589,19 +2281,27 @@ impl<T: IntoRunCondition> Logger<T> {
 /// [rc]: trait.RunCondition.html
 /// [logger]: struct.Logger.html
 #[doc(hidden)]
-pub struct InnerLogger<'a, T: IntoRunCondition>(&'a mut Logger<T>, Instant);
+#[cfg(feature = "std")]
+pub struct InnerLogger<'a, T: IntoRunCondition<F>, F>(
+    &'a mut Logger<T, F>,
+    Instant,
+    Option<(u32, Instant)>,
+);
 
-impl<'a, T: IntoRunCondition> IntoRunCondition for &'a mut Logger<T> {
-    type RunCondition = InnerLogger<'a, T>;
+#[cfg(feature = "std")]
+impl<'a, T: IntoRunCondition<F>, F: Copy> IntoRunCondition<F> for &'a mut Logger<T, F> {
+    type RunCondition = InnerLogger<'a, T, F>;
 
-    fn into_run_condition(self) -> InnerLogger<'a, T> {
-        self.steps = 0;
-        self.depth = 0;
-        InnerLogger(self, Instant::now())
+    fn into_run_condition(self) -> InnerLogger<'a, T, F> {
+        self.reset();
+        InnerLogger(self, Instant::now(), None)
     }
 }
 
-impl<'a, T: IntoRunCondition> RunCondition for InnerLogger<'a, T> {
+#[cfg(feature = "std")]
+impl<'a, T: IntoRunCondition<F>, F: Copy> RunCondition for InnerLogger<'a, T, F> {
+    type Fitness = F;
+
     #[inline]
     fn step(&mut self) -> bool {
         self.0.steps += 1;
@@ -615,20 +2315,284 @@ impl<'a, T: IntoRunCondition> RunCondition for InnerLogger<'a, T> {
 
     #[inline]
     fn depth(&mut self, depth: u32) -> bool {
+        self.completed_depth(depth, None)
+    }
+
+    #[inline]
+    fn completed_depth(&mut self, depth: u32, best: Option<&Self::Fitness>) -> bool {
         self.0.depth = depth;
-        if self.0.condition.depth(depth) {
+        // The previous depth is completed once the next one starts.
+        let now = Instant::now();
+        if let Some((previous, start)) = self.2.replace((depth, now)) {
+            self.0.depth_durations.push(now - start);
+            if let Some(&fitness) = best {
+                self.0.score_history.push((previous, fitness));
+            }
+        }
+        if self.0.condition.completed_depth(depth, best) {
             true
         } else {
             self.0.completed = false;
             false
         }
     }
+
+    #[inline]
+    fn search_finished(&mut self, fitness: &Self::Fitness, pv_len: usize) {
+        self.0.best_fitness = Some(*fitness);
+        self.0.pv_len = pv_len;
+        self.0.condition.search_finished(fitness, pv_len);
+    }
+
+    #[inline]
+    fn stable_depths(&mut self, depths: u32) {
+        self.0.stable_depths = depths;
+        self.0.condition.stable_depths(depths);
+    }
+
+    #[inline]
+    fn cutoff(&mut self) {
+        self.0.cutoffs += 1;
+        self.0.condition.cutoff();
+    }
+
+    #[inline]
+    fn leaf(&mut self) {
+        self.0.leaves += 1;
+        self.0.condition.leaf();
+    }
 }
 
-impl<'a, T: IntoRunCondition> Drop for InnerLogger<'a, T> {
+#[cfg(feature = "std")]
+impl<'a, T: IntoRunCondition<F>, F> Drop for InnerLogger<'a, T, F> {
     fn drop(&mut self) {
         self.0.duration = self.1.elapsed();
     }
 }
 
+/// A floating point number with a total order, which can be used as [`Game::Fitness`][fit].
+///
+/// `NaN` is equal to itself and less than every other value, including negative infinity.
+/// Positive and negative infinity can be used to represent won and lost games, see
+/// [`is_upper_bound`][ub] and [`is_lower_bound`][lb].
+///
+/// # Examples
+///
+/// ```rust
+/// use rubot::{Bot, ToCompletion, Total};
+///
+/// #[derive(Clone)]
+/// struct Guess(Vec<f64>);
+///
+/// impl rubot::Game for Guess {
+///     type Player = ();
+///     type Action = usize;
+///     type Fitness = Total<f64>;
+///     type Actions = std::ops::Range<usize>;
+///
+///     fn actions(&self, _: Self::Player) -> (bool, Self::Actions) {
+///         (true, 0..self.0.len())
+///     }
+///
+///     fn execute(&mut self, &action: &Self::Action, _: Self::Player) -> Self::Fitness {
+///         let fitness = Total(self.0[action]);
+///         self.0.clear();
+///         fitness
+///     }
+///
+///     fn is_upper_bound(&self, fitness: Self::Fitness, _: Self::Player) -> bool {
+///         fitness.is_upper_bound()
+///     }
+///
+///     fn is_lower_bound(&self, fitness: Self::Fitness, _: Self::Player) -> bool {
+///         fitness.is_lower_bound()
+///     }
+/// }
+///
+/// let guess = Guess(vec![0.1, 0.25, f64::NAN, 0.2]);
+/// assert_eq!(Bot::new(()).select(&guess, ToCompletion), Some(1));
+/// ```
+///
+/// [fit]: trait.Game.html#associatedtype.Fitness
+/// [ub]: struct.Total.html#method.is_upper_bound
+/// [lb]: struct.Total.html#method.is_lower_bound
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Total<F>(pub F);
+
+macro_rules! impl_total {
+    ($($f:ident),*) => {$(
+        impl Total<$f> {
+            /// Returns `true` if `self` is positive infinity.
+            pub fn is_upper_bound(self) -> bool {
+                self.0 == $f::INFINITY
+            }
+
+            /// Returns `true` if `self` is negative infinity.
+            pub fn is_lower_bound(self) -> bool {
+                self.0 == $f::NEG_INFINITY
+            }
+        }
+
+        impl From<$f> for Total<$f> {
+            fn from(f: $f) -> Self {
+                Total(f)
+            }
+        }
+
+        impl From<Total<$f>> for $f {
+            fn from(total: Total<$f>) -> Self {
+                total.0
+            }
+        }
+
+        impl PartialEq for Total<$f> {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
+
+        impl Eq for Total<$f> {}
+
+        impl PartialOrd for Total<$f> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Total<$f> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                match (self.0.is_nan(), other.0.is_nan()) {
+                    (true, true) => Ordering::Equal,
+                    (true, false) => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    (false, false) => self.0.partial_cmp(&other.0).unwrap(),
+                }
+            }
+        }
+
+        impl Add for Total<$f> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Total(self.0 + other.0)
+            }
+        }
+
+        impl Sub for Total<$f> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Total(self.0 - other.0)
+            }
+        }
+    )*};
+}
+
+impl_total!(f32, f64);
+
+/// A fitness type with a smallest and a greatest value.
+///
+/// This is used by [`tree::Node`][node], where the greatest value is an [upper bound][ub]
+/// and the smallest value is a [lower bound][lb].
+///
+/// Games using a primitive integer as their fitness can use this trait
+/// to implement [`Game::is_upper_bound`][ub] and [`Game::is_lower_bound`][lb],
+/// while games with a custom fitness type are free to define their own bounds.
+///
+/// ```rust
+/// use rubot::{Bot, Bounded, Game, ToCompletion};
+///
+/// /// A game which ends after a single action, which immediately decides the fitness.
+/// #[derive(Clone)]
+/// struct Race {
+///     finished: bool,
+/// }
+///
+/// impl Game for Race {
+///     type Player = ();
+///     type Action = i32;
+///     type Fitness = i32;
+///     type Actions = Vec<i32>;
+///
+///     fn actions(&self, _: Self::Player) -> (bool, Self::Actions) {
+///         if self.finished {
+///             (true, Vec::new())
+///         } else {
+///             (true, vec![7, i32::MAX, -3])
+///         }
+///     }
+///
+///     fn execute(&mut self, action: &Self::Action, _: Self::Player) -> Self::Fitness {
+///         self.finished = true;
+///         *action
+///     }
+///
+///     fn is_upper_bound(&self, fitness: Self::Fitness, _: Self::Player) -> bool {
+///         fitness.is_upper_bound()
+///     }
+///
+///     fn is_lower_bound(&self, fitness: Self::Fitness, _: Self::Player) -> bool {
+///         fitness.is_lower_bound()
+///     }
+/// }
+///
+/// let race = Race { finished: false };
+/// assert_eq!(Bot::new(()).select(&race, ToCompletion), Some(i32::MAX));
+/// assert!(race.is_upper_bound(i32::MAX, ()));
+/// assert!(!race.is_lower_bound(-3, ()));
+/// ```
+///
+/// [node]: tree/struct.Node.html
+/// [ub]: trait.Game.html#method.is_upper_bound
+/// [lb]: trait.Game.html#method.is_lower_bound
+pub trait Bounded: Ord + Copy {
+    /// The smallest value of this type.
+    const MIN: Self;
+    /// The greatest value of this type.
+    const MAX: Self;
+
+    /// Returns `true` if `self` is the greatest value of this type.
+    #[inline]
+    fn is_upper_bound(self) -> bool {
+        self == Self::MAX
+    }
+
+    /// Returns `true` if `self` is the smallest value of this type.
+    #[inline]
+    fn is_lower_bound(self) -> bool {
+        self == Self::MIN
+    }
+
+    /// Returns the smallest difference between two values of this type, see [`Game::fitness_step`][fs].
+    ///
+    /// This is `1` for integers and `None` by default.
+    ///
+    /// [fs]: trait.Game.html#method.fitness_step
+    #[inline]
+    fn step() -> Option<Self> {
+        None
+    }
+}
+
+macro_rules! impl_bounded {
+    ($($t:ident),*) => {$(
+        impl Bounded for $t {
+            const MIN: Self = $t::MIN;
+            const MAX: Self = $t::MAX;
+
+            #[inline]
+            fn step() -> Option<Self> {
+                Some(1)
+            }
+        }
+    )*};
+}
+
+impl_bounded!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl Bounded for bool {
+    const MIN: Self = false;
+    const MAX: Self = true;
+}
+
 pub use alpha_beta::Bot;
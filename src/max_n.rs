@@ -0,0 +1,236 @@
+//! A game bot for games with more than two players using the [max^n][max_n] algorithm.
+//!
+//! [`alpha_beta::Bot`][ab] assumes that every other player tries to minimize its fitness, which is
+//! only correct for two player games. In games with more players, each player instead maximizes their own
+//! fitness, even if this also helps the bot. As this prevents most pruning, this bot analyses all actions.
+//!
+//! [max_n]: https://www.chessprogramming.org/Max%5En
+//! [ab]: ../alpha_beta/struct.Bot.html
+use crate::{IntoRunCondition, RunCondition};
+
+use alloc::{vec, vec::Vec};
+
+/// A game with any amount of players, where each player tries to maximize their own fitness.
+///
+/// Unlike [`Game`][game], actions return the fitness of all players at once.
+///
+/// # Examples
+///
+/// ```rust
+/// /// Three players take turns adding either `1` or `2` to their own counter until
+/// /// the total reaches `4`. Each player wants the highest counter.
+/// #[derive(Clone)]
+/// struct Counters([u8; 3], usize);
+///
+/// impl rubot::max_n::MultiplayerGame for Counters {
+///     type Player = usize;
+///     type Action = u8;
+///     type Fitness = u8;
+///     type Scores = [u8; 3];
+///     type Actions = Vec<u8>;
+///
+///     fn actions(&self) -> (usize, Vec<u8>) {
+///         let total: u8 = self.0.iter().sum();
+///         (self.1, (1..=2).filter(|&n| total + n <= 4).collect())
+///     }
+///
+///     fn execute(&mut self, &action: &u8) -> [u8; 3] {
+///         self.0[self.1] += action;
+///         self.1 = (self.1 + 1) % 3;
+///         self.0
+///     }
+///
+///     fn fitness(scores: &[u8; 3], player: usize) -> u8 {
+///         scores[player]
+///     }
+/// }
+///
+/// let mut bot = rubot::max_n::Bot::new(0);
+/// assert_eq!(bot.select(&Counters([0; 3], 0), rubot::ToCompletion), Some(2));
+/// ```
+///
+/// [game]: ../trait.Game.html
+pub trait MultiplayerGame: Clone {
+    /// The player type.
+    type Player: Copy + PartialEq;
+    /// An executable action.
+    type Action: PartialEq + Clone;
+    /// The fitness of a single player, higher values are better.
+    type Fitness: Ord + Copy;
+    /// The fitness of all players, for example an array with one entry per player.
+    type Scores: Clone;
+    /// The collection returned by [`actions`][ac].
+    ///
+    /// [ac]: trait.MultiplayerGame.html#tymethod.actions
+    type Actions: IntoIterator<Item = Self::Action>;
+
+    /// Returns the currently active player and all of their possible actions.
+    fn actions(&self) -> (Self::Player, Self::Actions);
+
+    /// Executes `action` for the active player, returning the resulting fitness of all players.
+    fn execute(&mut self, action: &Self::Action) -> Self::Scores;
+
+    /// Returns the fitness of `player` in `scores`.
+    fn fitness(scores: &Self::Scores, player: Self::Player) -> Self::Fitness;
+}
+
+/// A game bot using [max^n][max_n] with iterative deepening, see the [module level documentation][mod].
+///
+/// Among equally good actions, each player chooses the first one returned by [`MultiplayerGame::actions`][act].
+///
+/// [max_n]: https://www.chessprogramming.org/Max%5En
+/// [mod]: index.html
+/// [act]: trait.MultiplayerGame.html#tymethod.actions
+pub struct Bot<T: MultiplayerGame> {
+    player: T::Player,
+}
+
+/// The search was stopped by the `RunCondition`.
+struct CancelledError;
+
+/// The expected result of a state.
+struct Line<T: MultiplayerGame> {
+    scores: T::Scores,
+    /// The expected actions, stored in reverse order.
+    path: Vec<T::Action>,
+    /// Whether every analysed line reached the end of the game.
+    terminated: bool,
+}
+
+impl<T: MultiplayerGame> Bot<T> {
+    /// Creates a new `Bot` for the given `player`.
+    pub fn new(player: T::Player) -> Self {
+        Self { player }
+    }
+
+    /// Returns a chosen action based on the given game state.
+    ///
+    /// Returns `None` if no action is possible or the bot is currently not the active player.
+    ///
+    /// This method runs until either the whole game was analysed
+    /// or one of `RunCondition::depth` and `RunCondition::step` returned `false`.
+    /// In the latter case, the best action of the last completed depth is returned.
+    pub fn select<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Option<T::Action> {
+        let mut condition = condition.into_run_condition();
+
+        let (player, actions) = state.actions();
+        if player != self.player {
+            return None;
+        }
+
+        let children: Vec<_> = actions
+            .into_iter()
+            .map(|action| {
+                let mut state = state.clone();
+                let scores = state.execute(&action);
+                (state, action, scores)
+            })
+            .collect();
+
+        // Before completing any depth, we only know the immediate fitness of each action.
+        let mut best = self.best(
+            self.player,
+            children.iter().map(|(_, action, scores)| Line {
+                scores: scores.clone(),
+                path: vec![action.clone()],
+                terminated: false,
+            }),
+        )?;
+
+        for depth in 0.. {
+            let fitness = T::fitness(&best.scores, self.player);
+            let fitness = if depth > 0 { Some(&fitness) } else { None };
+            if best.terminated || !condition.completed_depth(depth, fitness) {
+                break;
+            }
+
+            let mut lines = Vec::with_capacity(children.len());
+            for (state, action, scores) in children.iter() {
+                match self.max_n(state, depth, &mut condition) {
+                    Ok(line) => lines.push(
+                        line.unwrap_or_else(|| Line {
+                            scores: scores.clone(),
+                            path: Vec::new(),
+                            terminated: true,
+                        })
+                        .with(action.clone()),
+                    ),
+                    Err(CancelledError) => break,
+                }
+            }
+
+            if lines.len() == children.len() {
+                best = self.best(self.player, lines.into_iter()).unwrap();
+            } else {
+                break;
+            }
+        }
+
+        condition.search_finished(&T::fitness(&best.scores, self.player), best.path.len());
+        best.path.pop()
+    }
+
+    /// Returns the best line for `player`, which is only terminated if all `lines` are terminated.
+    fn best(&self, player: T::Player, lines: impl Iterator<Item = Line<T>>) -> Option<Line<T>> {
+        let mut terminated = true;
+        let mut best: Option<Line<T>> = None;
+        for line in lines {
+            terminated &= line.terminated;
+            if best.as_ref().map_or(true, |best| {
+                T::fitness(&line.scores, player) > T::fitness(&best.scores, player)
+            }) {
+                best = Some(line);
+            }
+        }
+
+        best.map(|best| Line { terminated, ..best })
+    }
+
+    /// Returns the expected line of `state`, or `None` if there are no possible actions.
+    ///
+    /// At depth `0`, the active player selects the action with the best immediate fitness.
+    fn max_n<U: RunCondition<Fitness = T::Fitness>>(
+        &self,
+        state: &T,
+        depth: u32,
+        condition: &mut U,
+    ) -> Result<Option<Line<T>>, CancelledError> {
+        if !condition.step() {
+            return Err(CancelledError);
+        }
+
+        let (player, actions) = state.actions();
+        let mut lines = Vec::new();
+        for action in actions {
+            let mut state = state.clone();
+            let scores = state.execute(&action);
+            let line = if depth == 0 {
+                Line {
+                    scores,
+                    path: Vec::new(),
+                    terminated: false,
+                }
+            } else {
+                self.max_n(&state, depth - 1, condition)?.unwrap_or(Line {
+                    scores,
+                    path: Vec::new(),
+                    terminated: true,
+                })
+            };
+            lines.push(line.with(action));
+        }
+
+        Ok(self.best(player, lines.into_iter()))
+    }
+}
+
+impl<T: MultiplayerGame> Line<T> {
+    fn with(mut self, action: T::Action) -> Self {
+        self.path.push(action);
+        self
+    }
+}
@@ -0,0 +1,262 @@
+//! A game bot using [Monte Carlo tree search][mcts], which does not require a good fitness function.
+//!
+//! Instead of analysing every action up to a given depth, the bot repeatedly plays random games starting
+//! at the current state, focusing on actions which led to good results in previous games.
+//! The fitness at the end of each random game is used as a reward, which is maximized by the bot
+//! and minimized by its opponent. As rewards are averaged, the fitness is converted to `f64` using `Into`.
+//!
+//! A random game ends once there are no possible actions, the fitness is either an [upper][ub]
+//! or a [lower bound][lb], or the [rollout limit][rl] is reached.
+//!
+//! [mcts]: https://en.wikipedia.org/wiki/Monte_Carlo_tree_search
+//! [ub]: ../trait.Game.html#method.is_upper_bound
+//! [lb]: ../trait.Game.html#method.is_lower_bound
+//! [rl]: struct.Bot.html#method.set_rollout_limit
+use crate::{rng::XorShiftRng, Game, IntoRunCondition, RunCondition};
+
+use alloc::{vec, vec::Vec};
+
+/// A game bot using Monte Carlo tree search with [UCT][uct], see the [module level documentation][mod].
+///
+/// Each random game counts as one completed depth of the [`RunCondition`][rc], so [`Depth(n)`][depth] limits
+/// the bot to `n` random games. The search also stops once the complete game tree was analysed.
+/// For a given seed, the bot is deterministic.
+///
+/// # Examples
+///
+/// ```rust
+/// use rubot::{mcts::Bot, tree::Node};
+/// use std::time::Duration;
+///
+/// # #[rustfmt::skip]
+/// let tree = Node::root().with_children(&[
+///     Node::new(false, 7).with_children(&[
+///         Node::new(true, 4),
+///         Node::new(true, 2),
+///     ]),
+///     Node::new(false, 5).with_children(&[
+///         Node::new(true, 8),
+///         Node::new(true, 9)
+///     ]),
+/// ]);
+///
+/// let mut bot = Bot::new(true);
+/// assert_eq!(bot.select(&tree, Duration::from_secs(1)), Some(1));
+/// ```
+///
+/// [uct]: https://en.wikipedia.org/wiki/Monte_Carlo_tree_search#Exploration_and_exploitation
+/// [mod]: index.html
+/// [rc]: ../trait.RunCondition.html
+/// [depth]: ../struct.Depth.html
+pub struct Bot<T: Game> {
+    player: T::Player,
+    seed: u32,
+    exploration: f64,
+    rollout_limit: u32,
+}
+
+/// A state of the search tree.
+struct Node<T: Game> {
+    state: T,
+    /// Whether the bot is the active player.
+    active: bool,
+    /// The fitness after executing the action leading to this state.
+    fitness: f64,
+    /// Whether the game is over at this state.
+    terminal: bool,
+    /// Possible actions which were not yet added to `children`.
+    unexpanded: Vec<T::Action>,
+    /// The action leading to each child and its index in the tree.
+    children: Vec<(T::Action, usize)>,
+    visits: u32,
+    /// The sum of all rewards of random games going through this state.
+    reward: f64,
+    /// Whether the subtree of this state was completely added to the tree.
+    exhausted: bool,
+}
+
+impl<T: Game> Bot<T>
+where
+    T::Fitness: Into<f64>,
+{
+    /// Creates a new `Bot` for the given `player`.
+    pub fn new(player: T::Player) -> Self {
+        Self::with_seed(player, 0)
+    }
+
+    /// Creates a new `Bot` for the given `player`, which uses `seed` to choose random actions.
+    pub fn with_seed(player: T::Player, seed: u32) -> Self {
+        Self {
+            player,
+            seed,
+            exploration: core::f64::consts::SQRT_2,
+            rollout_limit: 1000,
+        }
+    }
+
+    /// Sets how much the bot explores rarely chosen actions instead of focusing on the currently best ones.
+    ///
+    /// The default is `sqrt(2)`, which works best if rewards are between `0` and `1`.
+    /// For rewards with a larger range, the exploration should be scaled accordingly.
+    pub fn set_exploration(&mut self, exploration: f64) {
+        self.exploration = exploration;
+    }
+
+    /// Sets the maximum amount of random actions in each random game, which is `1000` by default.
+    ///
+    /// The fitness after the last action is used as the reward, even if the game is not yet over.
+    pub fn set_rollout_limit(&mut self, limit: u32) {
+        self.rollout_limit = limit;
+    }
+
+    /// Returns a chosen action based on the given game state.
+    ///
+    /// Returns `None` if no action is possible or the bot is currently not the active player.
+    ///
+    /// This method runs until either the complete game tree was analysed
+    /// or one of `RunCondition::depth` and `RunCondition::step` returned `false`.
+    /// The action which was analysed the most is returned.
+    pub fn select<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Option<T::Action> {
+        let mut condition = condition.into_run_condition();
+        let mut rng = XorShiftRng::new(self.seed);
+
+        let mut tree = vec![self.node(state.clone(), 0.0, false)];
+        if !tree[0].active || tree[0].unexpanded.is_empty() {
+            return None;
+        }
+
+        let mut path = Vec::new();
+        for games in 0.. {
+            if tree[0].exhausted || !condition.depth(games) || !condition.step() {
+                break;
+            }
+
+            // Selection, use the most promising child until there are unexpanded actions.
+            let mut current = 0;
+            path.clear();
+            path.push(current);
+            while tree[current].unexpanded.is_empty() && !tree[current].children.is_empty() {
+                current = self.most_promising(&tree, current);
+                path.push(current);
+            }
+
+            // Expansion, add a random unexpanded action to the tree.
+            let node = &mut tree[current];
+            if !node.unexpanded.is_empty() {
+                let action = node
+                    .unexpanded
+                    .swap_remove(rng.next_u32() as usize % node.unexpanded.len());
+                let mut state = node.state.clone();
+                let fitness = state.execute(&action, self.player);
+                let terminal = state.is_upper_bound(fitness, self.player)
+                    || state.is_lower_bound(fitness, self.player);
+                let child = self.node(state, fitness.into(), terminal);
+
+                let idx = tree.len();
+                tree[current].children.push((action, idx));
+                tree.push(child);
+                current = idx;
+                path.push(current);
+            }
+
+            let reward = self.rollout(&tree[current], &mut rng);
+
+            // Backpropagation.
+            for &idx in path.iter().rev() {
+                let exhausted = {
+                    let node = &tree[idx];
+                    node.terminal
+                        || (node.unexpanded.is_empty()
+                            && node
+                                .children
+                                .iter()
+                                .all(|&(_, child)| tree[child].exhausted))
+                };
+                let node = &mut tree[idx];
+                node.visits += 1;
+                node.reward += reward;
+                node.exhausted = exhausted;
+            }
+        }
+
+        let root = &tree[0];
+        let (action, _) = root.children.iter().max_by(|&&(_, a), &&(_, b)| {
+            let (a, b) = (&tree[a], &tree[b]);
+            a.visits.cmp(&b.visits).then_with(|| {
+                (a.reward / f64::from(a.visits)).total_cmp(&(b.reward / f64::from(b.visits)))
+            })
+        })?;
+        Some(action.clone())
+    }
+
+    fn node(&self, state: T, fitness: f64, terminal: bool) -> Node<T> {
+        let (active, actions) = state.actions(self.player);
+        let unexpanded = if terminal {
+            Vec::new()
+        } else {
+            actions.into_iter().collect()
+        };
+        Node {
+            state,
+            active,
+            fitness,
+            terminal: terminal || unexpanded.is_empty(),
+            unexpanded,
+            children: Vec::new(),
+            visits: 0,
+            reward: 0.0,
+            exhausted: false,
+        }
+    }
+
+    /// Returns the child of `tree[idx]` with the highest upper confidence bound for the active player.
+    ///
+    /// Children whose subtree was already completely added to the tree are skipped.
+    fn most_promising(&self, tree: &[Node<T>], idx: usize) -> usize {
+        let node = &tree[idx];
+        let ln_visits = f64::from(node.visits).ln();
+        let ucb = |child: &Node<T>| {
+            let mean = child.reward / f64::from(child.visits);
+            let mean = if node.active { mean } else { -mean };
+            mean + self.exploration * (ln_visits / f64::from(child.visits)).sqrt()
+        };
+
+        node.children
+            .iter()
+            .map(|&(_, child)| child)
+            .filter(|&child| !tree[child].exhausted)
+            .max_by(|&a, &b| ucb(&tree[a]).total_cmp(&ucb(&tree[b])))
+            .unwrap()
+    }
+
+    /// Plays a random game starting at `node`, returning the reward at its end.
+    fn rollout(&self, node: &Node<T>, rng: &mut XorShiftRng) -> f64 {
+        if node.terminal {
+            return node.fitness;
+        }
+
+        let mut state = node.state.clone();
+        let mut reward = node.fitness;
+        for _ in 0..self.rollout_limit {
+            let (_, actions) = state.actions(self.player);
+            let mut actions: Vec<_> = actions.into_iter().collect();
+            if actions.is_empty() {
+                break;
+            }
+
+            let action = actions.swap_remove(rng.next_u32() as usize % actions.len());
+            let fitness = state.execute(&action, self.player);
+            reward = fitness.into();
+            if state.is_upper_bound(fitness, self.player)
+                || state.is_lower_bound(fitness, self.player)
+            {
+                break;
+            }
+        }
+        reward
+    }
+}
@@ -0,0 +1,256 @@
+//! A game bot for two player zero-sum games using [negamax][nm].
+//!
+//! In a zero-sum game, the fitness of one player is always the negated fitness of the other,
+//! so each state can be analysed from the perspective of its active player by maximizing the fitness.
+//! Whenever the active player changes, the fitness is negated using [`Neg`][neg] instead of switching
+//! between maximizing and minimizing. This requires a fitness type where negating is always possible,
+//! so `i8::MIN` is not a valid fitness for `i8`.
+//!
+//! Unlike [`alpha_beta::Bot`][ab], this bot does not use [`Game::is_upper_bound`][ub] and
+//! [`Game::is_lower_bound`][lb] and has no further options, which makes it easier to understand.
+//!
+//! [nm]: https://en.wikipedia.org/wiki/Negamax
+//! [neg]: https://doc.rust-lang.org/std/ops/trait.Neg.html
+//! [ab]: ../alpha_beta/struct.Bot.html
+//! [ub]: ../trait.Game.html#method.is_upper_bound
+//! [lb]: ../trait.Game.html#method.is_lower_bound
+use crate::{Game, IntoRunCondition, RunCondition};
+
+use alloc::vec::Vec;
+use core::ops::Neg;
+
+/// A game bot using negamax with alpha-beta pruning and iterative deepening,
+/// see the [module level documentation][mod].
+///
+/// # Examples
+///
+/// ```rust
+/// use rubot::{negamax::Bot, Game, ToCompletion};
+///
+/// /// Players take turns removing `1` or `2` stones, the player taking the last stone wins.
+/// #[derive(Clone)]
+/// struct Stones {
+///     stones: u8,
+///     first: bool,
+/// }
+///
+/// impl Game for Stones {
+///     type Player = bool;
+///     type Action = u8;
+///     type Fitness = i8;
+///     type Actions = Vec<u8>;
+///
+///     fn actions(&self, player: bool) -> (bool, Vec<u8>) {
+///         (player == self.first, (1..=2).filter(|&n| n <= self.stones).collect())
+///     }
+///
+///     fn execute(&mut self, &action: &u8, player: bool) -> i8 {
+///         self.stones -= action;
+///         self.first = !self.first;
+///         match (self.stones, player == self.first) {
+///             (0, true) => -1,
+///             (0, false) => 1,
+///             _ => 0,
+///         }
+///     }
+/// }
+///
+/// let state = Stones { stones: 4, first: true };
+/// assert_eq!(Bot::new(true).select(&state, ToCompletion), Some(1));
+/// ```
+///
+/// [mod]: index.html
+pub struct Bot<T: Game> {
+    player: T::Player,
+}
+
+/// The search was stopped by the `RunCondition`.
+struct CancelledError;
+
+/// The fitness of a state from the perspective of some player and whether
+/// every analysed line reached the end of the game.
+#[derive(Clone, Copy)]
+struct Value<F> {
+    fitness: F,
+    terminated: bool,
+}
+
+impl<F: Neg<Output = F>> Neg for Value<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Value {
+            fitness: -self.fitness,
+            terminated: self.terminated,
+        }
+    }
+}
+
+impl<T: Game> Bot<T>
+where
+    T::Fitness: Neg<Output = T::Fitness>,
+{
+    /// Creates a new `Bot` for the given `player`.
+    pub fn new(player: T::Player) -> Self {
+        Self { player }
+    }
+
+    /// Returns a chosen action based on the given game state.
+    ///
+    /// Returns `None` if no action is possible or the bot is currently not the active player.
+    ///
+    /// This method runs until either the whole game was analysed
+    /// or one of `RunCondition::depth` and `RunCondition::step` returned `false`.
+    /// In the latter case, the best action of the last completed depth is returned.
+    pub fn select<U: IntoRunCondition<T::Fitness>>(
+        &mut self,
+        state: &T,
+        condition: U,
+    ) -> Option<T::Action> {
+        let mut condition = condition.into_run_condition();
+
+        let (active, actions) = state.actions(self.player);
+        if !active {
+            return None;
+        }
+
+        let children: Vec<_> = actions
+            .into_iter()
+            .map(|action| {
+                let mut state = state.clone();
+                let fitness = state.execute(&action, self.player);
+                (state, action, fitness)
+            })
+            .collect();
+
+        // Before completing any depth, we only know the immediate fitness of each action.
+        let mut best = children.iter().fold(None, |best, (_, action, fitness)| {
+            let value = Value {
+                fitness: *fitness,
+                terminated: false,
+            };
+            better(best, (action, value))
+        });
+
+        for depth in 0.. {
+            let value = best?.1;
+            let fitness = if depth > 0 {
+                Some(&value.fitness)
+            } else {
+                None
+            };
+            if value.terminated || !condition.completed_depth(depth, fitness) {
+                break;
+            }
+
+            let mut new = None;
+            let mut terminated = true;
+            for (state, action, fitness) in children.iter() {
+                let alpha = new.map(|(_, value): (_, Value<_>)| value.fitness);
+                match self.negamax(state, depth, true, alpha, None, &mut condition) {
+                    Ok(value) => {
+                        let value = value.unwrap_or(Value {
+                            fitness: *fitness,
+                            terminated: true,
+                        });
+                        terminated &= value.terminated;
+                        new = better(new, (action, value));
+                    }
+                    Err(CancelledError) => return best.map(|(action, _)| action.clone()),
+                }
+            }
+
+            best = new.map(|(action, value)| {
+                (
+                    action,
+                    Value {
+                        terminated,
+                        ..value
+                    },
+                )
+            });
+        }
+
+        best.map(|(action, _)| action.clone())
+    }
+
+    /// Returns the fitness of `state` from the perspective of the bot if `perspective` and
+    /// of its opponent otherwise, or `None` if there are no possible actions.
+    ///
+    /// `alpha` and `beta` use the same perspective. Once an action of the active player
+    /// is at least as good as `beta`, the remaining actions are skipped.
+    /// At depth `0`, the active player selects the action with the best immediate fitness.
+    fn negamax<U: RunCondition<Fitness = T::Fitness>>(
+        &self,
+        state: &T,
+        depth: u32,
+        perspective: bool,
+        alpha: Option<T::Fitness>,
+        beta: Option<T::Fitness>,
+        condition: &mut U,
+    ) -> Result<Option<Value<T::Fitness>>, CancelledError> {
+        if !condition.step() {
+            return Err(CancelledError);
+        }
+
+        let (active, actions) = state.actions(self.player);
+        let (mut alpha, beta) = if active == perspective {
+            (alpha, beta)
+        } else {
+            (beta.map(Neg::neg), alpha.map(Neg::neg))
+        };
+
+        // `execute` returns the fitness of the bot, so it is negated for the opponent.
+        let relative = |fitness: T::Fitness| if active { fitness } else { -fitness };
+
+        let mut terminated = true;
+        let mut best: Option<T::Fitness> = None;
+        for action in actions {
+            let value = if depth == 0 {
+                Value {
+                    fitness: relative(state.look_ahead(&action, self.player)),
+                    terminated: false,
+                }
+            } else {
+                let mut state = state.clone();
+                let fitness = state.execute(&action, self.player);
+                self.negamax(&state, depth - 1, active, alpha, beta, condition)?
+                    .unwrap_or(Value {
+                        fitness: relative(fitness),
+                        terminated: true,
+                    })
+            };
+
+            terminated &= value.terminated;
+            if best.map_or(true, |best| value.fitness > best) {
+                best = Some(value.fitness);
+                if alpha.map_or(true, |alpha| value.fitness > alpha) {
+                    alpha = Some(value.fitness);
+                }
+                if beta.is_some_and(|beta| value.fitness >= beta) {
+                    break;
+                }
+            }
+        }
+
+        Ok(best.map(|fitness| {
+            let value = Value {
+                fitness,
+                terminated,
+            };
+            if active == perspective {
+                value
+            } else {
+                -value
+            }
+        }))
+    }
+}
+
+/// Returns `new` if it has a higher fitness than `best`.
+fn better<A, F: Ord>(best: Option<(A, Value<F>)>, new: (A, Value<F>)) -> Option<(A, Value<F>)> {
+    match best {
+        Some(best) if best.1.fitness >= new.1.fitness => Some(best),
+        _ => Some(new),
+    }
+}
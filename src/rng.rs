@@ -0,0 +1,35 @@
+//! A minimal seeded random number generator, used to avoid depending on `rand`.
+
+use core::num::Wrapping;
+
+/// A xorshift generator, which is deterministic for a given seed.
+pub(crate) struct XorShiftRng {
+    x: Wrapping<u32>,
+    y: Wrapping<u32>,
+    z: Wrapping<u32>,
+    w: Wrapping<u32>,
+}
+
+impl XorShiftRng {
+    pub(crate) fn new(seed: u32) -> Self {
+        // A xorshift generator must not be seeded with 0.
+        let seed = if seed == 0 { 0xBAD_5EED } else { seed };
+        XorShiftRng {
+            x: Wrapping(seed),
+            y: Wrapping(seed),
+            z: Wrapping(seed),
+            w: Wrapping(seed),
+        }
+    }
+
+    pub(crate) fn next_u32(&mut self) -> u32 {
+        let x = self.x;
+        let t = x ^ (x << 11);
+        self.x = self.y;
+        self.y = self.z;
+        self.z = self.w;
+        let w = self.w;
+        self.w = w ^ (w >> 19) ^ (t ^ (t >> 8));
+        self.w.0
+    }
+}
@@ -0,0 +1,194 @@
+//! A solver for single player puzzles, like mazes or sliding puzzles.
+//!
+//! Instead of selecting one action at a time, [`Bot::solve`][solve] returns the whole sequence
+//! of actions needed to reach a goal, which is a state whose fitness is an [upper bound][ub].
+//! As there is no opponent, there is no need to assume the worst and the search can stop
+//! as soon as the first goal is found.
+//!
+//! States are analysed in the order of the amount of actions needed to reach them, starting
+//! with the states with the better fitness. The first goal found therefore uses the fewest possible actions.
+//! If the game implements [`Game::key`][key], each state is only analysed the first time it is reached,
+//! which is required for games where the same state can be reached in different ways.
+//!
+//! [solve]: struct.Bot.html#method.solve
+//! [ub]: ../trait.Game.html#method.is_upper_bound
+//! [key]: ../trait.Game.html#method.key
+use crate::{Game, IntoRunCondition, RunCondition};
+
+use alloc::collections::BTreeSet;
+use alloc::{vec, vec::Vec};
+use core::cmp::Reverse;
+
+/// A solver for single player games, see the [module level documentation][mod].
+///
+/// # Examples
+///
+/// ```rust
+/// use rubot::{solver::Bot, Game, ToCompletion};
+///
+/// /// Reach `10` starting from `1` by either adding `1` or doubling the number.
+/// #[derive(Clone)]
+/// struct Double(u32);
+///
+/// impl Game for Double {
+///     type Player = ();
+///     type Action = bool;
+///     type Fitness = bool;
+///     type Actions = [bool; 2];
+///
+///     fn actions(&self, _: ()) -> (bool, [bool; 2]) {
+///         (true, [false, true])
+///     }
+///
+///     fn execute(&mut self, &double: &bool, _: ()) -> bool {
+///         self.0 = if double { self.0 * 2 } else { self.0 + 1 };
+///         self.0 == 10
+///     }
+///
+///     fn is_upper_bound(&self, fitness: bool, _: ()) -> bool {
+///         fitness
+///     }
+///
+///     fn is_lower_bound(&self, _: bool, _: ()) -> bool {
+///         self.0 > 10
+///     }
+///
+///     fn key(&self, _: ()) -> Option<u64> {
+///         Some(self.0.into())
+///     }
+/// }
+///
+/// // 1 -> 2 -> 4 -> 5 -> 10
+/// let actions = Bot::new(()).solve(&Double(1), ToCompletion).unwrap();
+/// assert_eq!(actions.len(), 4);
+/// assert_eq!(actions.last(), Some(&true));
+/// ```
+///
+/// [mod]: index.html
+pub struct Bot<T: Game> {
+    player: T::Player,
+}
+
+/// A state which has not been analysed yet.
+struct Open<T: Game> {
+    state: T,
+    /// The fitness after the last action, which is `None` for the initial state.
+    fitness: Option<T::Fitness>,
+    /// The index of the last action in `actions` of `Bot::solve`.
+    node: Option<usize>,
+}
+
+impl<T: Game> Bot<T> {
+    /// Creates a new `Bot` for the given `player`.
+    pub fn new(player: T::Player) -> Self {
+        Self { player }
+    }
+
+    /// Returns the shortest sequence of actions which reaches a goal, starting at `state`.
+    ///
+    /// A goal is a state whose fitness is an [upper bound][ub], while states whose
+    /// fitness is a [lower bound][lb] or which are [over][st] are not analysed further.
+    ///
+    /// This method runs until either a goal was found, every reachable state was analysed
+    /// or the `condition` stopped the search. Before analysing the states reached by `depth` actions,
+    /// [`RunCondition::depth`][depth] is called, so using [`Depth`][d]`(n)` only searches for
+    /// sequences of at most `n` actions.
+    ///
+    /// Returns `None` if no goal was found or the bot is currently not the active player.
+    ///
+    /// [ub]: ../trait.Game.html#method.is_upper_bound
+    /// [lb]: ../trait.Game.html#method.is_lower_bound
+    /// [st]: ../trait.Game.html#method.status
+    /// [depth]: ../trait.RunCondition.html#tymethod.depth
+    /// [d]: ../struct.Depth.html
+    pub fn solve<U: IntoRunCondition<T::Fitness>>(
+        &self,
+        state: &T,
+        condition: U,
+    ) -> Option<Vec<T::Action>> {
+        let mut condition = condition.into_run_condition();
+        // Each executed action together with the index of the previous one.
+        let mut actions: Vec<(Option<usize>, T::Action)> = Vec::new();
+        let mut seen = BTreeSet::new();
+        seen.extend(state.key(self.player));
+
+        let mut current = vec![Open {
+            state: state.clone(),
+            fitness: None,
+            node: None,
+        }];
+        for depth in 0.. {
+            if current.is_empty() || !condition.completed_depth(depth, None) {
+                break;
+            }
+
+            // Stable, so the order of states with an equal fitness stays deterministic.
+            current.sort_by_key(|open| Reverse(open.fitness));
+            let mut next = Vec::new();
+            for open in current {
+                if !condition.step() {
+                    return None;
+                }
+
+                if let Some((goal, fitness)) = self.expand(open, &mut actions, &mut seen, &mut next)
+                {
+                    let path = path(&actions, goal);
+                    condition.search_finished(&fitness, path.len());
+                    return Some(path);
+                }
+            }
+            current = next;
+        }
+
+        None
+    }
+
+    /// Adds all states reached by an action of `open` to `next`,
+    /// returning the node and fitness of a reached goal.
+    fn expand(
+        &self,
+        open: Open<T>,
+        actions: &mut Vec<(Option<usize>, T::Action)>,
+        seen: &mut BTreeSet<u64>,
+        next: &mut Vec<Open<T>>,
+    ) -> Option<(usize, T::Fitness)> {
+        let (active, possible) = open.state.actions(self.player);
+        if !active || open.state.status(self.player).is_over() {
+            return None;
+        }
+
+        for action in possible {
+            let mut state = open.state.clone();
+            let fitness = state.execute(&action, self.player);
+            if state.key(self.player).is_some_and(|key| !seen.insert(key)) {
+                continue;
+            }
+
+            let node = actions.len();
+            actions.push((open.node, action));
+            if state.is_upper_bound(fitness, self.player) {
+                return Some((node, fitness));
+            } else if !state.is_lower_bound(fitness, self.player) {
+                next.push(Open {
+                    state,
+                    fitness: Some(fitness),
+                    node: Some(node),
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Returns the actions leading to `node`.
+fn path<A: Clone>(actions: &[(Option<usize>, A)], node: usize) -> Vec<A> {
+    let mut path = Vec::new();
+    let mut node = Some(node);
+    while let Some((parent, action)) = node.map(|node| &actions[node]) {
+        path.push(action.clone());
+        node = *parent;
+    }
+    path.reverse();
+    path
+}
@@ -0,0 +1,86 @@
+//! Helpers to deterministically test time based [`RunCondition`s][rc].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use rubot::{Bot, Logger, tree::Node};
+//! use rubot::test_util::MockClock;
+//!
+//! # #[rustfmt::skip]
+//! let tree = Node::root().with_children(&[
+//!     Node::new(false, 7).with_children(&[
+//!         Node::new(true, 4),
+//!         Node::new(true, 2),
+//!     ]),
+//!     Node::new(false, 5).with_children(&[
+//!         Node::new(true, 8),
+//!         Node::new(true, 9)
+//!     ]),
+//! ]);
+//!
+//! // Allow exactly 5 calls to the run condition, which is enough to complete depth 0.
+//! let mut logger = Logger::new(MockClock::deadline(5));
+//! assert_eq!(Bot::new(true).select(&tree, &mut logger), Some(1));
+//! assert_eq!(logger.depth(), 1);
+//! assert!(!logger.completed());
+//! ```
+//!
+//! [rc]: ../trait.RunCondition.html
+use crate::{Clock, Deadline};
+
+use std::time::{Duration, Instant};
+
+/// A [`Clock`][clock] which advances by a fixed `tick` each time it is used.
+///
+/// As a [`Deadline`][deadline] checks the clock once during each call to [`RunCondition::step`][step]
+/// and [`RunCondition::depth`][depth], time is directly mapped to the amount of calls to the run condition.
+///
+/// [clock]: ../trait.Clock.html
+/// [deadline]: ../struct.Deadline.html
+/// [step]: ../trait.RunCondition.html#tymethod.step
+/// [depth]: ../trait.RunCondition.html#tymethod.depth
+#[derive(Clone, Copy, Debug)]
+pub struct MockClock {
+    now: Instant,
+    tick: Duration,
+    ticks: u32,
+}
+
+impl MockClock {
+    /// Creates a new `MockClock` which advances by `tick` each time it is used.
+    pub fn new(tick: Duration) -> Self {
+        Self {
+            now: Instant::now(),
+            tick,
+            ticks: 0,
+        }
+    }
+
+    /// Returns a [`Deadline`][deadline] which allows exactly `calls` calls to the run condition
+    /// before stopping the search.
+    ///
+    /// [deadline]: ../struct.Deadline.html
+    pub fn deadline(calls: u32) -> Deadline<MockClock> {
+        let tick = Duration::from_millis(1);
+        // Creating the deadline already advances the clock once.
+        Deadline::new(tick * (calls + 1), MockClock::new(tick))
+    }
+
+    /// Returns the total time this clock has advanced since it was created.
+    pub fn elapsed(&self) -> Duration {
+        self.tick * self.ticks
+    }
+
+    /// Returns how often this clock has been used since it was created.
+    pub fn ticks(&self) -> u32 {
+        self.ticks
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&mut self) -> Instant {
+        self.now += self.tick;
+        self.ticks += 1;
+        self.now
+    }
+}
@@ -26,3 +26,26 @@ fn allowed_actions_depth_zero() {
         }
     }
 }
+
+/// The principal variation results in the best fitness, which is also the fitness reported by `detailed_select`.
+#[test]
+fn principal_variation() {
+    for seed in 1..500u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 40).map(|i| seed.wrapping_mul(i * 17 + 3) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let mut brute = Brute::new(true);
+        let best = brute.best_fitness(&tree, u32::MAX);
+        let path = brute.principal_variation(&tree, u32::MAX);
+        assert_eq!(path.first().copied(), brute.select(&tree, u32::MAX));
+        assert!(brute.principal_variation(&tree, 1).len() <= 2);
+
+        let mut state = tree.clone();
+        let fitness = path.iter().map(|act| state.execute(act, true)).last();
+        assert_eq!(fitness, best, "tree: {:?}, path: {:?}", tree, path);
+
+        let detailed = Bot::new(true).detailed_select(&tree, ToCompletion);
+        assert_eq!(detailed.map(|act| act.fitness), best, "tree: {:?}", tree);
+    }
+}
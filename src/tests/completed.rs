@@ -9,6 +9,7 @@ fn empty() {
     let empty = Node::root();
 
     assert_eq!(Bot::new(true).select(&empty, ToCompletion), None);
+    check_pv(&empty, true);
 }
 
 /// Tests if the trivial case works
@@ -23,6 +24,7 @@ fn depth_one() {
 
     // Some(1) to love
     assert_eq!(Bot::new(true).select(&depth_one, ToCompletion), Some(1));
+    check_pv(&depth_one, true);
 }
 
 /// Tests if terminating nodes get ignored in case another branch is longer
@@ -41,6 +43,46 @@ fn different_depths() {
         Bot::new(true).select(&different_depths, ToCompletion),
         Some(1)
     );
+    check_pv(&different_depths, true);
+}
+
+/// `Node` works with fitness types other than `i8`, using their extremes as bounds.
+#[test]
+fn fitness_types() {
+    // Only the extremes are bounds, just like before `Node` was generic.
+    let root = Node::root();
+    assert!(root.is_upper_bound(i8::MAX, true) && root.is_lower_bound(i8::MIN, true));
+    assert!(!root.is_upper_bound(i8::MAX - 1, true) && !root.is_lower_bound(i8::MIN + 1, true));
+
+    #[rustfmt::skip]
+    let unsigned = Node::<u8>::new(true, 5).with_children(&[
+        Node::new(false, 3).with_children(&[
+            Node::new(true, 4),
+            Node::new(true, 0),
+        ]),
+        Node::new(false, 1),
+    ]);
+
+    assert_eq!(Bot::new(true).select(&unsigned, ToCompletion), Some(1));
+    check_pv(&unsigned, true);
+
+    #[rustfmt::skip]
+    let boolean = Node::<bool>::new(true, false).with_children(&[
+        Node::new(false, false).with_children(&[
+            Node::new(true, false).with_children(&[
+                Node::new(false, true),
+            ]),
+        ]),
+        Node::new(false, false).with_children(&[
+            Node::new(true, true),
+        ]),
+    ]);
+
+    let action = Bot::new(true)
+        .detailed_select(&boolean, ToCompletion)
+        .unwrap();
+    assert_eq!((action.fitness, action.path), (true, vec![1, 0]));
+    check_pv(&boolean, true);
 }
 
 /// This test tries to catch errors where alpha values are not removed after each depth,
@@ -63,6 +105,7 @@ fn alpha_reuse() {
     ]);
 
     assert_eq!(Bot::new(true).select(&alpha_reuse, ToCompletion), Some(0));
+    check_pv(&alpha_reuse, true);
 }
 
 /// Removing a branch which seems to terminate can be dangerous in case no deeper nodes are
@@ -101,6 +144,7 @@ fn premature_termination() {
         Bot::new(true).select(&premature_termination, ToCompletion),
         Some(1)
     );
+    check_pv(&premature_termination, true);
 }
 
 /// The world is weird.
@@ -130,6 +174,7 @@ fn fuzz_one() {
     ]);
 
     assert_eq!(Bot::new(true).select(&fuzz_one, ToCompletion), Some(0));
+    check_pv(&fuzz_one, true);
 }
 
 /// error due to incorrect interpretation of the cutoff
@@ -158,6 +203,7 @@ fn fuzz_two() {
     ]);
 
     assert_eq!(Bot::new(true).select(&fuzz_two, ToCompletion), Some(0));
+    check_pv(&fuzz_two, true);
 }
 
 #[test]
@@ -186,6 +232,7 @@ fn fuzz_three() {
     ]);
 
     assert_eq!(Bot::new(true).select(&fuzz_three, ToCompletion), Some(1));
+    check_pv(&fuzz_three, true);
 }
 
 #[test]
@@ -203,6 +250,7 @@ fn fuzz_four() {
     ]);
 
     assert_eq!(Bot::new(true).select(&fuzz_four, ToCompletion), Some(0));
+    check_pv(&fuzz_four, true);
 }
 
 /// There was a time where we handled bounds wrong. We used `alpha` and `beta`
@@ -222,6 +270,7 @@ fn fuzz_five() {
     ]);
 
     assert_eq!(Bot::new(true).select(&fuzz_five, ToCompletion), Some(1));
+    check_pv(&fuzz_five, true);
 }
 
 /// Tests for a bug which caused [0] to always return Terminated(Worse(1)), as [0][1][1]
@@ -246,6 +295,7 @@ fn subtree_cutoff() {
         Bot::new(true).select(&subtree_cutoff, ToCompletion),
         Some(0)
     );
+    check_pv(&subtree_cutoff, true);
 }
 
 // In case all actions are worse than the lower bound, return the action taking the
@@ -260,6 +310,281 @@ fn with_my_dying_breath() {
         Node::new(true, i8::MIN),
     ]);
     assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(0));
+    check_pv(&tree, true);
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(true, 0).with_children(&[
+            Node::new(true, i8::MIN),
+        ]),
+        Node::new(true, 0).with_children(&[
+            Node::new(true, 0).with_children(&[
+                Node::new(true, i8::MIN),
+            ]),
+        ]),
+        Node::new(true, i8::MIN),
+    ]);
+    assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(1));
+    check_pv(&tree, true);
+}
+
+/// The opponent is able to avoid the win at [1][0] and [2] only wins after 3 plies.
+#[test]
+fn forced_win_in() {
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 0).with_children(&[
+            Node::new(true, i8::MAX),
+            Node::new(true, 0),
+        ]),
+        Node::new(true, 0).with_children(&[
+            Node::new(false, 3).with_children(&[
+                Node::new(true, i8::MAX),
+            ]),
+        ]),
+    ]);
+
+    let mut bot = Bot::new(true);
+    assert_eq!(bot.forced_win_in(&tree, 2), None);
+    assert_eq!(bot.forced_win_in(&tree, 3), Some(3));
+    assert_eq!(bot.forced_win_in(&tree, 10), Some(3));
+    assert_eq!(bot.forced_win_in(&tree, 0), None);
+    assert_eq!(bot.forced_win_in(&Node::root(), 3), None);
+}
+
+/// A single player game where waiting and advancing are equally good,
+/// unless repetitions are penalized.
+#[test]
+fn repetition_penalty() {
+    #[derive(Clone)]
+    struct Shuffle {
+        position: u8,
+        turns: u8,
+    }
+
+    impl crate::Game for Shuffle {
+        type Player = ();
+        type Action = bool;
+        type Fitness = i8;
+        type Actions = Vec<bool>;
+
+        fn actions(&self, _: Self::Player) -> (bool, Self::Actions) {
+            if self.turns < 3 {
+                (true, vec![false, true])
+            } else {
+                (true, Vec::new())
+            }
+        }
+
+        fn execute(&mut self, &advance: &bool, _: Self::Player) -> Self::Fitness {
+            self.turns += 1;
+            self.position += advance as u8;
+            0
+        }
+
+        fn key(&self, _: Self::Player) -> Option<u64> {
+            Some(self.position.into())
+        }
+    }
+
+    let state = Shuffle {
+        position: 0,
+        turns: 0,
+    };
+
+    let mut bot = Bot::new(());
+    assert_eq!(bot.solve(&state, 10), Some(0));
+
+    bot.set_repetition_penalty(Some(1));
+    assert_eq!(bot.select(&state, ToCompletion), Some(true));
+    assert_eq!(
+        bot.detailed_select(&state, ToCompletion).unwrap().path,
+        &[true, true, true]
+    );
+    assert_eq!(bot.solve(&state, 10), Some(0));
+
+    bot.set_repetition_penalty(None);
+    assert_eq!(bot.solve(&state, 10), Some(0));
+}
+
+/// A single player game which can be restarted at any point, so it only terminates
+/// if repetitions are treated as a draw.
+#[test]
+fn repetition_draw() {
+    #[derive(Clone)]
+    struct Restart {
+        position: u8,
+        end: i8,
+    }
+
+    impl crate::Game for Restart {
+        type Player = ();
+        type Action = bool;
+        type Fitness = i8;
+        type Actions = Vec<bool>;
+
+        fn actions(&self, _: Self::Player) -> (bool, Self::Actions) {
+            if self.position < 2 {
+                (true, vec![false, true])
+            } else {
+                (true, Vec::new())
+            }
+        }
+
+        fn execute(&mut self, &advance: &bool, _: Self::Player) -> Self::Fitness {
+            if advance {
+                self.position += 1;
+            } else {
+                self.position = 0;
+            }
+
+            if self.position == 2 {
+                self.end
+            } else {
+                0
+            }
+        }
+
+        fn key(&self, _: Self::Player) -> Option<u64> {
+            Some(self.position.into())
+        }
+    }
+
+    let state = Restart {
+        position: 0,
+        end: -1,
+    };
+
+    let mut bot = Bot::new(());
+    bot.set_repetition_draw(Some(0));
+    let action = bot.detailed_select(&state, ToCompletion).unwrap();
+    assert_eq!((action.fitness, action.path[0]), (0, false));
+
+    bot.set_repetition_draw(Some(-2));
+    let action = bot.detailed_select(&state, ToCompletion).unwrap();
+    assert_eq!((action.fitness, action.path), (-1, vec![true, true]));
+
+    bot.set_repetition_draw(Some(0));
+    bot.set_contempt(Some(2));
+    let action = bot.detailed_select(&state, ToCompletion).unwrap();
+    assert_eq!((action.fitness, action.path), (-1, vec![true, true]));
+
+    let state = Restart {
+        position: 0,
+        end: 1,
+    };
+    bot.set_contempt(Some(-2));
+    let action = bot.detailed_select(&state, ToCompletion).unwrap();
+    assert_eq!(action.fitness, 2);
+}
+
+/// Drawn states are avoided with a positive contempt and preferred with a negative one.
+#[test]
+fn contempt() {
+    /// A `Node` where all states with a fitness of `0` are a draw.
+    #[derive(Clone)]
+    struct Drawish(Node, i8);
+
+    impl crate::Game for Drawish {
+        type Player = bool;
+        type Action = usize;
+        type Fitness = i8;
+        type Actions = std::ops::Range<usize>;
+
+        fn actions(&self, player: bool) -> (bool, Self::Actions) {
+            self.0.actions(player)
+        }
+
+        fn execute(&mut self, action: &usize, player: bool) -> i8 {
+            self.1 = self.0.execute(action, player);
+            self.1
+        }
+
+        fn is_draw(&self, _: bool) -> bool {
+            self.1 == 0
+        }
+    }
+
+    #[rustfmt::skip]
+    let tree = Drawish(Node::root().with_children(&[
+        Node::new(false, -2),
+        Node::new(false, 0),
+        Node::new(false, 1).with_children(&[
+            Node::new(true, -1),
+        ]),
+    ]), 1);
+
+    let mut bot = Bot::new(true);
+    assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+
+    bot.set_contempt(Some(2));
+    assert_eq!(bot.select(&tree, ToCompletion), Some(2));
+    assert_eq!(
+        bot.detailed_select(&tree, ToCompletion).unwrap().fitness,
+        -1
+    );
+
+    bot.set_contempt(Some(-2));
+    assert_eq!(bot.detailed_select(&tree, ToCompletion).unwrap().fitness, 2);
+
+    bot.set_contempt(None);
+    assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+}
+
+/// Bounds marked using `Node::upper_bound` and `Node::lower_bound` are used by the bot.
+#[test]
+fn node_bounds() {
+    use crate::alpha_beta::TerminalKind;
+    use crate::tree::Marked;
+    use std::sync::mpsc;
+
+    let node = |player, fitness| Node::new(player, Marked::new(fitness));
+
+    // The bot trusts the upper bound, even though the other action has a greater fitness.
+    #[rustfmt::skip]
+    let tree = node(true, 0).with_children(&[
+        node(false, 3).upper_bound(),
+        node(false, 7),
+    ]);
+    assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(0));
+
+    #[rustfmt::skip]
+    let tree = node(true, 0).with_children(&[
+        node(false, -3).lower_bound(),
+        node(false, 0).with_children(&[
+            node(true, -3).lower_bound(),
+        ]),
+    ]);
+
+    let (sender, receiver) = mpsc::channel();
+    let mut bot = Bot::new(true);
+    bot.on_terminal_found(move |kind, action| sender.send((kind, action.path.clone())).unwrap());
+    assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+    assert_eq!(
+        receiver.try_iter().collect::<Vec<_>>(),
+        &[(TerminalKind::Loss, vec![1, 0])]
+    );
+
+    // Only the marked node at [1][0] is an upper bound, not the unmarked one with the same value.
+    #[rustfmt::skip]
+    let tree = node(true, 0).with_children(&[
+        node(false, 5).with_children(&[
+            node(true, 1),
+        ]),
+        node(false, 3).with_children(&[
+            node(true, 5).upper_bound(),
+        ]),
+    ]);
+    assert_eq!(Bot::new(true).forced_win_in(&tree, 3), Some(2));
+    assert!(!tree.is_upper_bound(Marked::new(5), true));
+    assert!(tree.to_dot().contains("4 [label=\"true: 5+\"]"));
+}
+
+/// All actions are lost, so `on_terminal_found` should report the longest losing line.
+#[test]
+fn terminal_found_loss() {
+    use crate::alpha_beta::TerminalKind;
+    use std::sync::mpsc;
 
     #[rustfmt::skip]
     let tree = Node::root().with_children(&[
@@ -273,5 +598,1820 @@ fn with_my_dying_breath() {
         ]),
         Node::new(true, i8::MIN),
     ]);
+
+    let (sender, receiver) = mpsc::channel();
+    let mut bot = Bot::new(true);
+    bot.on_terminal_found(move |kind, action| sender.send((kind, action.path.clone())).unwrap());
+
+    assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+    assert_eq!(
+        receiver.try_iter().collect::<Vec<_>>(),
+        &[(TerminalKind::Loss, vec![1, 0, 0])]
+    );
+
+    // The search is cancelled before the game is decided.
+    assert!(bot.select(&tree, Depth(0)).is_some());
+    assert_eq!(receiver.try_iter().count(), 0);
+}
+
+/// Only [0] and [1] are considered at the root, but their consequences are still fully analysed.
+#[test]
+fn root_action_limit() {
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 3).with_children(&[
+            Node::new(true, 1),
+        ]),
+        Node::new(false, 2).with_children(&[
+            Node::new(true, 6),
+        ]),
+        Node::new(false, 0).with_children(&[
+            Node::new(true, 9),
+        ]),
+    ]);
+
+    assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(2));
+    let mut bot = Bot::with_root_action_limit(true, 2);
+    assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+    assert_eq!(bot.solve(&tree, 10), Some(6));
+}
+
+/// [1] is a dead end with the lowest fitness, while [2] is cut off at [2][1].
+#[test]
+fn select_with_range() {
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(true, 3),
+        Node::new(true, -5),
+        Node::new(false, 4).with_children(&[
+            Node::new(true, 6),
+            Node::new(true, 1),
+        ]),
+    ]);
+
+    let mut bot = Bot::new(true);
+    assert_eq!(bot.select_with_range(&tree, ToCompletion), Some((0, 3, -5)));
+    assert_eq!(bot.select_with_range(&Node::root(), ToCompletion), None);
+}
+
+/// Disabling root cutoffs must not change which actions are considered the best.
+#[test]
+fn no_root_cutoff() {
+    use crate::brute::Brute;
+
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 17 + 3) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let selected = Bot::new(true).select_no_root_cutoff(&tree, ToCompletion);
+        assert!(
+            Brute::new(true).check_if_best(&tree, selected.as_ref(), u32::MAX),
+            "tree: {:?}, selected: {:?}",
+            tree,
+            selected
+        );
+    }
+}
+
+/// The fitness of [1] is 3, regardless of the given line.
+#[test]
+fn verify_line() {
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(true, 4),
+        Node::new(false, 0).with_children(&[
+            Node::new(true, 6).with_children(&[
+                Node::new(false, 7),
+                Node::new(false, 3),
+            ]),
+            Node::new(true, 3).with_children(&[
+                Node::new(false, 1),
+                Node::new(false, 3),
+            ]),
+        ]),
+    ]);
+
+    let mut bot = Bot::new(true);
+    assert_eq!(bot.verify_line(&tree, &[1], ToCompletion), Some(3));
+    assert_eq!(bot.verify_line(&tree, &[1, 1], ToCompletion), Some(3));
+    assert_eq!(bot.verify_line(&tree, &[1, 1, 1], ToCompletion), Some(3));
+    assert_eq!(bot.verify_line(&tree, &[1, 0, 0], ToCompletion), Some(3));
+    assert_eq!(bot.verify_line(&tree, &[0], ToCompletion), Some(4));
+    assert_eq!(bot.verify_line(&tree, &[], ToCompletion), None);
+    assert_eq!(Bot::new(false).verify_line(&tree, &[0], ToCompletion), None);
+
+    // Without any search, only the initial fitness of [1] is known.
+    bot.set_max_depth(Some(0));
+    assert_eq!(bot.verify_line(&tree, &[1, 1, 1], ToCompletion), Some(0));
+}
+
+/// [0] looks decisive after depth 0, so the bot stops searching before noticing that [1] is better.
+#[test]
+fn is_decisive() {
+    #[derive(Clone)]
+    struct Decisive(Node);
+
+    impl crate::Game for Decisive {
+        type Player = bool;
+        type Action = usize;
+        type Fitness = i8;
+        type Actions = std::ops::Range<usize>;
+
+        fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+            self.0.actions(player)
+        }
+
+        fn execute(&mut self, action: &Self::Action, player: Self::Player) -> Self::Fitness {
+            self.0.execute(action, player)
+        }
+
+        fn is_decisive(&self, fitness: Self::Fitness, _: Self::Player) -> bool {
+            fitness >= 10
+        }
+    }
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(true, 0).with_children(&[
+            Node::new(true, 10).with_children(&[
+                Node::new(true, -5),
+            ]),
+        ]),
+        Node::new(true, 5),
+    ]);
+
     assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(1));
+
+    let decisive = Decisive(tree);
+    let mut bot = Bot::new(true);
+    assert_eq!(bot.select(&decisive, ToCompletion), Some(0));
+    assert_eq!(bot.solve(&decisive, 10), Some(5));
+}
+
+/// Using an order preserving `fitness_key` must not change the selected actions,
+/// while a key which is not order preserving is detected.
+#[test]
+fn fitness_key() {
+    #[derive(Clone)]
+    struct Keyed(Node, fn(i8) -> u64);
+
+    impl crate::Game for Keyed {
+        type Player = bool;
+        type Action = usize;
+        type Fitness = i8;
+        type Actions = std::ops::Range<usize>;
+
+        fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+            self.0.actions(player)
+        }
+
+        fn execute(&mut self, action: &Self::Action, player: Self::Player) -> Self::Fitness {
+            self.0.execute(action, player)
+        }
+
+        fn fitness_key(&self, fitness: Self::Fitness) -> u64 {
+            (self.1)(fitness)
+        }
+    }
+
+    for seed in 1..100u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 13 + 5) as u8));
+        let tree = Node::from_bytes(&bytes);
+        // Only distinguishes positive from negative fitness values, so there are a lot of ties.
+        let keyed = Keyed(tree.clone(), |fitness| (fitness >= 0) as u64);
+        assert_eq!(
+            Bot::new(true).select(&keyed, ToCompletion),
+            Bot::new(true).select(&tree, ToCompletion)
+        );
+    }
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(true, 0).with_children(&[
+            Node::new(true, 1),
+            Node::new(true, 2),
+        ]),
+    ]);
+    let reversed = Keyed(tree, |fitness| u64::from(u8::MAX - fitness as u8));
+    let result = std::panic::catch_unwind(|| Bot::new(true).select(&reversed, ToCompletion));
+    assert_eq!(result.is_err(), cfg!(debug_assertions));
+}
+
+/// Futility pruning only skips actions at the last analysed depth,
+/// so it can't change the result of a complete search.
+#[test]
+fn futility_margin() {
+    use crate::brute::Brute;
+
+    let mut pruned = false;
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 23 + 11) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let mut logger = Logger::new(ToCompletion);
+        let selected = Bot::with_futility_margin(true, 0).select(&tree, &mut logger);
+        assert!(
+            Brute::new(true).check_if_best(&tree, selected.as_ref(), u32::MAX),
+            "tree: {:?}, selected: {:?}",
+            tree,
+            selected
+        );
+
+        let mut unpruned = Logger::new(ToCompletion);
+        Bot::new(true).select(&tree, &mut unpruned);
+        pruned |= logger.steps() < unpruned.steps();
+    }
+
+    assert!(pruned, "futility pruning never skipped an action");
+}
+
+#[test]
+fn select_scored() {
+    for seed in 1..100u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 7 + 3) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let mut bot = Bot::new(true);
+        let detailed = bot.detailed_select(&tree, ToCompletion);
+        assert_eq!(
+            bot.select_scored(&tree, ToCompletion),
+            detailed.map(|act| (act.path[0], act.fitness)),
+            "tree: {:?}",
+            tree
+        );
+    }
+}
+
+#[test]
+fn logger_cutoffs() {
+    let mut cutoffs = false;
+    for seed in 1..100u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 13 + 5) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let mut inner = Logger::new(ToCompletion);
+        let counts = {
+            let mut logger = Logger::new(&mut inner);
+            Bot::new(true).select(&tree, &mut logger);
+            assert!(logger.cutoffs() <= logger.steps(), "tree: {:?}", tree);
+            assert!(logger.leaves() <= logger.steps(), "tree: {:?}", tree);
+            (logger.cutoffs(), logger.leaves())
+        };
+        cutoffs |= counts.0 > 0;
+        // Both hooks are forwarded to the wrapped condition.
+        assert_eq!((inner.cutoffs(), inner.leaves()), counts);
+    }
+
+    assert!(cutoffs, "no search had a cutoff");
+}
+
+#[test]
+fn explain() {
+    for seed in 1..100u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 11 + 13) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let explanation = Bot::new(true).explain(&tree, ToCompletion);
+        let detailed = Bot::new(true).detailed_select(&tree, ToCompletion);
+        let verdict = Bot::new(true).select_with_verdict(&tree, ToCompletion);
+        assert_eq!(
+            explanation
+                .as_ref()
+                .map(|e| (&e.action, &e.line[..], e.fitness)),
+            detailed
+                .as_ref()
+                .map(|a| (&a.path[0], &a.path[1..], a.fitness)),
+            "tree: {:?}",
+            tree
+        );
+        assert_eq!(
+            explanation.map(|e| (e.action, e.verdict)),
+            verdict,
+            "tree: {:?}",
+            tree
+        );
+    }
+}
+
+/// Principal variation search only changes which windows are used, so it must find the same fitness.
+#[test]
+fn principal_variation_search() {
+    use crate::brute::Brute;
+
+    let mut scouted = false;
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 23 + 11) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let mut logger = Logger::new(ToCompletion);
+        let selected =
+            Bot::with_principal_variation_search(true, 1).detailed_select(&tree, &mut logger);
+        assert!(
+            Brute::new(true).check_if_best(&tree, selected.as_ref().map(|a| &a.path[0]), u32::MAX),
+            "tree: {:?}, selected: {:?}",
+            tree,
+            selected
+        );
+
+        let mut unscouted = Logger::new(ToCompletion);
+        let expected = Bot::new(true).detailed_select(&tree, &mut unscouted);
+        let fitness = selected.map(|a| a.fitness);
+        assert_eq!(fitness, expected.map(|a| a.fitness), "tree: {:?}", tree);
+        assert_eq!(fitness, Brute::new(true).best_fitness(&tree, u32::MAX));
+        scouted |= logger.steps() != unscouted.steps();
+    }
+
+    assert!(
+        scouted,
+        "principal variation search never used a null window"
+    );
+}
+
+/// The settings of the different `Bot::with_*` constructors can be combined using their setters.
+#[test]
+fn combined_settings() {
+    use crate::alpha_beta::RetryOrder;
+    use crate::brute::Brute;
+
+    for seed in 1..200u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 29 + 5) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let mut bot = Bot::with_transposition_table(true, 64);
+        bot.set_principal_variation_search(Some(1));
+        bot.set_history_heuristic(true);
+        bot.set_partial_order(RetryOrder::Descending);
+        let selected = bot.detailed_select(&tree, ToCompletion);
+        assert!(
+            Brute::new(true).check_if_best(&tree, selected.as_ref().map(|a| &a.path[0]), u32::MAX),
+            "tree: {:?}, selected: {:?}",
+            tree,
+            selected
+        );
+    }
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 3).with_children(&[
+            Node::new(true, 1),
+        ]),
+        Node::new(false, 2).with_children(&[
+            Node::new(true, 6),
+        ]),
+    ]);
+
+    let mut bot = Bot::with_principal_variation_search(true, 1);
+    assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+    bot.set_root_action_limit(Some(1));
+    // Only `[0]` is considered, while still using principal variation search.
+    assert_eq!(bot.select(&tree, ToCompletion), Some(0));
+    bot.set_principal_variation_search(None);
+    bot.set_root_action_limit(None);
+    assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+}
+
+/// `Bot::with_fitness_step` behaves like principal variation search using `Game::fitness_step`.
+#[test]
+fn fitness_step() {
+    for seed in 1..100u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 19 + 7) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let mut logger = Logger::new(ToCompletion);
+        let selected = Bot::with_fitness_step(true).select(&tree, &mut logger);
+        let mut expected = Logger::new(ToCompletion);
+        let pvs = Bot::with_principal_variation_search(true, 1).select(&tree, &mut expected);
+        assert_eq!(selected, pvs, "tree: {:?}", tree);
+        assert_eq!(logger.steps(), expected.steps(), "tree: {:?}", tree);
+
+        // `Coarse` does not have a fitness step, so no null windows are used.
+        let coarse = Coarse(tree);
+        let selected = Bot::with_fitness_step(true).select(&coarse, &mut logger);
+        let plain = Bot::new(true).select(&coarse, &mut expected);
+        assert_eq!(selected, plain, "tree: {:?}", coarse.0);
+        assert_eq!(logger.steps(), expected.steps(), "tree: {:?}", coarse.0);
+    }
+}
+
+/// Trying killer moves first must not change the selected action.
+#[test]
+fn killer_moves() {
+    use crate::brute::Brute;
+
+    // `[0][1]` causes a cutoff, which makes `1` a killer move for the following states.
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 5).with_children(&[
+            Node::new(true, 9),
+            Node::new(true, 1),
+        ]),
+        Node::new(false, 6).with_children(&[
+            Node::new(true, 8),
+            Node::new(true, 0),
+        ]),
+        Node::new(false, 3).with_children(&[
+            Node::new(true, 2),
+            Node::new(true, 7),
+        ]),
+    ]);
+    assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(2));
+
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 60).map(|i| seed.wrapping_mul(i * 31 + 7) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let selected = Bot::new(true).select(&tree, ToCompletion);
+        assert!(
+            Brute::new(true).check_if_best(&tree, selected.as_ref(), u32::MAX),
+            "tree: {:?}, selected: {:?}",
+            tree,
+            selected
+        );
+    }
+}
+
+/// Sorting actions with the same fitness by how often they caused cutoffs must not change the result.
+#[test]
+fn history_heuristic() {
+    use crate::brute::Brute;
+
+    let mut reordered = false;
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 60).map(|i| seed.wrapping_mul(i * 31 + 7) as u8));
+        let tree = Coarse(Node::from_bytes(&bytes));
+
+        let mut logger = Logger::new(ToCompletion);
+        let selected = Bot::with_history_heuristic(true).detailed_select(&tree, &mut logger);
+        assert!(
+            Brute::new(true).check_if_best(&tree, selected.as_ref().map(|a| &a.path[0]), u32::MAX),
+            "tree: {:?}, selected: {:?}",
+            tree.0,
+            selected
+        );
+
+        let mut unordered = Logger::new(ToCompletion);
+        let expected = Bot::new(true).detailed_select(&tree, &mut unordered);
+        assert_eq!(
+            selected.map(|a| a.fitness),
+            expected.map(|a| a.fitness),
+            "tree: {:?}",
+            tree.0
+        );
+        reordered |= logger.steps() != unordered.steps();
+    }
+
+    assert!(
+        reordered,
+        "the history heuristic never changed the order of actions"
+    );
+}
+
+/// `Game::max_actions` is only used to allocate buffers, even if it is too small.
+#[test]
+fn max_actions() {
+    #[derive(Clone)]
+    struct Limited(Node, usize);
+
+    impl crate::Game for Limited {
+        type Player = bool;
+        type Action = usize;
+        type Fitness = i8;
+        type Actions = std::ops::Range<usize>;
+
+        fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+            self.0.actions(player)
+        }
+
+        fn execute(&mut self, action: &Self::Action, player: Self::Player) -> Self::Fitness {
+            self.0.execute(action, player)
+        }
+
+        fn max_actions(&self) -> Option<usize> {
+            Some(self.1)
+        }
+    }
+
+    for seed in 1..100u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 7 + 3) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let expected = Bot::new(true)
+            .detailed_select(&tree, ToCompletion)
+            .map(|act| (act.fitness, act.path));
+        for &max in &[0, 1, 64] {
+            let limited = Limited(tree.clone(), max);
+            let selected = Bot::new(true)
+                .detailed_select(&limited, ToCompletion)
+                .map(|act| (act.fitness, act.path));
+            assert_eq!(selected, expected, "tree: {:?}", tree);
+        }
+    }
+}
+
+/// Sorting actions by `Game::order_key` without executing them must not change the selected action.
+#[test]
+fn order_key() {
+    use crate::brute::Brute;
+
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 60).map(|i| seed.wrapping_mul(i * 13 + 5) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let expected = Bot::new(true).detailed_select(&tree, ToCompletion);
+        let selected = Bot::with_order_key(true).detailed_select(&tree, ToCompletion);
+        assert!(
+            Brute::new(true).check_if_best(&tree, selected.as_ref().map(|a| &a.path[0]), u32::MAX),
+            "tree: {:?}, selected: {:?}",
+            tree,
+            selected
+        );
+        assert_eq!(
+            selected.map(|a| a.fitness),
+            expected.map(|a| a.fitness),
+            "tree: {:?}",
+            tree
+        );
+    }
+}
+
+/// A `Node` which remembers all previous states, so actions can be undone.
+#[derive(Clone, Debug, PartialEq)]
+struct History(Vec<Node>);
+
+impl Game for History {
+    type Player = bool;
+    type Action = usize;
+    type Fitness = i8;
+    type Actions = std::ops::Range<usize>;
+
+    fn actions(&self, player: bool) -> (bool, Self::Actions) {
+        self.0.last().unwrap().actions(player)
+    }
+
+    fn execute(&mut self, action: &usize, player: bool) -> i8 {
+        let mut state = self.0.last().unwrap().clone();
+        let fitness = state.execute(action, player);
+        self.0.push(state);
+        fitness
+    }
+
+    fn is_upper_bound(&self, fitness: i8, player: bool) -> bool {
+        self.0.last().unwrap().is_upper_bound(fitness, player)
+    }
+
+    fn is_lower_bound(&self, fitness: i8, player: bool) -> bool {
+        self.0.last().unwrap().is_lower_bound(fitness, player)
+    }
+}
+
+impl crate::Undo for History {
+    fn undo(&mut self, _: &usize, _: bool) {
+        self.0.pop();
+    }
+}
+
+/// Executing and then undoing an action results in the original state.
+#[test]
+fn undo() {
+    use crate::Undo;
+
+    fn check(state: &mut History) {
+        let (_, actions) = state.actions(true);
+        for action in actions {
+            let original = state.clone();
+            state.execute(&action, true);
+            let executed = state.clone();
+            check(state);
+            assert_eq!(*state, executed);
+            state.undo(&action, true);
+            assert_eq!(*state, original);
+        }
+    }
+
+    for seed in 1..100u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 7 + 3) as u8));
+        check(&mut History(vec![Node::from_bytes(&bytes)]));
+    }
+}
+
+/// Executing actions in place must not change the search.
+#[test]
+fn with_undo() {
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 60).map(|i| seed.wrapping_mul(i * 13 + 5) as u8));
+        let tree = Node::from_bytes(&bytes);
+        let history = History(vec![tree.clone()]);
+
+        for depth in 0..3 {
+            let mut expected_logger = Logger::new(Depth(depth));
+            let expected = Bot::new(true)
+                .detailed_select(&tree, &mut expected_logger)
+                .map(|a| (a.fitness, a.path));
+            let mut logger = Logger::new(Depth(depth));
+            let selected = Bot::with_undo(true)
+                .detailed_select(&history, &mut logger)
+                .map(|a| (a.fitness, a.path));
+            assert_eq!(selected, expected, "tree: {:?}", tree);
+            assert_eq!(logger.steps(), expected_logger.steps(), "tree: {:?}", tree);
+        }
+
+        let expected = Bot::new(true)
+            .detailed_select(&tree, ToCompletion)
+            .map(|a| (a.fitness, a.path));
+        let selected = Bot::with_undo(true)
+            .detailed_select(&history, ToCompletion)
+            .map(|a| (a.fitness, a.path));
+        assert_eq!(selected, expected, "tree: {:?}", tree);
+    }
+}
+
+/// A `Node` where reaching an upper or lower bound ends the game, even if there are still actions left.
+#[derive(Clone, Debug)]
+struct Ended(Node, i8);
+
+impl Game for Ended {
+    type Player = bool;
+    type Action = usize;
+    type Fitness = i8;
+    type Actions = std::ops::Range<usize>;
+
+    fn actions(&self, player: bool) -> (bool, Self::Actions) {
+        self.0.actions(player)
+    }
+
+    fn execute(&mut self, action: &usize, player: bool) -> i8 {
+        self.1 = self.0.execute(action, player);
+        self.1
+    }
+
+    fn is_upper_bound(&self, fitness: i8, player: bool) -> bool {
+        self.0.is_upper_bound(fitness, player)
+    }
+
+    fn is_lower_bound(&self, fitness: i8, player: bool) -> bool {
+        self.0.is_lower_bound(fitness, player)
+    }
+
+    fn status(&self, _: bool) -> crate::Status {
+        match self.1 {
+            i8::MAX => crate::Status::Win,
+            i8::MIN => crate::Status::Loss,
+            _ => crate::Status::Ongoing,
+        }
+    }
+}
+
+/// States which are over according to `Game::status` are not analysed any further.
+#[test]
+fn status() {
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 3),
+        Node::new(false, i8::MAX).with_children(&[
+            Node::new(true, i8::MIN),
+        ]),
+    ]);
+
+    assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(0));
+    let ended = Ended(tree, 0);
+    let action = Bot::new(true)
+        .detailed_select(&ended, ToCompletion)
+        .unwrap();
+    assert_eq!((action.fitness, action.path), (i8::MAX, vec![1]));
+    assert_eq!(
+        Bot::new(true).try_select(
+            &Ended(Node::root().with_children(&[Node::root()]), i8::MAX),
+            ToCompletion
+        ),
+        Err(crate::alpha_beta::SelectError::Terminal)
+    );
+
+    for seed in 1..200u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 41 + 29) as u8));
+        let tree = Ended(Node::from_bytes(&bytes), 0);
+        let selected = Bot::new(true).select(&tree, ToCompletion);
+        assert!(
+            crate::brute::Brute::new(true).check_if_best(&tree, selected.as_ref(), u32::MAX),
+            "tree: {:?}, selected: {:?}",
+            tree,
+            selected
+        );
+    }
+}
+
+/// Both players take 1 or 2 tokens from their own pile, passing if it is empty.
+/// The player taking the last token wins.
+#[derive(Clone, Debug)]
+struct Tokens {
+    piles: [u8; 2],
+    active: bool,
+}
+
+impl Game for Tokens {
+    type Player = bool;
+    type Action = u8;
+    type Fitness = i8;
+    type Actions = Vec<u8>;
+
+    fn actions(&self, player: bool) -> (bool, Vec<u8>) {
+        let pile = self.piles[self.active as usize];
+        (player == self.active, (1..=pile.min(2)).collect())
+    }
+
+    fn execute(&mut self, action: &u8, player: bool) -> i8 {
+        self.piles[self.active as usize] -= action;
+        let winner = self.active;
+        self.active = !self.active;
+        match self.piles {
+            [0, 0] if winner == player => i8::MAX,
+            [0, 0] => i8::MIN,
+            _ => 0,
+        }
+    }
+
+    fn pass(&mut self, _: bool) -> bool {
+        self.active = !self.active;
+        true
+    }
+
+    fn is_upper_bound(&self, fitness: i8, _: bool) -> bool {
+        fitness == i8::MAX
+    }
+
+    fn is_lower_bound(&self, fitness: i8, _: bool) -> bool {
+        fitness == i8::MIN
+    }
+}
+
+/// The search continues after a player without any actions passes.
+#[test]
+fn pass() {
+    // The opponent has to pass after every action of the bot.
+    let tokens = Tokens {
+        piles: [0, 3],
+        active: true,
+    };
+    let action = Bot::new(true)
+        .detailed_select(&tokens, ToCompletion)
+        .unwrap();
+    assert_eq!(action.fitness, i8::MAX);
+    assert_eq!(action.path.iter().sum::<u8>(), 3);
+
+    // The bot only has a single token, so the opponent takes the last tokens.
+    let tokens = Tokens {
+        piles: [2, 1],
+        active: true,
+    };
+    let action = Bot::new(true)
+        .detailed_select(&tokens, ToCompletion)
+        .unwrap();
+    assert_eq!((action.fitness, action.path), (i8::MIN, vec![1, 2]));
+
+    for a in 0..6 {
+        for b in 0..6 {
+            let tokens = Tokens {
+                piles: [a, b],
+                active: true,
+            };
+            let selected = Bot::new(true).select(&tokens, ToCompletion);
+            assert!(
+                crate::brute::Brute::new(true).check_if_best(&tokens, selected.as_ref(), u32::MAX),
+                "tokens: {:?}, selected: {:?}",
+                tokens,
+                selected
+            );
+        }
+    }
+}
+
+/// `Game::heuristic_actions` is used for all states except the root.
+#[test]
+fn heuristic_actions() {
+    /// Only considers the first action of each state.
+    #[derive(Clone)]
+    struct First(Node);
+
+    impl crate::Game for First {
+        type Player = bool;
+        type Action = usize;
+        type Fitness = i8;
+        type Actions = std::ops::Range<usize>;
+
+        fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+            self.0.actions(player)
+        }
+
+        fn execute(&mut self, action: &Self::Action, player: Self::Player) -> Self::Fitness {
+            self.0.execute(action, player)
+        }
+
+        fn heuristic_actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+            let (active, actions) = self.0.actions(player);
+            (active, 0..actions.end.min(1))
+        }
+    }
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 3).with_children(&[
+            Node::new(true, 3),
+        ]),
+        Node::new(false, 5).with_children(&[
+            Node::new(true, 6),
+            Node::new(true, -10),
+        ]),
+    ]);
+
+    assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(0));
+    // The opponent is expected to only consider `[1][0]`.
+    assert_eq!(Bot::new(true).select(&First(tree), ToCompletion), Some(1));
+}
+
+/// Among equally good root actions, the one with the highest rarity is selected.
+#[test]
+fn rarity() {
+    use crate::brute::Brute;
+
+    #[derive(Clone)]
+    struct Rare(Node, fn(usize) -> i32);
+
+    impl crate::Game for Rare {
+        type Player = bool;
+        type Action = usize;
+        type Fitness = i8;
+        type Actions = std::ops::Range<usize>;
+
+        fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+            self.0.actions(player)
+        }
+
+        fn execute(&mut self, action: &Self::Action, player: Self::Player) -> Self::Fitness {
+            self.0.execute(action, player)
+        }
+
+        fn rarity(&self, action: &Self::Action, _: Self::Player) -> i32 {
+            (self.1)(*action)
+        }
+    }
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 0).with_children(&[
+            Node::new(true, 3),
+            Node::new(true, 4),
+        ]),
+        Node::new(false, 0).with_children(&[
+            Node::new(true, 0).with_children(&[
+                Node::new(false, 3),
+            ]),
+            Node::new(true, 5),
+        ]),
+        Node::new(false, 0).with_children(&[
+            Node::new(true, 1),
+            Node::new(true, 7),
+        ]),
+    ]);
+
+    assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(0));
+    let rare = Rare(tree.clone(), |action| [0, 5, 0][action]);
+    assert_eq!(Bot::new(true).select(&rare, ToCompletion), Some(1));
+    // A rare action is never preferred over a better one.
+    let rare = Rare(tree, |action| [0, 0, 5][action]);
+    assert_eq!(Bot::new(true).select(&rare, ToCompletion), Some(0));
+
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 29 + 7) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        // Prefers the last of all equally good actions.
+        let rare = Rare(tree.clone(), |action| action as i32);
+        let selected = Bot::new(true).select(&rare, ToCompletion);
+        let mut brute = Brute::new(true);
+        assert!(
+            brute.check_if_best(&tree, selected.as_ref(), u32::MAX),
+            "tree: {:?}, selected: {:?}",
+            tree,
+            selected
+        );
+        if let Some(selected) = selected {
+            for action in selected + 1..tree.actions(true).1.end {
+                assert!(
+                    !brute.check_if_best(&tree, Some(&action), u32::MAX),
+                    "tree: {:?}, selected: {}, rarer: {}",
+                    tree,
+                    selected,
+                    action
+                );
+            }
+        }
+    }
+}
+
+/// The order in which partially terminated actions are retried must not change the result.
+#[test]
+fn partial_order() {
+    use crate::alpha_beta::RetryOrder;
+    use crate::brute::Brute;
+
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 31 + 13) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let selected =
+            Bot::with_partial_order(true, RetryOrder::Descending).select(&tree, ToCompletion);
+        assert!(
+            Brute::new(true).check_if_best(&tree, selected.as_ref(), u32::MAX),
+            "tree: {:?}, selected: {:?}",
+            tree,
+            selected
+        );
+    }
+}
+
+/// Evaluating actions in parallel gives the same result as verifying each action on its own.
+#[cfg(feature = "rayon")]
+#[test]
+fn evaluate_moves_parallel() {
+    for seed in 1..100u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 37 + 17) as u8));
+        let tree = Node::from_bytes(&bytes);
+        let (active, actions) = tree.actions(true);
+        let actions: Vec<_> = actions.collect();
+        let bot = Bot::new(true);
+        if !active {
+            assert_eq!(bot.evaluate_moves_parallel(&tree, &actions, 1), None);
+            continue;
+        }
+
+        for depth in 1..4 {
+            let expected: Vec<_> = actions
+                .iter()
+                .map(|&action| {
+                    Bot::new(true)
+                        .verify_line(&tree, &[action], Depth(depth))
+                        .unwrap()
+                })
+                .collect();
+            assert_eq!(
+                bot.evaluate_moves_parallel(&tree, &actions, depth),
+                Some(expected)
+            );
+        }
+    }
+}
+
+/// Searching the root actions in parallel selects an action as good as the one of `select`.
+#[cfg(feature = "rayon")]
+#[test]
+fn select_parallel() {
+    use crate::brute::Brute;
+
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 40).map(|i| seed.wrapping_mul(i * 19 + 7) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let expected = Bot::new(true).detailed_select(&tree, ToCompletion);
+        for threads in 1..4 {
+            let selected = Bot::new(true).select_parallel(&tree, ToCompletion, threads);
+            assert!(
+                Brute::new(true).check_if_best(&tree, selected.as_ref(), u32::MAX),
+                "tree: {:?}, selected: {:?}",
+                tree,
+                selected
+            );
+            let fitness = selected.map(|action| {
+                Bot::new(true)
+                    .verify_line(&tree, &[action], ToCompletion)
+                    .unwrap()
+            });
+            assert_eq!(
+                fitness,
+                expected.as_ref().map(|a| a.fitness),
+                "tree: {:?}",
+                tree
+            );
+        }
+    }
+}
+
+/// Serializing and deserializing an `Action` keeps its fitness and path.
+#[cfg(feature = "serde")]
+#[test]
+fn serde_roundtrip() {
+    use crate::alpha_beta::{Action, AnalysisTree};
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 0).with_children(&[
+            Node::new(true, 3),
+            Node::new(true, -2),
+        ]),
+        Node::new(false, 0).with_children(&[
+            Node::new(true, 1).with_children(&[
+                Node::new(false, 4),
+            ]),
+        ]),
+    ]);
+
+    let action = Bot::new(true).detailed_select(&tree, ToCompletion).unwrap();
+    let json = serde_json::to_string(&action).unwrap();
+    let deserialized: Action<Node> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.fitness, action.fitness);
+    assert_eq!(deserialized.path, action.path);
+
+    let analysis = Bot::new(true).analyze(&tree, ToCompletion).unwrap();
+    let json = serde_json::to_string(&analysis).unwrap();
+    let deserialized: AnalysisTree<Node> = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+}
+
+#[test]
+fn select_with_diagnostics() {
+    use crate::alpha_beta::Diagnostic;
+
+    #[derive(Clone)]
+    struct Bounded(Node);
+
+    impl crate::Game for Bounded {
+        type Player = bool;
+        type Action = usize;
+        type Fitness = i8;
+        type Actions = std::ops::Range<usize>;
+
+        fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+            self.0.actions(player)
+        }
+
+        fn execute(&mut self, action: &Self::Action, player: Self::Player) -> Self::Fitness {
+            self.0.execute(action, player)
+        }
+
+        fn is_upper_bound(&self, fitness: Self::Fitness, _: Self::Player) -> bool {
+            fitness == 10
+        }
+    }
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 10),
+        Node::new(false, 5),
+        Node::new(false, 20),
+    ]);
+    let mut bot = Bot::new(true);
+    let (action, diagnostics) = bot.select_with_diagnostics(&tree, ToCompletion);
+    assert_eq!(action, Some(2));
+    assert!(diagnostics.is_empty());
+
+    let (action, diagnostics) =
+        Bot::new(true).select_with_diagnostics(&Bounded(tree), ToCompletion);
+    // `[0]` is an upper bound, so `[2]` is never considered.
+    assert_eq!(action, Some(0));
+    assert_eq!(diagnostics, &[Diagnostic::FitnessNearBounds]);
+
+    // A single action is not a flat evaluation.
+    let single = Node::root().with_children(&[Node::new(false, 3)]);
+    assert_eq!(
+        bot.select_with_diagnostics(&single, ToCompletion),
+        (Some(0), Vec::new())
+    );
+}
+
+/// Using the order of `Game::actions` only changes the amount of cutoffs, not the result.
+#[test]
+fn trust_action_order() {
+    use crate::brute::Brute;
+
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 41 + 19) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let selected = Bot::trust_action_order(true).select(&tree, ToCompletion);
+        assert!(
+            Brute::new(true).check_if_best(&tree, selected.as_ref(), u32::MAX),
+            "tree: {:?}, selected: {:?}",
+            tree,
+            selected
+        );
+    }
+}
+
+/// `[0]` is irreversible, so with a limit of one irreversible action,
+/// the bot does not see that `[0]` leads to a loss.
+#[test]
+fn irreversible_limit() {
+    #[derive(Clone)]
+    struct Irreversible(Node);
+
+    impl crate::Game for Irreversible {
+        type Player = bool;
+        type Action = usize;
+        type Fitness = i8;
+        type Actions = std::ops::Range<usize>;
+
+        fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+            self.0.actions(player)
+        }
+
+        fn execute(&mut self, action: &Self::Action, player: Self::Player) -> Self::Fitness {
+            self.0.execute(action, player)
+        }
+
+        fn is_irreversible(&self, &action: &Self::Action) -> bool {
+            action == 0
+        }
+    }
+
+    #[rustfmt::skip]
+    let tree = Irreversible(Node::root().with_children(&[
+        Node::new(true, 5).with_children(&[
+            Node::new(true, -10),
+        ]),
+        Node::new(true, 3).with_children(&[
+            Node::new(true, -50),
+            Node::new(true, 6).with_children(&[
+                Node::new(true, 4),
+            ]),
+        ]),
+    ]));
+
+    assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(1));
+    assert_eq!(Bot::new(true).solve(&tree, 10), Some(4));
+
+    let mut bot = Bot::with_irreversible_limit(true, 1);
+    assert_eq!(bot.select(&tree, ToCompletion), Some(0));
+    assert_eq!(bot.solve(&tree, 10), Some(5));
+    // Reversible actions are still analysed.
+    assert_eq!(bot.verify_line(&tree, &[1], ToCompletion), Some(4));
+
+    let mut bot = Bot::with_irreversible_limit(true, 2);
+    assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+}
+
+/// A complete search is always proven, and a proven action is always the best one,
+/// even if the search was limited.
+#[test]
+fn select_checked() {
+    use crate::brute::Brute;
+
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 17 + 3) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let (selected, proven) = Bot::new(true).select_checked(&tree, ToCompletion);
+        assert!(
+            proven || selected.is_none(),
+            "tree: {:?}, selected: {:?}",
+            tree,
+            selected
+        );
+        assert!(Brute::new(true).check_if_best(&tree, selected.as_ref(), u32::MAX));
+
+        for depth in 0..3 {
+            let mut logger = Logger::new(Depth(depth));
+            let (selected, proven) = Bot::new(true).select_checked(&tree, &mut logger);
+            if proven {
+                assert!(
+                    Brute::new(true).check_if_best(&tree, selected.as_ref(), u32::MAX),
+                    "tree: {:?}, selected: {:?}",
+                    tree,
+                    selected
+                );
+            } else {
+                assert!(selected.is_none() || !logger.completed());
+            }
+        }
+    }
+}
+
+/// Every root action is ranked with its exact fitness, starting with the best one.
+///
+/// The search stops once a winning action is found, so the fitness of the other actions
+/// is only exact if there is no such action.
+#[test]
+fn rank_actions() {
+    use crate::brute::Brute;
+
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 17 + 3) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let mut bot = Bot::new(true);
+        let ranking = bot.rank_actions(&tree, ToCompletion);
+        let (_, actions) = tree.actions(true);
+        assert_eq!(ranking.len(), actions.len());
+        assert!(ranking.windows(2).all(|w| w[0].fitness >= w[1].fitness));
+        assert!(
+            Brute::new(true).check_if_best(
+                &tree,
+                ranking.first().map(|act| &act.path[0]),
+                u32::MAX
+            ),
+            "tree: {:?}",
+            tree
+        );
+        if ranking
+            .first()
+            .is_some_and(|act| tree.is_upper_bound(act.fitness, true))
+        {
+            continue;
+        }
+
+        for act in ranking.iter() {
+            assert_eq!(
+                bot.verify_line(&tree, &act.path[..1], ToCompletion),
+                Some(act.fitness)
+            );
+        }
+    }
+}
+
+/// The `k` best actions start with the best action and never contain more actions than possible.
+#[test]
+fn select_top_k() {
+    use crate::brute::Brute;
+
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 17 + 3) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let (_, actions) = tree.actions(true);
+        let mut bot = Bot::new(true);
+        for k in 0..4 {
+            let top = bot.select_top_k(&tree, ToCompletion, k);
+            assert_eq!(top.len(), k.min(actions.len()));
+            assert!(top.windows(2).all(|w| w[0].fitness >= w[1].fitness));
+            if let Some(best) = top.first() {
+                assert!(
+                    Brute::new(true).check_if_best(&tree, Some(&best.path[0]), u32::MAX),
+                    "tree: {:?}",
+                    tree
+                );
+            }
+        }
+    }
+}
+
+/// `select_subset` chooses the best action out of the allowed ones.
+#[test]
+fn select_subset() {
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 23 + 11) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let ranking = Bot::new(true).rank_actions(&tree, ToCompletion);
+        let best = ranking
+            .iter()
+            .filter(|act| act.path[0] % 2 == 1)
+            .map(|act| act.fitness)
+            .max();
+
+        let allowed = (0..tree.child_count()).filter(|i| i % 2 == 1);
+        let selected = Bot::new(true).select_subset(&tree, ToCompletion, allowed.chain(Some(100)));
+        let fitness = selected.map(|selected| {
+            assert_eq!(selected % 2, 1);
+            ranking
+                .iter()
+                .find(|act| act.path[0] == selected)
+                .unwrap()
+                .fitness
+        });
+        assert_eq!(fitness, best, "tree: {:?}", tree);
+    }
+}
+
+/// `widen_and_continue` chooses the best action out of the previous and the added ones,
+/// regardless of the depth reached by the previous search.
+#[test]
+fn widen_and_continue() {
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 23 + 11) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let ranking = Bot::new(true).rank_actions(&tree, ToCompletion);
+        let fitness = |action| {
+            ranking
+                .iter()
+                .find(|act| act.path[0] == action)
+                .unwrap()
+                .fitness
+        };
+        let best = ranking.iter().map(|act| act.fitness).max();
+
+        for &depth in &[1, 3, 100] {
+            let mut bot = Bot::with_root_action_limit(true, 2);
+            let mut context = crate::alpha_beta::SearchContext::new();
+            bot.select_with(&tree, Depth(depth), &mut context);
+
+            let odd = (0..tree.child_count()).filter(|i| i % 2 == 1);
+            let best_odd = odd.clone().map(fitness).max();
+            let selected =
+                bot.widen_and_continue(&tree, ToCompletion, &mut context, odd.chain(Some(100)));
+            assert!(selected.map(fitness) >= best_odd, "tree: {:?}", tree);
+
+            let even = (0..tree.child_count()).filter(|i| i % 2 == 0);
+            let selected = bot.widen_and_continue(&tree, ToCompletion, &mut context, even);
+            assert_eq!(selected.map(fitness), best, "tree: {:?}", tree);
+        }
+    }
+}
+
+/// Only uses a few different fitness values, so there are a lot of ties.
+#[derive(Clone)]
+struct Coarse(Node);
+
+impl crate::Game for Coarse {
+    type Player = bool;
+    type Action = usize;
+    type Fitness = i8;
+    type Actions = std::ops::Range<usize>;
+
+    fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+        self.0.actions(player)
+    }
+
+    fn execute(&mut self, action: &Self::Action, player: Self::Player) -> Self::Fitness {
+        self.0.execute(action, player) / 64
+    }
+
+    fn hash_action(&self, action: &Self::Action, player: Self::Player) -> Option<u64> {
+        self.0.hash_action(action, player)
+    }
+}
+
+/// `select_by` chooses the preferred action out of all best actions.
+#[test]
+fn select_by() {
+    use crate::brute::Brute;
+
+    let mut ties = 0;
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 17 + 3) as u8));
+        let tree = Coarse(Node::from_bytes(&bytes));
+
+        let mut brute = Brute::new(true);
+        let (_, actions) = tree.actions(true);
+        let best: Vec<_> = actions
+            .filter(|action| brute.check_if_best(&tree, Some(action), u32::MAX))
+            .collect();
+        if best.len() > 1 {
+            ties += 1;
+        }
+
+        let mut bot = Bot::new(true);
+        let selected = bot.select_by(&tree, ToCompletion, |a, b| a.cmp(b));
+        assert_eq!(selected, best.last().copied(), "tree: {:?}", tree.0);
+        let selected = bot.select_by(&tree, ToCompletion, |a, b| b.cmp(a));
+        assert_eq!(selected, best.first().copied(), "tree: {:?}", tree.0);
+    }
+    assert!(ties > 50, "only {} trees with multiple best actions", ties);
+}
+
+/// Out of all best root actions, the one with the greatest fitness after executing it is selected,
+/// preferring the first one returned by `Game::actions` if this is also equal.
+#[test]
+fn equal_fitness() {
+    use crate::brute::Brute;
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 2).with_children(&[
+            Node::new(true, 6),
+        ]),
+        Node::new(false, 9).with_children(&[
+            Node::new(true, 3),
+        ]),
+        Node::new(false, 7).with_children(&[
+            Node::new(true, 8),
+            Node::new(true, 6),
+        ]),
+        Node::new(false, 6),
+        Node::new(false, 7).with_children(&[
+            Node::new(true, 6),
+        ]),
+    ]);
+    for depth in 1..3 {
+        assert_eq!(Bot::new(true).select(&tree, Depth(depth)), Some(2));
+    }
+    assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(2));
+
+    let tree = Node::root().with_children(&[Node::new(false, 5), Node::new(false, 5)]);
+    assert_eq!(Bot::new(true).select(&tree, Depth(0)), Some(0));
+    assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(0));
+
+    let mut ties = 0;
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 17 + 3) as u8));
+        let tree = Coarse(Node::from_bytes(&bytes));
+
+        let mut bot = Bot::new(true);
+        let selected = bot.detailed_select(&tree, ToCompletion);
+
+        let mut brute = Brute::new(true);
+        let (_, actions) = tree.actions(true);
+        let best: Vec<_> = actions
+            .filter(|action| brute.check_if_best(&tree, Some(action), u32::MAX))
+            .collect();
+        if best.len() > 1 {
+            ties += 1;
+        }
+        let expected = best
+            .iter()
+            .rev()
+            .max_by_key(|&action| tree.look_ahead(action, true))
+            .copied();
+        assert_eq!(
+            selected.map(|act| act.path[0]),
+            expected,
+            "tree: {:?}",
+            tree.0
+        );
+        #[cfg(feature = "rayon")]
+        for threads in 1..4 {
+            assert_eq!(
+                bot.select_parallel(&tree, ToCompletion, threads),
+                expected,
+                "tree: {:?}",
+                tree.0
+            );
+        }
+    }
+    assert!(ties > 50, "only {} trees with multiple best actions", ties);
+}
+
+/// `select_random` chooses each of the best actions for some seed, but never a worse one.
+#[test]
+fn select_random() {
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 0).with_children(&[
+            Node::new(true, 3),
+        ]),
+        Node::new(false, 5),
+        Node::new(false, 9).with_children(&[
+            Node::new(true, 5),
+        ]),
+        Node::new(false, 5),
+        Node::new(false, 5).with_children(&[
+            Node::new(true, 8),
+            Node::new(true, 5),
+        ]),
+    ]);
+
+    let mut bot = Bot::new(true);
+    let mut chosen = [0; 5];
+    for seed in 0..200 {
+        let action = bot.select_random(&tree, ToCompletion, seed).unwrap();
+        assert_eq!(bot.select_random(&tree, ToCompletion, seed), Some(action));
+        chosen[action] += 1;
+    }
+
+    assert_eq!(chosen[0], 0);
+    assert!(chosen[1..].iter().all(|&count| count > 20), "{:?}", chosen);
+}
+
+/// A pile of stones, both players take 1 to 3 stones. Taking the last stone wins,
+/// while taking exactly 2 stones is slightly preferred.
+#[derive(Clone)]
+struct Pile(u32, bool);
+
+impl crate::Game for Pile {
+    type Player = bool;
+    type Action = u32;
+    type Fitness = i8;
+    type Actions = std::ops::RangeInclusive<u32>;
+
+    fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+        (player == self.1, 1..=self.0.min(3))
+    }
+
+    fn execute(&mut self, &action: &Self::Action, player: Self::Player) -> Self::Fitness {
+        self.0 -= action;
+        self.1 = !self.1;
+        let sign = if player == self.1 { -1 } else { 1 };
+        if self.0 == 0 {
+            sign * 100
+        } else {
+            sign * (self.0 % 5) as i8
+        }
+    }
+
+    fn key(&self, _: Self::Player) -> Option<u64> {
+        Some(u64::from(self.0) * 2 + u64::from(self.1))
+    }
+}
+
+/// Using a transposition table does not change the result of a complete search,
+/// regardless of its size and whether it is kept between searches.
+///
+/// As cached results are only used for the exact same arguments, even the principal variation is the same.
+#[test]
+fn transposition_table() {
+    let mut persistent = Bot::with_transposition_table(true, 64);
+    persistent.set_persistent_transpositions(true);
+    for stones in 1..14 {
+        let pile = Pile(stones, true);
+        let expected = Bot::new(true).detailed_select(&pile, ToCompletion).unwrap();
+        for &size in &[1, 7, 1024] {
+            let mut bot = Bot::with_transposition_table(true, size);
+            for bot in [&mut bot, &mut persistent].iter_mut() {
+                let action = bot.detailed_select(&pile, ToCompletion).unwrap();
+                assert_eq!(action.fitness, expected.fitness, "{} stones", stones);
+                assert_eq!(action.path, expected.path, "{} stones", stones);
+            }
+        }
+    }
+
+    let pile = Pile(13, true);
+    let mut logger = Logger::new(ToCompletion);
+    Bot::new(true).select(&pile, &mut logger);
+    let steps = logger.steps();
+    Bot::with_transposition_table(true, 1024).select(&pile, &mut logger);
+    assert!(logger.steps() < steps);
+
+    // Results of the first search are reused for the following move.
+    let mut cached = Bot::with_cache(true);
+    let mut pile = Pile(13, true);
+    let action = cached.select(&pile, ToCompletion).unwrap();
+    pile.execute(&action, true);
+    pile.execute(&1, false);
+    let expected = Bot::with_transposition_table(true, 1 << 16)
+        .detailed_select(&pile, &mut logger)
+        .unwrap();
+    let steps = logger.steps();
+    let action = cached.detailed_select(&pile, &mut logger).unwrap();
+    assert_eq!(action.fitness, expected.fitness);
+    assert_eq!(action.path, expected.path);
+    assert!(logger.steps() < steps);
+
+    // A reused search context keeps the results of the previous search, while a new one does not.
+    let mut context = crate::alpha_beta::SearchContext::with_transposition_table(1 << 16);
+    let mut pile = Pile(13, true);
+    let action = Bot::new(true)
+        .select_with(&pile, ToCompletion, &mut context)
+        .unwrap();
+    pile.execute(&action, true);
+    pile.execute(&1, false);
+    let mut fresh = crate::alpha_beta::SearchContext::with_transposition_table(1 << 16);
+    let expected = Bot::new(true).select_with(&pile, &mut logger, &mut fresh);
+    let steps = logger.steps();
+    let action = Bot::new(true).select_with(&pile, &mut logger, &mut context);
+    assert_eq!(action, expected);
+    assert!(logger.steps() < steps);
+
+    // Pondering only helps if the opponent chose the predicted action.
+    let mut pile = Pile(13, true);
+    pile.execute(&2, true);
+    let mut next = pile.clone();
+    next.execute(&1, false);
+    let expected = Bot::with_transposition_table(true, 1 << 16)
+        .detailed_select(&next, &mut logger)
+        .unwrap();
+    let steps = logger.steps();
+    for &(predicted, hit) in &[(1, true), (2, false)] {
+        let mut bot = Bot::with_transposition_table(true, 1 << 16);
+        bot.ponder(&pile, &predicted, ToCompletion);
+        let action = bot.detailed_select(&next, &mut logger).unwrap();
+        assert_eq!(action.fitness, expected.fitness);
+        assert_eq!(action.path, expected.path);
+        assert_eq!(logger.steps() < steps, hit, "predicted: {}", predicted);
+    }
+}
+
+/// A wrong prediction reuses the results of `Bot::ponder` if the actual state was analysed while pondering.
+#[test]
+fn ponder_miss() {
+    let mut logger = Logger::new(ToCompletion);
+    let pile = Pile(18, false);
+    // Taking 3 stones results in the same state as taking 1 stone followed by 1 stone each,
+    // while the remaining states can not be reached after the predicted action.
+    for &(actual, predicted, reused) in &[(3, 1, true), (2, 1, false), (1, 3, false)] {
+        let mut next = pile.clone();
+        next.execute(&actual, false);
+        let expected = Bot::with_transposition_table(true, 1024)
+            .detailed_select(&next, &mut logger)
+            .unwrap();
+        let steps = logger.steps();
+        let mut bot = Bot::with_transposition_table(true, 1024);
+        bot.ponder(&pile, &predicted, ToCompletion);
+        let action = bot.detailed_select(&next, &mut logger).unwrap();
+        assert_eq!(action.fitness, expected.fitness);
+        assert_eq!(action.path, expected.path);
+        let msg = format!("actual: {}, predicted: {}", actual, predicted);
+        assert_eq!(logger.steps() < steps, reused, "{}", msg);
+        assert_eq!(logger.steps() == steps, !reused, "{}", msg);
+    }
+}
+
+/// A search context shared by both players of a self-play game does not reuse
+/// the results of the other player, which are stored from its point of view.
+#[test]
+fn shared_search_context() {
+    for stones in 4..14 {
+        let mut context = crate::alpha_beta::SearchContext::with_transposition_table(1 << 16);
+        Bot::new(true).select_with(&Pile(stones + 3, true), ToCompletion, &mut context);
+        let pile = Pile(stones, false);
+        let expected = Bot::new(false).select(&pile, ToCompletion);
+        let action = Bot::new(false).select_with(&pile, ToCompletion, &mut context);
+        assert_eq!(action, expected, "{} stones", stones);
+
+        let action = Bot::new(true).select_with(&Pile(stones, true), ToCompletion, &mut context);
+        let expected = Bot::new(true).select(&Pile(stones, true), ToCompletion);
+        assert_eq!(action, expected, "{} stones", stones);
+    }
+}
+
+/// Analysing the best action of a cached result first must not change the result of a complete search.
+#[test]
+fn transposition_best_action() {
+    use crate::brute::Brute;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    /// A node which identifies equal subtrees using `Game::key`.
+    #[derive(Clone)]
+    struct Keyed(Node);
+
+    impl crate::Game for Keyed {
+        type Player = bool;
+        type Action = usize;
+        type Fitness = i8;
+        type Actions = std::ops::Range<usize>;
+
+        fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+            self.0.actions(player)
+        }
+
+        fn execute(&mut self, action: &Self::Action, player: Self::Player) -> Self::Fitness {
+            self.0.execute(action, player)
+        }
+
+        fn key(&self, _: Self::Player) -> Option<u64> {
+            let mut hasher = DefaultHasher::new();
+            format!("{:?}", self.0).hash(&mut hasher);
+            Some(hasher.finish())
+        }
+    }
+
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 40).map(|i| seed.wrapping_mul(i * 13 + 5) as u8));
+        let tree = Keyed(Node::from_bytes(&bytes));
+
+        let expected = Bot::new(true).detailed_select(&tree, ToCompletion);
+        for &size in &[1, 7, 1024] {
+            let selected =
+                Bot::with_transposition_table(true, size).detailed_select(&tree, ToCompletion);
+            assert!(
+                Brute::new(true).check_if_best(
+                    &tree,
+                    selected.as_ref().map(|a| &a.path[0]),
+                    u32::MAX
+                ),
+                "tree: {:?}, selected: {:?}",
+                tree.0,
+                selected
+            );
+            assert_eq!(
+                selected.map(|a| a.fitness),
+                expected.as_ref().map(|a| a.fitness),
+                "tree: {:?}",
+                tree.0
+            );
+        }
+    }
+}
+
+/// Among multiple winning actions, the one with the shortest path is selected,
+/// even if the longer win is found first as its states are not quiet.
+#[test]
+fn shortest_win() {
+    #[derive(Clone, Debug)]
+    struct Noisy(Node, i8);
+
+    impl crate::Game for Noisy {
+        type Player = bool;
+        type Action = usize;
+        type Fitness = i8;
+        type Actions = std::ops::Range<usize>;
+
+        fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+            self.0.actions(player)
+        }
+
+        fn execute(&mut self, action: &Self::Action, player: Self::Player) -> Self::Fitness {
+            self.1 = self.0.execute(action, player);
+            self.1
+        }
+
+        fn is_upper_bound(&self, fitness: Self::Fitness, player: Self::Player) -> bool {
+            self.0.is_upper_bound(fitness, player)
+        }
+
+        fn is_quiet(&self, _: Self::Player) -> bool {
+            self.1 != 5
+        }
+    }
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 5).with_children(&[
+            Node::new(true, 5).with_children(&[
+                Node::new(false, 5).with_children(&[
+                    Node::new(true, i8::MAX),
+                ]),
+            ]),
+        ]),
+        Node::new(false, 0).with_children(&[
+            Node::new(true, 0).with_children(&[
+                Node::new(false, i8::MAX),
+            ]),
+        ]),
+    ]);
+
+    let noisy = Noisy(tree, 0);
+    let action = Bot::new(true)
+        .detailed_select(&noisy, ToCompletion)
+        .unwrap();
+    assert_eq!(action.fitness, i8::MAX);
+    assert_eq!(action.path, &[1, 0, 0]);
+    // The longer win is still used if the shorter one wasn't found yet.
+    assert_eq!(Bot::new(true).select(&noisy, Depth(1)), Some(0));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "`Game::look_ahead` and `Game::execute` returned a different fitness")]
+fn inconsistent_look_ahead() {
+    /// A `Node` whose `look_ahead` does not match `execute`.
+    #[derive(Clone, Debug)]
+    struct Inconsistent(Node);
+
+    impl crate::Game for Inconsistent {
+        type Player = bool;
+        type Action = usize;
+        type Fitness = i8;
+        type Actions = std::ops::Range<usize>;
+
+        fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+            self.0.actions(player)
+        }
+
+        fn execute(&mut self, action: &Self::Action, player: Self::Player) -> Self::Fitness {
+            self.0.execute(action, player)
+        }
+
+        fn look_ahead(&self, action: &Self::Action, player: Self::Player) -> Self::Fitness {
+            self.0.look_ahead(action, player).saturating_add(1)
+        }
+    }
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 7).with_children(&[
+            Node::new(true, 4),
+        ]),
+        Node::new(false, 5),
+    ]);
+    Bot::new(true).select(&Inconsistent(tree), ToCompletion);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "invalid according to `Game::validate_action`")]
+fn invalid_action() {
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 7),
+        Node::new(false, 5),
+    ]);
+    // `Node` only has two children, so `2` is not a valid action.
+    Bot::new(true).ponder(&tree, &2, ToCompletion);
 }
@@ -0,0 +1,79 @@
+//! Tests of the expectimax bot for games with chance nodes.
+use crate::brute::Brute;
+use crate::expectimax::Bot;
+use crate::{tree::Node, Depth, Game, ToCompletion};
+
+/// Without chance nodes, expectimax selects the same action as a minimax search.
+#[test]
+fn deterministic() {
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 17 + 3) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let selected = Bot::new(true).select(&tree, ToCompletion);
+        assert!(
+            Brute::new(true).check_if_best(&tree, selected.as_ref(), u32::MAX),
+            "tree: {:?}, selected: {:?}",
+            tree,
+            selected
+        );
+    }
+}
+
+/// The player first chooses a die, which is then rolled by the opponent.
+#[derive(Clone, Debug)]
+struct Dice {
+    die: Option<&'static [i8]>,
+    result: Option<i8>,
+}
+
+impl Game for Dice {
+    type Player = bool;
+    type Action = usize;
+    type Fitness = i8;
+    type Actions = std::ops::Range<usize>;
+
+    fn actions(&self, _: bool) -> (bool, Self::Actions) {
+        match self.die {
+            None => (true, 0..2),
+            Some(_) => (true, 0..0),
+        }
+    }
+
+    fn execute(&mut self, &action: &usize, _: bool) -> i8 {
+        match self.die {
+            None => {
+                self.die = Some([&[3, 3, 3][..], &[0, 0, 10]][action]);
+                0
+            }
+            Some(die) => {
+                self.result = Some(die[action]);
+                die[action]
+            }
+        }
+    }
+
+    fn chance_outcomes(&self, _: bool) -> Option<Vec<(f64, usize)>> {
+        match (self.die, self.result) {
+            (Some(die), None) => Some((0..die.len()).map(|i| (1.0 / 3.0, i)).collect()),
+            _ => None,
+        }
+    }
+}
+
+/// The second die has a worse minimum but a better expected result.
+#[test]
+fn chance_outcomes() {
+    let state = Dice {
+        die: None,
+        result: None,
+    };
+    assert_eq!(Bot::new(true).select(&state, ToCompletion), Some(1));
+    // Chance nodes do not count towards the depth.
+    assert_eq!(Bot::new(true).select(&state, Depth(1)), Some(1));
+
+    let mut rolling = state.clone();
+    rolling.execute(&1, true);
+    assert_eq!(Bot::new(true).select(&rolling, ToCompletion), None);
+}
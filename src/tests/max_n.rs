@@ -0,0 +1,80 @@
+//! Tests of the max^n bot for games with more than two players.
+use crate::max_n::{Bot, MultiplayerGame};
+use crate::{Logger, ToCompletion};
+
+/// A game tree with three players, where each node stores the fitness of all players.
+#[derive(Clone, Debug)]
+struct Tree {
+    player: usize,
+    scores: [i8; 3],
+    children: Vec<Tree>,
+}
+
+impl Tree {
+    fn new(player: usize, scores: [i8; 3]) -> Self {
+        Tree {
+            player,
+            scores,
+            children: Vec::new(),
+        }
+    }
+
+    fn with_children(mut self, children: &[Tree]) -> Self {
+        self.children = children.to_vec();
+        self
+    }
+}
+
+impl MultiplayerGame for Tree {
+    type Player = usize;
+    type Action = usize;
+    type Fitness = i8;
+    type Scores = [i8; 3];
+    type Actions = std::ops::Range<usize>;
+
+    fn actions(&self) -> (usize, Self::Actions) {
+        (self.player, 0..self.children.len())
+    }
+
+    fn execute(&mut self, &action: &usize) -> [i8; 3] {
+        *self = self.children[action].clone();
+        self.scores
+    }
+
+    fn fitness(scores: &[i8; 3], player: usize) -> i8 {
+        scores[player]
+    }
+}
+
+/// Each player maximizes their own fitness instead of minimizing the fitness of the bot.
+#[test]
+fn three_players() {
+    #[rustfmt::skip]
+    let tree = Tree::new(0, [0; 3]).with_children(&[
+        Tree::new(1, [0; 3]).with_children(&[
+            Tree::new(2, [8, 5, 0]),
+            Tree::new(2, [0, 2, 9]),
+        ]),
+        Tree::new(1, [0; 3]).with_children(&[
+            Tree::new(2, [0; 3]).with_children(&[
+                Tree::new(0, [1, 4, 3]),
+                Tree::new(0, [6, 4, 2]),
+            ]),
+            Tree::new(2, [2, 3, 3]),
+        ]),
+    ]);
+
+    // Player 1 selects `[0][0]`, as it is better for them, even though it is also good for player 0.
+    // If the other players would only try to minimize the fitness of player 0, `[1]` would be better.
+    let mut logger = Logger::new(ToCompletion);
+    assert_eq!(Bot::new(0).select(&tree, &mut logger), Some(0));
+    assert_eq!(logger.best_fitness(), Some(8));
+    assert_eq!(logger.pv_len(), 2);
+    assert!(logger.completed());
+
+    // Player 2 would select `[1][0][0]`, so player 1 selects `[1][0]`.
+    let mut state = tree.clone();
+    state.execute(&1);
+    assert_eq!(Bot::new(1).select(&state, ToCompletion), Some(0));
+    assert_eq!(Bot::new(2).select(&state, ToCompletion), None);
+}
@@ -0,0 +1,67 @@
+//! Tests of the Monte Carlo tree search bot.
+use crate::mcts::Bot;
+use crate::{tree::Node, Depth, Logger, Steps, ToCompletion};
+
+/// Once every state was added to the tree, the search stops even without a limit.
+#[test]
+fn exhausted() {
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 7).with_children(&[
+            Node::new(true, 4),
+            Node::new(true, 2),
+        ]),
+        Node::new(false, 5).with_children(&[
+            Node::new(true, 8),
+            Node::new(true, 9)
+        ]),
+    ]);
+
+    let mut logger = Logger::new(ToCompletion);
+    assert_eq!(Bot::new(true).select(&tree, &mut logger), Some(1));
+    assert!(logger.completed());
+    assert_eq!(logger.steps(), 6);
+}
+
+/// Each random game counts as one depth and one step.
+#[test]
+fn run_condition() {
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 0).with_children(&[
+            Node::new(true, 5),
+            Node::new(true, 1),
+        ]),
+        Node::new(false, 0).with_children(&[
+            Node::new(true, 3),
+            Node::new(true, 4),
+        ]),
+    ]);
+
+    let mut logger = Logger::new(Depth(3));
+    assert!(Bot::new(true).select(&tree, &mut logger).is_some());
+    assert_eq!(logger.steps(), 3);
+    assert!(!logger.completed());
+
+    let mut logger = Logger::new(Steps(2));
+    assert!(Bot::new(true).select(&tree, &mut logger).is_some());
+    assert_eq!(logger.steps(), 2);
+
+    assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(1));
+    assert_eq!(Bot::new(false).select(&tree, ToCompletion), None);
+    assert_eq!(Bot::new(true).select(&Node::root(), ToCompletion), None);
+}
+
+/// The same seed always results in the same action.
+#[test]
+fn deterministic() {
+    for seed in 1..100u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 23 + 5) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let first = Bot::with_seed(true, seed).select(&tree, Depth(20));
+        let second = Bot::with_seed(true, seed).select(&tree, Depth(20));
+        assert_eq!(first, second, "tree: {:?}", tree);
+    }
+}
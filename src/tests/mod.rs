@@ -1,8 +1,76 @@
-use crate::{tree::Node, Bot, Depth, Logger, Steps, ToCompletion};
+use crate::{tree::Node, Bot, Depth, Game, Logger, Steps, ToCompletion};
+
+use std::fmt::Debug;
 
 mod brute;
 mod completed;
+mod expectimax;
+mod max_n;
+mod mcts;
+mod negamax;
 mod partial;
+mod solver;
+
+/// Replays the principal variation returned by `detailed_select` using `Game::execute`
+/// and checks that it actually results in the reported fitness.
+fn check_pv<T: Game>(state: &T, player: T::Player)
+where
+    T::Action: Debug,
+    T::Fitness: Debug,
+{
+    let action = match Bot::new(player).detailed_select(state, ToCompletion) {
+        Some(action) => action,
+        None => return,
+    };
+
+    let mut state = state.clone();
+    let mut fitness = None;
+    for act in action.path.iter() {
+        let (_, actions) = state.actions(player);
+        assert!(
+            actions.into_iter().any(|a| a == *act),
+            "impossible action {:?} in path {:?}",
+            act,
+            action.path
+        );
+        fitness = Some(state.execute(act, player));
+    }
+
+    assert_eq!(fitness, Some(action.fitness), "path: {:?}", action.path);
+}
+
+/// Replays the principal variation for a lot of generated trees.
+#[test]
+fn pv_replay() {
+    for seed in 1..500u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 40).map(|i| seed.wrapping_mul(i * 31 + 7) as u8));
+        check_pv(&Node::from_bytes(&bytes), true);
+    }
+}
+
+/// `Node::parse` is the inverse of `Node::to_dsl`.
+#[test]
+fn dsl_roundtrip() {
+    use crate::tree::ParseError;
+
+    for seed in 1..200u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 40).map(|i| seed.wrapping_mul(i * 13 + 5) as u8));
+        let tree = Node::from_bytes(&bytes);
+        assert_eq!(Node::parse(&tree.to_dsl()), Ok(tree));
+    }
+
+    assert_eq!(
+        Node::parse(" ( t-128\n(f127) ) "),
+        Ok(Node::new(true, i8::MIN).with_children(&[Node::new(false, i8::MAX)]))
+    );
+    assert_eq!(Node::parse(""), Err(ParseError { position: 0 }));
+    assert_eq!(Node::parse("(x0)"), Err(ParseError { position: 1 }));
+    assert_eq!(Node::parse("(t128)"), Err(ParseError { position: 2 }));
+    assert_eq!(Node::parse("(t0 (f1)"), Err(ParseError { position: 8 }));
+    assert_eq!(Node::parse("(t0) (f1)"), Err(ParseError { position: 5 }));
+}
 
 #[test]
 fn logger_eq() {
@@ -39,4 +107,525 @@ fn logger_eq() {
     let mut logger = Logger::new(Depth(2));
     Bot::new(true).select(&logger_eq, &mut logger);
     assert_eq!(logger.depth(), 2);
+    assert_eq!(logger.depth_durations().len(), 2);
+
+    let mut logger = Logger::new(ToCompletion);
+    assert_eq!(Bot::new(true).select(&logger_eq, &mut logger), Some(1));
+    assert_eq!(logger.best_fitness(), Some(3));
+    assert_eq!(logger.pv_len(), 4);
+
+    let mut logger = Logger::new(ToCompletion);
+    Bot::new(true).select(&logger_eq, &mut logger);
+    assert_eq!(logger.best_fitness(), Some(3));
+    assert_eq!(logger.pv_len(), 4);
+}
+
+/// A single `Logger` can be used for many searches without results
+/// of previous searches leaking into later ones.
+#[test]
+fn logger_reuse() {
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 7).with_children(&[
+            Node::new(true, 4),
+            Node::new(true, 2),
+        ]),
+        Node::new(false, 5).with_children(&[
+            Node::new(true, 8),
+            Node::new(true, 9)
+        ]),
+    ]);
+
+    let mut bot = Bot::new(true);
+    let mut logger = Logger::new(ToCompletion);
+    for _ in 0..3 {
+        assert_eq!(bot.select(&tree, &mut logger), Some(1));
+        assert!(logger.completed());
+        assert_eq!(logger.depth(), 2);
+        assert_eq!(logger.depth_durations().len(), 2);
+        assert_eq!(logger.score_history(), &[(0, 8), (1, 8)]);
+    }
+
+    let mut logger = Logger::new(Depth(0));
+    bot.select(&tree, &mut logger);
+    let steps = logger.steps();
+    bot.select(&tree, &mut logger);
+    assert_eq!(logger.steps(), steps);
+
+    let mut logger = Logger::new(Steps(3));
+    bot.select(&tree, &mut logger);
+    assert!(!logger.completed());
+    logger.reset();
+    assert_eq!(logger.steps(), 0);
+    assert_eq!(logger.depth(), 0);
+    assert!(logger.completed());
+}
+
+/// The callback of `Progress` sees every depth reported to the wrapped condition,
+/// including the one which stops the search.
+#[test]
+fn progress() {
+    use crate::Progress;
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 7).with_children(&[
+            Node::new(true, 4),
+            Node::new(true, 2),
+        ]),
+        Node::new(false, 5).with_children(&[
+            Node::new(true, 8),
+            Node::new(true, 9)
+        ]),
+    ]);
+
+    let mut bot = Bot::new(true);
+    let mut depths = Vec::new();
+    let mut logger = Logger::new(Depth(1));
+    let progress = Progress::new(&mut logger, |depth| depths.push(depth));
+    assert_eq!(bot.select(&tree, progress), Some(1));
+    assert_eq!(depths, &[0, 1]);
+    assert_eq!(logger.depth(), 1);
+
+    let mut depths = Vec::new();
+    let progress = Progress::new(Depth(5), |depth| depths.push(depth));
+    assert_eq!(bot.select(&tree, progress), Some(1));
+    assert_eq!(depths, &[0, 1, 2]);
+}
+
+/// Once the soft limit of a `TimeBudget` has passed, no new depth is started,
+/// but the current depth is only cancelled by the hard limit.
+#[test]
+fn time_budget() {
+    use crate::{IndependentCondition, InnerTimeBudget, TimeBudget};
+    use std::time::{Duration, Instant};
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 7).with_children(&[
+            Node::new(true, 4),
+            Node::new(true, 2),
+        ]),
+        Node::new(false, 5).with_children(&[
+            Node::new(true, 8),
+            Node::new(true, 9)
+        ]),
+    ]);
+
+    let budget = TimeBudget {
+        soft: Duration::from_secs(0),
+        hard: Duration::from_secs(3600),
+    };
+    let mut logger = Logger::new(budget);
+    Bot::new(true).select(&tree, &mut logger);
+    assert_eq!(logger.depth(), 0);
+    assert_eq!(logger.steps(), 0);
+    assert!(!logger.completed());
+
+    let now = Instant::now();
+    let mut soft_passed = InnerTimeBudget {
+        soft: now,
+        hard: now + Duration::from_secs(3600),
+    };
+    assert!(soft_passed.step());
+    assert!(!soft_passed.depth(1));
+
+    let budget = TimeBudget {
+        soft: Duration::from_secs(3600),
+        hard: Duration::from_secs(3600),
+    };
+    let mut logger = Logger::new(budget);
+    assert_eq!(Bot::new(true).select(&tree, &mut logger), Some(1));
+    assert!(logger.completed());
+}
+
+/// A search cancelled by `NodeCount` visits exactly the given amount of nodes.
+#[test]
+fn node_count() {
+    use crate::NodeCount;
+
+    for seed in 1..100u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 43 + 7) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let mut logger = Logger::new(ToCompletion);
+        Bot::new(true).select(&tree, &mut logger);
+        let total = u64::from(logger.steps());
+
+        for count in 1..total.min(50) {
+            let mut logger = Logger::new(NodeCount(count));
+            Bot::new(true).select(&tree, &mut logger);
+            assert_eq!(u64::from(logger.steps()), count);
+            assert!(!logger.completed());
+        }
+
+        let mut logger = Logger::new(NodeCount(total + 1));
+        Bot::new(true).select(&tree, &mut logger);
+        assert_eq!(u64::from(logger.steps()), total);
+        assert!(logger.completed());
+    }
+}
+
+/// A cancelled search still returns the best action found so far.
+#[test]
+fn cancel() {
+    use crate::Cancel;
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 7).with_children(&[
+            Node::new(true, 4),
+            Node::new(true, 2),
+        ]),
+        Node::new(false, 5).with_children(&[
+            Node::new(true, 8),
+            Node::new(true, 9)
+        ]),
+    ]);
+
+    let (cancel, handle) = Cancel::new();
+    assert!(!handle.is_cancelled());
+    handle.clone().cancel();
+    assert!(handle.is_cancelled());
+
+    let mut logger = Logger::new(cancel);
+    assert_eq!(Bot::new(true).select(&tree, &mut logger), Some(0));
+    assert_eq!(logger.steps(), 0);
+    assert!(!logger.completed());
+
+    let (cancel, _handle) = Cancel::new();
+    assert_eq!(Bot::new(true).select(&tree, cancel), Some(1));
+}
+
+/// `completed_depth` receives the fitness of the best action of the previous depth.
+#[test]
+fn completed_depth() {
+    use crate::{Or, RunCondition};
+
+    struct Recorder(Vec<(u32, Option<i8>)>);
+
+    impl RunCondition for &mut Recorder {
+        type Fitness = i8;
+
+        fn step(&mut self) -> bool {
+            true
+        }
+
+        fn depth(&mut self, _: u32) -> bool {
+            unreachable!("`completed_depth` is overwritten")
+        }
+
+        fn completed_depth(&mut self, depth: u32, best: Option<&i8>) -> bool {
+            self.0.push((depth, best.copied()));
+            true
+        }
+    }
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(true, 0).with_children(&[
+            Node::new(true, 5).with_children(&[
+                Node::new(true, 5).with_children(&[
+                    Node::new(true, 1),
+                ]),
+            ]),
+        ]),
+        Node::new(true, 4),
+    ]);
+
+    let mut recorder = Recorder(Vec::new());
+    let mut logger = Logger::new(ToCompletion);
+    Bot::new(true).select(&tree, Or(&mut recorder, &mut logger));
+    assert_eq!(
+        recorder.0,
+        &[
+            (0, None),
+            (1, Some(5)),
+            (2, Some(5)),
+            (3, Some(4)),
+            (4, Some(4))
+        ]
+    );
+    assert_eq!(logger.score_history(), &[(0, 5), (1, 5), (2, 4), (3, 4)]);
+}
+
+/// Both conditions of `Or` and `And` are always called, so a wrapped `Logger` records every step.
+#[test]
+fn combined_conditions() {
+    use crate::{And, Or};
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 0).with_children(&[
+            Node::new(true, 5).with_children(&[
+                Node::new(true, 5).with_children(&[
+                    Node::new(true, 3),
+                ]),
+            ]),
+        ]),
+        Node::new(false, 0).with_children(&[
+            Node::new(false, 4),
+            Node::new(false, 2),
+        ]),
+    ]);
+
+    let mut logger = Logger::new(ToCompletion);
+    Bot::new(true).select(&tree, Or(Steps(7), &mut logger));
+    assert_eq!(logger.steps(), 7);
+    assert!(logger.completed());
+
+    let mut logger = Logger::new(Steps(3));
+    Bot::new(true).select(&tree, And(Steps(7), &mut logger));
+    assert_eq!(logger.steps(), 7);
+    assert!(!logger.completed());
+
+    let mut logger = Logger::new(Depth(1));
+    Bot::new(true).select(&tree, And(ToCompletion, &mut logger));
+    assert!(logger.depth() > 1);
+}
+
+/// The score history shows the best action getting worse once the search looks deeper.
+#[test]
+fn score_history() {
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(true, 0).with_children(&[
+            Node::new(true, 5).with_children(&[
+                Node::new(true, 5).with_children(&[
+                    Node::new(true, 1),
+                ]),
+            ]),
+        ]),
+        Node::new(true, 4),
+    ]);
+
+    let mut logger = Logger::new(ToCompletion);
+    assert_eq!(Bot::new(true).select(&tree, &mut logger), Some(1));
+    assert_eq!(logger.score_history(), &[(0, 5), (1, 5), (2, 4), (3, 4)]);
+    assert_eq!(logger.depth_durations().len(), 4);
+    // `[1]` is the best action since depth 2.
+    assert_eq!(logger.stable_depths(), 2);
+}
+
+/// Repetitions detected inside of `execute` using a history stored in the game state
+/// are visible to both `look_ahead` and the search.
+#[test]
+fn execute_history() {
+    #[derive(Clone)]
+    struct History {
+        position: i8,
+        history: Vec<i8>,
+    }
+
+    impl Game for History {
+        type Player = ();
+        type Action = bool;
+        type Fitness = i8;
+        type Actions = Vec<bool>;
+
+        fn actions(&self, _: Self::Player) -> (bool, Self::Actions) {
+            let (position, history) = self.history.split_last().unwrap();
+            if history.contains(position) {
+                (true, Vec::new())
+            } else {
+                (true, vec![false, true])
+            }
+        }
+
+        /// Prefers going back, but repeating a position loses the game.
+        fn execute(&mut self, &forward: &Self::Action, _: Self::Player) -> Self::Fitness {
+            self.position += if forward { 1 } else { -1 };
+            let repeated = self.history.contains(&self.position);
+            self.history.push(self.position);
+            if repeated {
+                i8::MIN
+            } else {
+                -self.position
+            }
+        }
+    }
+
+    let state = History {
+        position: 0,
+        history: vec![-1, 0],
+    };
+    assert_eq!(state.look_ahead(&false, ()), i8::MIN);
+    assert_eq!(state.clone().execute(&false, ()), i8::MIN);
+    assert_eq!(Bot::new(()).select(&state, Depth(3)), Some(true));
+}
+
+/// A `Deadline` only depends on its clock, so a mock clock stops the search deterministically.
+#[test]
+fn deadline_mock_clock() {
+    use crate::{Clock, Deadline};
+    use std::time::{Duration, Instant};
+
+    struct Ticks(Instant);
+
+    impl Clock for Ticks {
+        fn now(&mut self) -> Instant {
+            self.0 += Duration::from_millis(1);
+            self.0
+        }
+    }
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 0).with_children(&[
+            Node::new(true, 2).with_children(&[
+                Node::new(false, 3),
+            ]),
+        ]),
+        Node::new(false, 1),
+    ]);
+
+    let start = Instant::now();
+    let mut logger = Logger::new(Deadline::new(Duration::from_millis(5), Ticks(start)));
+    assert!(Bot::new(true).select(&tree, &mut logger).is_some());
+    assert!(!logger.completed());
+    // One tick when creating the deadline and 5 more until the deadline was reached.
+    let clock = logger.into_inner().into_inner().into_clock();
+    assert_eq!(clock.0 - start, Duration::from_millis(6));
+}
+
+/// The search completes once the budget allows for every call to the run condition.
+#[test]
+fn mock_clock_deadline() {
+    use crate::test_util::MockClock;
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 7).with_children(&[
+            Node::new(true, 4),
+            Node::new(true, 2),
+        ]),
+        Node::new(false, 5).with_children(&[
+            Node::new(true, 8),
+            Node::new(true, 9)
+        ]),
+    ]);
+
+    let mut logger = Logger::new(MockClock::deadline(13));
+    assert_eq!(Bot::new(true).select(&tree, &mut logger), Some(1));
+    assert!(!logger.completed());
+
+    let mut logger = Logger::new(MockClock::deadline(14));
+    assert_eq!(Bot::new(true).select(&tree, &mut logger), Some(1));
+    assert!(logger.completed());
+    assert_eq!(logger.into_inner().into_inner().into_clock().ticks(), 15);
+}
+
+/// `NaN` is the lowest possible `Total`, so a bot never prefers it.
+#[test]
+fn total_order() {
+    use crate::Total;
+
+    let mut values: Vec<_> = [1.0, f64::NAN, f64::NEG_INFINITY, -0.0, f64::INFINITY, 0.0]
+        .iter()
+        .map(|&f| Total(f))
+        .collect();
+    values.sort();
+    assert!(values[0].0.is_nan());
+    assert!(values[1].is_lower_bound());
+    assert_eq!(values[2], values[3]);
+    assert_eq!(f64::from(values[4]), 1.0);
+    assert!(values[5].is_upper_bound());
+    assert_eq!(Total(f32::NAN), Total::from(f32::NAN));
+    assert!(Total(f32::NAN) < Total(f32::MIN));
+}
+
+/// `Bot::analyze` returns the states analysed during the last completed depth.
+#[test]
+fn analyze() {
+    use crate::alpha_beta::{AnalysisNode, Bound};
+
+    /// Writes each node as `action:fitness`, followed by `<` for upper and `>` for lower bounds.
+    fn shape(nodes: &[AnalysisNode<Node>]) -> String {
+        let nodes: Vec<_> = nodes
+            .iter()
+            .map(|node| {
+                let bound = match node.bound {
+                    Bound::Exact => "",
+                    Bound::Upper => "<",
+                    Bound::Lower => ">",
+                };
+                let mut s = format!("{}:{}{}", node.action, node.fitness, bound);
+                if !node.children.is_empty() {
+                    s += &format!("({})", shape(&node.children));
+                }
+                s
+            })
+            .collect();
+        nodes.join(" ")
+    }
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 0).with_children(&[
+            Node::new(true, 3).with_children(&[
+                Node::new(false, 1),
+                Node::new(false, 6),
+            ]),
+            Node::new(true, 4),
+        ]),
+        Node::new(false, 0).with_children(&[
+            Node::new(true, 2),
+            Node::new(true, 5),
+        ]),
+    ]);
+
+    let analysis = Bot::new(true).analyze(&tree, ToCompletion).unwrap();
+    assert_eq!(analysis.depth, 2);
+    // `[1]` was already completely analysed at depth `1`, so it is not analysed again.
+    assert_eq!(shape(&analysis.children), "0:4(1:4 0:6>(1:6))");
+
+    let analysis = Bot::new(true).analyze(&tree, Depth(1)).unwrap();
+    assert_eq!(analysis.depth, 0);
+    assert_eq!(shape(&analysis.children), "0:3(0:3) 1:2(0:2)");
+
+    assert!(Bot::new(true).analyze(&tree, Steps(1)).is_none());
+    assert!(Bot::new(false).analyze(&tree, ToCompletion).is_none());
+}
+
+/// The verdict depends on whether the expected fitness of the selected action is a bound.
+#[test]
+fn verdict() {
+    use crate::alpha_beta::Verdict;
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 3).with_children(&[
+            Node::new(true, 0).with_children(&[
+                Node::new(false, i8::MIN),
+            ]),
+        ]),
+        Node::new(false, i8::MIN),
+    ]);
+
+    let mut bot = Bot::new(true);
+    assert_eq!(
+        bot.select_with_verdict(&tree, Depth(1)),
+        Some((0, Verdict::Unknown))
+    );
+    assert_eq!(
+        bot.select_with_verdict(&tree, ToCompletion),
+        Some((0, Verdict::Losing))
+    );
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 3),
+        Node::new(false, 0).with_children(&[
+            Node::new(true, i8::MAX),
+        ]),
+    ]);
+    assert_eq!(
+        bot.select_with_verdict(&tree, ToCompletion),
+        Some((1, Verdict::Winning))
+    );
+    assert_eq!(
+        Bot::new(false).select_with_verdict(&tree, ToCompletion),
+        None
+    );
 }
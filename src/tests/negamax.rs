@@ -0,0 +1,66 @@
+//! Tests of the negamax bot for zero-sum games.
+use crate::brute::Brute;
+use crate::negamax::Bot;
+use crate::{tree::Node, Depth, Game, Logger, Steps, ToCompletion};
+
+/// A `Node` with a wider fitness type, as `i8::MIN` can't be negated.
+#[derive(Clone, Debug)]
+struct Wide(Node);
+
+impl Game for Wide {
+    type Player = bool;
+    type Action = usize;
+    type Fitness = i16;
+    type Actions = std::ops::Range<usize>;
+
+    fn actions(&self, player: bool) -> (bool, Self::Actions) {
+        self.0.actions(player)
+    }
+
+    fn execute(&mut self, action: &usize, player: bool) -> i16 {
+        i16::from(self.0.execute(action, player))
+    }
+}
+
+/// Negamax selects an action which is as good as the one found by a minimax search.
+#[test]
+fn brute() {
+    for seed in 1..300u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 29 + 11) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let selected = Bot::new(true).select(&Wide(tree.clone()), ToCompletion);
+        assert!(
+            Brute::new(true).check_if_best(&tree, selected.as_ref(), u32::MAX),
+            "tree: {:?}, selected: {:?}",
+            tree,
+            selected
+        );
+    }
+}
+
+/// A cancelled search returns the best action of the last completed depth.
+#[test]
+fn cancelled() {
+    #[rustfmt::skip]
+    let tree = Wide(Node::root().with_children(&[
+        Node::new(false, 7).with_children(&[
+            Node::new(true, 4),
+            Node::new(true, 2),
+        ]),
+        Node::new(false, 5).with_children(&[
+            Node::new(true, 8),
+            Node::new(true, 9)
+        ]),
+    ]));
+
+    assert_eq!(Bot::new(true).select(&tree, Depth(0)), Some(0));
+    assert_eq!(Bot::new(true).select(&tree, Steps(1)), Some(0));
+    assert_eq!(Bot::new(true).select(&tree, Depth(1)), Some(1));
+
+    let mut logger = Logger::new(ToCompletion);
+    assert_eq!(Bot::new(true).select(&tree, &mut logger), Some(1));
+    assert!(logger.completed());
+    assert_eq!(Bot::new(false).select(&tree, ToCompletion), None);
+}
@@ -54,3 +54,219 @@ fn fuzz_two() {
     let selected = Bot::new(true).select(&tree, Steps(7));
     assert_eq!(selected, Some(0));
 }
+
+/// Lowering the maximum depth should prevent the bot from seeing the better action at depth 1.
+#[test]
+fn change_max_depth() {
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(true, 5),
+        Node::new(true, 0).with_children(&[
+            Node::new(true, 7),
+        ]),
+    ]);
+
+    let mut bot = Bot::new(true);
+    bot.set_max_depth(Some(0));
+    assert_eq!(bot.select(&tree, ToCompletion), Some(0));
+    assert_eq!(bot.solve(&tree, 10), None);
+
+    bot.set_max_depth(Some(1));
+    assert_eq!(bot.select(&tree, ToCompletion), Some(1));
+
+    bot.set_max_depth(None);
+    assert_eq!(bot.solve(&tree, 10), Some(7));
+}
+
+/// `set_strength` limits the depth just like `set_max_depth`, `u8::MAX` removes the limit.
+#[test]
+fn strength() {
+    for seed in 1..100u32 {
+        let mut bytes = seed.to_be_bytes().to_vec();
+        bytes.extend((0..seed % 30).map(|i| seed.wrapping_mul(i * 23 + 11) as u8));
+        let tree = Node::from_bytes(&bytes);
+
+        let mut bot = Bot::new(true);
+        let mut limited = Bot::new(true);
+        for &(level, depth) in &[(0, Some(0)), (1, Some(1)), (2, Some(2)), (u8::MAX, None)] {
+            bot.set_strength(level);
+            limited.set_max_depth(depth);
+            let action = bot.detailed_select(&tree, ToCompletion);
+            let expected = limited.detailed_select(&tree, ToCompletion);
+            assert_eq!(
+                action.map(|act| (act.fitness, act.path)),
+                expected.map(|act| (act.fitness, act.path)),
+                "level: {}, tree: {:?}",
+                level,
+                tree
+            );
+        }
+    }
+}
+
+/// States reached by an action with a fitness of `5` are not quiet and must be analysed
+/// past the lowest depth.
+#[test]
+fn quiescence() {
+    #[derive(Clone, Debug)]
+    struct Noisy(Node, i8);
+
+    impl crate::Game for Noisy {
+        type Player = bool;
+        type Action = usize;
+        type Fitness = i8;
+        type Actions = std::ops::Range<usize>;
+
+        fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+            self.0.actions(player)
+        }
+
+        fn execute(&mut self, action: &Self::Action, player: Self::Player) -> Self::Fitness {
+            self.1 = self.0.execute(action, player);
+            self.1
+        }
+
+        fn is_quiet(&self, _: Self::Player) -> bool {
+            self.1 != 5
+        }
+    }
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 5).with_children(&[
+            Node::new(true, 8).with_children(&[
+                Node::new(false, -10),
+            ]),
+        ]),
+        Node::new(false, 1).with_children(&[
+            Node::new(true, 1).with_children(&[
+                Node::new(false, 1),
+            ]),
+        ]),
+    ]);
+
+    assert_eq!(Bot::new(true).select(&tree, Depth(1)), Some(0));
+    let noisy = Noisy(tree, 0);
+    assert_eq!(Bot::new(true).select(&noisy, Depth(1)), Some(1));
+    assert_eq!(Bot::new(true).select(&noisy, ToCompletion), Some(1));
+}
+
+/// Only the noisy actions of a state which is not quiet are analysed past the lowest depth,
+/// which are compared with the evaluation of the state itself.
+#[test]
+fn noisy_actions() {
+    #[derive(Clone, Debug)]
+    struct Capture(Node, i8);
+
+    impl crate::Game for Capture {
+        type Player = bool;
+        type Action = usize;
+        type Fitness = i8;
+        type Actions = std::ops::Range<usize>;
+
+        fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+            self.0.actions(player)
+        }
+
+        fn execute(&mut self, action: &Self::Action, player: Self::Player) -> Self::Fitness {
+            self.1 = self.0.execute(action, player);
+            self.1
+        }
+
+        fn is_quiet(&self, _: Self::Player) -> bool {
+            self.1 != 6
+        }
+
+        /// Only the first action is a capture.
+        fn noisy_actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+            let (active, actions) = self.0.actions(player);
+            (
+                active,
+                actions.start..std::cmp::min(actions.start + 1, actions.end),
+            )
+        }
+    }
+
+    #[rustfmt::skip]
+    let tree = Node::root().with_children(&[
+        Node::new(false, 6).with_children(&[
+            Node::new(true, 4),
+            Node::new(true, 6).with_children(&[
+                Node::new(false, -20),
+            ]),
+        ]),
+        Node::new(false, 3).with_children(&[
+            Node::new(true, 3),
+        ]),
+    ]);
+
+    // The second answer is not a capture, so its consequences are only found by a deeper search.
+    let capture = Capture(tree, 0);
+    assert_eq!(Bot::new(true).select(&capture, Depth(1)), Some(0));
+    assert_eq!(Bot::new(true).select(&capture, Depth(3)), Some(1));
+}
+
+/// The analysis past the lowest depth ends even if no quiet state is ever reached.
+#[test]
+fn quiescence_limit() {
+    /// A token which is moved back and forth forever.
+    #[derive(Clone)]
+    struct Restless(bool);
+
+    impl crate::Game for Restless {
+        type Player = bool;
+        type Action = ();
+        type Fitness = i8;
+        type Actions = std::iter::Once<()>;
+
+        fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+            (player == self.0, std::iter::once(()))
+        }
+
+        fn execute(&mut self, _: &Self::Action, _: Self::Player) -> Self::Fitness {
+            self.0 = !self.0;
+            0
+        }
+
+        fn is_quiet(&self, _: Self::Player) -> bool {
+            false
+        }
+    }
+
+    let mut logger = Logger::new(Depth(2));
+    assert_eq!(
+        Bot::new(true).select(&Restless(true), &mut logger),
+        Some(())
+    );
+    assert!(logger.steps() < 100);
+}
+
+/// A maximum depth stops the search of a game which never ends, even when using `ToCompletion`.
+#[test]
+fn max_depth() {
+    /// A token which is moved back and forth forever.
+    #[derive(Clone)]
+    struct Shuffle(bool);
+
+    impl crate::Game for Shuffle {
+        type Player = bool;
+        type Action = ();
+        type Fitness = i8;
+        type Actions = std::iter::Once<()>;
+
+        fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
+            (player == self.0, std::iter::once(()))
+        }
+
+        fn execute(&mut self, _: &Self::Action, _: Self::Player) -> Self::Fitness {
+            self.0 = !self.0;
+            0
+        }
+    }
+
+    let mut bot = Bot::new(true);
+    bot.set_max_depth(Some(6));
+    let mut logger = Logger::new(ToCompletion);
+    assert_eq!(bot.select(&Shuffle(true), &mut logger), Some(()));
+    assert_eq!(logger.depth(), 6);
+}
@@ -0,0 +1,120 @@
+//! Tests of the solver for single player games.
+use crate::solver::Bot;
+use crate::{Depth, Game, Logger, Steps, ToCompletion};
+
+/// Reach `target` by adding `1` or `3` or by doubling the current number.
+#[derive(Clone, Debug)]
+struct Reach {
+    value: u32,
+    target: u32,
+    keyed: bool,
+}
+
+impl Reach {
+    fn new(target: u32, keyed: bool) -> Self {
+        Reach {
+            value: 1,
+            target,
+            keyed,
+        }
+    }
+}
+
+impl Game for Reach {
+    type Player = ();
+    type Action = u8;
+    type Fitness = i64;
+    type Actions = std::ops::Range<u8>;
+
+    fn actions(&self, _: ()) -> (bool, Self::Actions) {
+        (true, 0..3)
+    }
+
+    fn execute(&mut self, action: &u8, _: ()) -> i64 {
+        match action {
+            0 => self.value += 1,
+            1 => self.value += 3,
+            _ => self.value *= 2,
+        }
+        -(i64::from(self.value) - i64::from(self.target)).abs()
+    }
+
+    fn is_upper_bound(&self, fitness: i64, _: ()) -> bool {
+        fitness == 0
+    }
+
+    fn is_lower_bound(&self, _: i64, _: ()) -> bool {
+        self.value > self.target
+    }
+
+    fn key(&self, _: ()) -> Option<u64> {
+        Some(self.value.into()).filter(|_| self.keyed)
+    }
+}
+
+/// Returns the length of the shortest sequence reaching the target by trying all sequences.
+fn shortest(state: &Reach, depth: usize) -> Option<usize> {
+    if state.value == state.target {
+        return Some(0);
+    } else if depth == 0 || state.value > state.target {
+        return None;
+    }
+
+    (0..3)
+        .filter_map(|action| {
+            let mut state = state.clone();
+            state.execute(&action, ());
+            shortest(&state, depth - 1)
+        })
+        .min()
+        .map(|len| len + 1)
+}
+
+#[test]
+fn shortest_sequence() {
+    for target in 2..60 {
+        for &keyed in &[false, true] {
+            let state = Reach::new(target, keyed);
+            let actions = Bot::new(()).solve(&state, ToCompletion).unwrap();
+            assert_eq!(Some(actions.len()), shortest(&state, 8), "{:?}", state);
+
+            let mut reached = state.clone();
+            for action in actions.iter() {
+                reached.execute(action, ());
+            }
+            assert_eq!(reached.value, target);
+        }
+    }
+}
+
+#[test]
+fn keys() {
+    let mut unkeyed = Logger::new(ToCompletion);
+    Bot::new(()).solve(&Reach::new(57, false), &mut unkeyed);
+    let mut keyed = Logger::new(ToCompletion);
+    Bot::new(()).solve(&Reach::new(57, true), &mut keyed);
+    assert!(keyed.steps() < unkeyed.steps());
+}
+
+#[test]
+fn run_condition() {
+    // 1 -> 4 -> 7 -> 10
+    let state = Reach::new(10, true);
+    assert_eq!(Bot::new(()).solve(&state, Depth(2)), None);
+    assert_eq!(Bot::new(()).solve(&state, Depth(3)), Some(vec![1, 1, 1]));
+    assert_eq!(Bot::new(()).solve(&state, Steps(1)), None);
+
+    let mut logger = Logger::new(ToCompletion);
+    let actions = Bot::new(()).solve(&state, &mut logger).unwrap();
+    assert_eq!(logger.best_fitness(), Some(0));
+    assert_eq!(logger.pv_len(), actions.len());
+}
+
+/// Once every reachable state was analysed, the search stops without finding a goal.
+#[test]
+fn unreachable() {
+    let state = Reach::new(0, true);
+    let mut logger = Logger::new(ToCompletion);
+    assert_eq!(Bot::new(()).solve(&state, &mut logger), None);
+    assert!(logger.completed());
+}
@@ -1,13 +1,19 @@
 //! A tree implementation used in examples and tests.
 
-use crate::Game;
-use std::convert::TryInto;
-use std::fmt::Debug;
-use std::num::Wrapping;
-use std::ops::Range;
+use crate::{rng::XorShiftRng, Bounded, Game};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use core::fmt::{self, Debug, Write};
+use core::ops::Range;
 
 /// A tree node, implements [`Game`][game].
 ///
+/// The fitness of each node is of type `F`, which is `i8` by default. The greatest and smallest
+/// fitness of type `F` are an upper and a lower bound respectively, see [`Bounded`][bounded].
+/// Using [`Marked`][marked] as the fitness, other fitnesses can be marked as a bound using
+/// [`upper_bound`][ub] and [`lower_bound`][lb].
+///
 /// # Examples
 ///
 /// ```rust
@@ -24,20 +30,33 @@ use std::ops::Range;
 ///
 /// let mut bot = Bot::new(true);
 /// assert_eq!(bot.select(&tree, ToCompletion), Some(0));
+///
+/// // `true` is the greatest `bool`, so it is an upper bound.
+/// # #[rustfmt::skip]
+/// let tree = Node::<bool>::new(true, false).with_children(&[
+///     Node::new(false, false),
+///     Node::new(false, true),
+/// ]);
+///
+/// assert_eq!(Bot::new(true).select(&tree, ToCompletion), Some(1));
 /// ```
 /// [game]: ../trait.Game.html
+/// [bounded]: ../trait.Bounded.html
+/// [marked]: struct.Marked.html
+/// [ub]: struct.Node.html#method.upper_bound
+/// [lb]: struct.Node.html#method.lower_bound
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Node {
+pub struct Node<F = i8> {
     player: bool,
     // always from the perspective of the tested player
-    fitness: i8,
-    children: Vec<Node>,
+    fitness: F,
+    children: Vec<Node<F>>,
 }
 
-impl Game for Node {
+impl<F: Bounded> Game for Node<F> {
     type Player = bool;
     type Action = usize;
-    type Fitness = i8;
+    type Fitness = F;
     type Actions = Range<usize>;
 
     fn actions(&self, player: Self::Player) -> (bool, Self::Actions) {
@@ -54,12 +73,24 @@ impl Game for Node {
         self.children[*action].fitness
     }
 
+    fn validate_action(&self, &action: &Self::Action, _: Self::Player) -> bool {
+        action < self.children.len()
+    }
+
+    fn hash_action(&self, &action: &Self::Action, _: Self::Player) -> Option<u64> {
+        Some(action as u64)
+    }
+
+    fn fitness_step(&self) -> Option<F> {
+        F::step()
+    }
+
     fn is_upper_bound(&self, fitness: Self::Fitness, _: Self::Player) -> bool {
-        fitness == i8::MAX
+        fitness.is_upper_bound()
     }
 
     fn is_lower_bound(&self, fitness: Self::Fitness, _: Self::Player) -> bool {
-        fitness == i8::MIN
+        fitness.is_lower_bound()
     }
 }
 
@@ -69,15 +100,6 @@ impl Node {
         Self::new(true, 0)
     }
 
-    /// Creates a new node with no children.
-    pub fn new(player: bool, fitness: i8) -> Self {
-        Self {
-            player,
-            fitness,
-            children: Vec::new(),
-        }
-    }
-
     /// Generates a tree from `bytes`, the total amount of tree nodes, excluding the root,
     /// is currently `bytes.len() - 4`.
     ///
@@ -86,37 +108,7 @@ impl Node {
     pub fn from_bytes(bytes: &[u8]) -> Self {
         match bytes[0..4].try_into() {
             Ok(seed) => {
-                let mut seed = u32::from_be_bytes(seed);
-                if seed == 0 {
-                    seed = 0xBAD_5EED;
-                }
-
-                struct XorShiftRng {
-                    x: Wrapping<u32>,
-                    y: Wrapping<u32>,
-                    z: Wrapping<u32>,
-                    w: Wrapping<u32>,
-                }
-
-                impl XorShiftRng {
-                    fn next_u32(&mut self) -> u32 {
-                        let x = self.x;
-                        let t = x ^ (x << 11);
-                        self.x = self.y;
-                        self.y = self.z;
-                        self.z = self.w;
-                        let w = self.w;
-                        self.w = w ^ (w >> 19) ^ (t ^ (t >> 8));
-                        self.w.0
-                    }
-                }
-
-                let mut rng = XorShiftRng {
-                    x: Wrapping(seed),
-                    y: Wrapping(seed),
-                    z: Wrapping(seed),
-                    w: Wrapping(seed),
-                };
+                let mut rng = XorShiftRng::new(u32::from_be_bytes(seed));
 
                 let mut root = Node::new(true, 0);
                 for &i in bytes[4..].iter() {
@@ -124,9 +116,9 @@ impl Node {
                     let mut next =
                         rng.next_u32() as usize % (pos.as_ref().unwrap().children.len() + 1);
                     while next != pos.as_ref().unwrap().children.len() {
-                        pos.take().map(|node| {
+                        if let Some(node) = pos.take() {
                             pos = Some(&mut node.children[next]);
-                        });
+                        }
                         next = rng.next_u32() as usize % (pos.as_ref().unwrap().children.len() + 1);
                     }
 
@@ -141,16 +133,99 @@ impl Node {
         }
     }
 
+    /// Parses a tree written as `(<player><fitness> <children>...)`, where the player is either `t` or `f`.
+    ///
+    /// This is the format returned by [`to_dsl`][to], so `Node::parse(&node.to_dsl())` is equal to `node`.
+    /// Whitespace is ignored, except between the player and the fitness of a node.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rubot::tree::Node;
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 4),
+    ///         Node::new(true, 2),
+    ///     ]),
+    ///     Node::new(false, -5),
+    /// ]);
+    ///
+    /// assert_eq!(Node::parse("(t0 (f7 (t4)(t2)) (f-5))"), Ok(tree));
+    /// assert!(Node::parse("(t0 (f7)").is_err());
+    /// ```
+    ///
+    /// [to]: struct.Node.html#method.to_dsl
+    pub fn parse(s: &str) -> Result<Node, ParseError> {
+        let mut parser = Parser {
+            bytes: s.as_bytes(),
+            position: 0,
+        };
+        let node = parser.node()?;
+        parser.skip_whitespace();
+        if parser.position == parser.bytes.len() {
+            Ok(node)
+        } else {
+            Err(parser.error())
+        }
+    }
+
+    /// Returns this tree in the format used by [`parse`][parse].
+    ///
+    /// ```rust
+    /// use rubot::tree::Node;
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 7).with_children(&[
+    ///         Node::new(true, 4),
+    ///     ]),
+    ///     Node::new(false, -5),
+    /// ]);
+    ///
+    /// assert_eq!(tree.to_dsl(), "(t0 (f7 (t4)) (f-5))");
+    /// ```
+    ///
+    /// [parse]: struct.Node.html#method.parse
+    pub fn to_dsl(&self) -> String {
+        fn add_node(node: &Node, dsl: &mut String) {
+            let player = if node.player { 't' } else { 'f' };
+            // Writing to a `String` never fails.
+            let _ = write!(dsl, "({}{}", player, node.fitness);
+            for child in node.children.iter() {
+                dsl.push(' ');
+                add_node(child, dsl);
+            }
+            dsl.push(')');
+        }
+
+        let mut dsl = String::new();
+        add_node(self, &mut dsl);
+        dsl
+    }
+}
+
+impl<F: Copy> Node<F> {
+    /// Creates a new node with no children.
+    pub fn new(player: bool, fitness: F) -> Self {
+        Self {
+            player,
+            fitness,
+            children: Vec::new(),
+        }
+    }
+
     /// Sets the children of `self` to `children`.
     /// Previous children are forgotten.
-    pub fn with_children(mut self, children: &[Node]) -> Self {
+    pub fn with_children(mut self, children: &[Node<F>]) -> Self {
         self.children.clear();
         self.children.extend_from_slice(children);
         self
     }
 
     // Add a `child` node to `self`
-    pub fn push_child(&mut self, child: Node) {
+    pub fn push_child(&mut self, child: Node<F>) {
         self.children.push(child);
     }
 
@@ -165,3 +240,271 @@ impl Node {
         self.children.is_empty()
     }
 }
+
+impl<F: Copy> Node<Marked<F>> {
+    /// Marks the fitness of this node as an upper bound, meaning that there is no better outcome
+    /// for the tested player. This is used by [`Game::is_upper_bound`][ub].
+    ///
+    /// ```rust
+    /// use rubot::{Bot, tree::{Marked, Node}};
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::new(true, Marked::new(0)).with_children(&[
+    ///     Node::new(false, Marked::new(3)),
+    ///     Node::new(false, Marked::new(5)),
+    /// ]);
+    /// assert_eq!(Bot::new(true).forced_win_in(&tree, 1), None);
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::new(true, Marked::new(0)).with_children(&[
+    ///     Node::new(false, Marked::new(3)),
+    ///     Node::new(false, Marked::new(5)).upper_bound(),
+    /// ]);
+    /// assert_eq!(Bot::new(true).forced_win_in(&tree, 1), Some(1));
+    /// ```
+    ///
+    /// [ub]: ../trait.Game.html#method.is_upper_bound
+    pub fn upper_bound(mut self) -> Self {
+        self.fitness.mark = Mark::Upper;
+        self
+    }
+
+    /// Marks the fitness of this node as a lower bound, meaning that there is no worse outcome
+    /// for the tested player. This is used by [`Game::is_lower_bound`][lb].
+    ///
+    /// [lb]: ../trait.Game.html#method.is_lower_bound
+    pub fn lower_bound(mut self) -> Self {
+        self.fitness.mark = Mark::Lower;
+        self
+    }
+}
+
+impl<F: Copy + fmt::Display> Node<F> {
+    /// Returns this tree in the [DOT language][dot], which can be rendered using Graphviz.
+    ///
+    /// Each node is labeled with its player and fitness and each edge with the action
+    /// used to reach the child, which is its position in the children of its parent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rubot::tree::Node;
+    ///
+    /// # #[rustfmt::skip]
+    /// let tree = Node::root().with_children(&[
+    ///     Node::new(false, 4),
+    ///     Node::new(false, -7),
+    /// ]);
+    ///
+    /// assert_eq!(tree.to_dot(), "\
+    /// digraph {
+    ///     0 [label=\"true: 0\"];
+    ///     0 -> 1 [label=\"0\"];
+    ///     1 [label=\"false: 4\"];
+    ///     0 -> 2 [label=\"1\"];
+    ///     2 [label=\"false: -7\"];
+    /// }
+    /// ");
+    /// ```
+    ///
+    /// [dot]: https://graphviz.org/doc/info/lang.html
+    pub fn to_dot(&self) -> String {
+        fn add_node<F: Copy + fmt::Display>(
+            node: &Node<F>,
+            id: usize,
+            next_id: &mut usize,
+            dot: &mut String,
+        ) {
+            // Writing to a `String` never fails.
+            let _ = writeln!(
+                dot,
+                "    {} [label=\"{}: {}\"];",
+                id, node.player, node.fitness
+            );
+            for (action, child) in node.children.iter().enumerate() {
+                let child_id = *next_id;
+                *next_id += 1;
+                let _ = writeln!(dot, "    {} -> {} [label=\"{}\"];", id, child_id, action);
+                add_node(child, child_id, next_id, dot);
+            }
+        }
+
+        let mut dot = String::from("digraph {\n");
+        add_node(self, 0, &mut 1, &mut dot);
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// A fitness which can be marked as an upper or a lower bound, used by [`Node::upper_bound`][ub]
+/// and [`Node::lower_bound`][lb].
+///
+/// Unmarked fitnesses are only bounds if they are the greatest or smallest value of type `F`.
+/// Marked fitnesses are compared by their value first, with a marked upper bound being greater
+/// and a marked lower bound being smaller than an unmarked fitness with the same value.
+///
+/// Displaying a marked fitness appends `+` to upper and `-` to lower bounds.
+///
+/// [ub]: struct.Node.html#method.upper_bound
+/// [lb]: struct.Node.html#method.lower_bound
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Marked<F = i8> {
+    fitness: F,
+    mark: Mark,
+}
+
+/// The kind of bound of a `Marked` fitness, ordered so that a lower bound is the worst
+/// and an upper bound the best outcome with the same value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Mark {
+    Lower,
+    Unmarked,
+    Upper,
+}
+
+impl<F> Marked<F> {
+    /// Creates a new unmarked fitness.
+    pub fn new(fitness: F) -> Self {
+        Marked {
+            fitness,
+            mark: Mark::Unmarked,
+        }
+    }
+
+    /// Returns the value of this fitness, ignoring its mark.
+    pub fn fitness(self) -> F {
+        self.fitness
+    }
+}
+
+impl<F> From<F> for Marked<F> {
+    fn from(fitness: F) -> Self {
+        Marked::new(fitness)
+    }
+}
+
+impl<F: Bounded> Bounded for Marked<F> {
+    const MIN: Self = Marked {
+        fitness: F::MIN,
+        mark: Mark::Lower,
+    };
+    const MAX: Self = Marked {
+        fitness: F::MAX,
+        mark: Mark::Upper,
+    };
+
+    #[inline]
+    fn is_upper_bound(self) -> bool {
+        self.mark == Mark::Upper || self.fitness.is_upper_bound()
+    }
+
+    #[inline]
+    fn is_lower_bound(self) -> bool {
+        self.mark == Mark::Lower || self.fitness.is_lower_bound()
+    }
+}
+
+impl<F: fmt::Display> fmt::Display for Marked<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.mark {
+            Mark::Lower => write!(f, "{}-", self.fitness),
+            Mark::Unmarked => write!(f, "{}", self.fitness),
+            Mark::Upper => write!(f, "{}+", self.fitness),
+        }
+    }
+}
+
+/// The error returned by [`Node::parse`][parse] for invalid input.
+///
+/// [parse]: struct.Node.html#method.parse
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The byte offset of the input at which parsing failed.
+    pub position: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid tree at byte {}", self.position)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// A recursive descent parser used by `Node::parse`.
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self) -> ParseError {
+        ParseError {
+            position: self.position,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .bytes
+            .get(self.position)
+            .is_some_and(u8::is_ascii_whitespace)
+        {
+            self.position += 1;
+        }
+    }
+
+    /// Consumes `byte` if it is the next byte of the input.
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.bytes.get(self.position) == Some(&byte) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn node(&mut self) -> Result<Node, ParseError> {
+        self.skip_whitespace();
+        if !self.eat(b'(') {
+            return Err(self.error());
+        }
+        self.skip_whitespace();
+
+        let player = if self.eat(b't') {
+            true
+        } else if self.eat(b'f') {
+            false
+        } else {
+            return Err(self.error());
+        };
+        let mut node = Node::new(player, self.fitness()?);
+
+        loop {
+            self.skip_whitespace();
+            if self.eat(b')') {
+                return Ok(node);
+            }
+            node.children.push(self.node()?);
+        }
+    }
+
+    fn fitness(&mut self) -> Result<i8, ParseError> {
+        let start = self.position;
+        self.eat(b'-');
+        while self
+            .bytes
+            .get(self.position)
+            .is_some_and(u8::is_ascii_digit)
+        {
+            self.position += 1;
+        }
+
+        // The parsed bytes are ASCII, so they are valid UTF-8.
+        core::str::from_utf8(&self.bytes[start..self.position])
+            .ok()
+            .and_then(|fitness| fitness.parse().ok())
+            .ok_or(ParseError { position: start })
+    }
+}